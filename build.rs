@@ -84,6 +84,13 @@ fn main() {
         )
         .unwrap();
 
+    // The API served by the explorer itself.
+    tonic_build::configure()
+        .build_server(true)
+        .build_client(false)
+        .compile(&["proto/explorer/v1/explorer.proto"], &["proto"])
+        .unwrap();
+
     let descriptor_bytes = std::fs::read(descriptor_file).unwrap();
     let descriptor = FileDescriptorSet::decode(&descriptor_bytes[..]).unwrap();
 
@@ -0,0 +1,286 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use actix_web::body::BoxBody;
+use actix_web::{HttpRequest, HttpResponse, Responder};
+use dashmap::DashMap;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::config::CacheTtlConfig;
+use crate::routes::{json_response, TNRAppError, TNRAppSuccessResponse};
+
+/// The maximum count of the responses cached for a chain.\
+/// The paginated routes have a key for each query, so the expired responses are dropped when the cache is full.
+const MAX_CACHED_RESPONSES: usize = 1000;
+
+/// The cached routes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CacheRoute {
+    Dashboard,
+    Stats,
+    ValidatorsBonded,
+    Params,
+    Inflation,
+}
+
+impl CacheRoute {
+    const ALL: [CacheRoute; 5] = [
+        CacheRoute::Dashboard,
+        CacheRoute::Stats,
+        CacheRoute::ValidatorsBonded,
+        CacheRoute::Params,
+        CacheRoute::Inflation,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CacheRoute::Dashboard => "dashboard",
+            CacheRoute::Stats => "stats",
+            CacheRoute::ValidatorsBonded => "validators_bonded",
+            CacheRoute::Params => "params",
+            CacheRoute::Inflation => "inflation",
+        }
+    }
+
+    /// Returns the time a response of the route is cached for, if it is not invalidated before.
+    fn ttl(&self, config: &CacheTtlConfig) -> Duration {
+        let seconds = match self {
+            CacheRoute::Dashboard => config.dashboard,
+            CacheRoute::Stats => config.stats,
+            CacheRoute::ValidatorsBonded => config.validators_bonded,
+            CacheRoute::Params => config.params,
+            CacheRoute::Inflation => config.inflation,
+        };
+
+        Duration::from_secs(seconds)
+    }
+}
+
+/// The in-process cache of the JSON responses of a chain, keyed by route and query.\
+/// The clones share the same responses, so the cache can be invalidated from the cron jobs and the ingestion sinks.
+#[derive(Clone)]
+pub struct ResponseCache {
+    entries: Arc<DashMap<String, CachedResponse>>,
+    ttls: Arc<HashMap<CacheRoute, Duration>>,
+    /// The count of the invalidations of each route. The responses made before the last invalidation of their route are stale.
+    generations: Arc<HashMap<CacheRoute, AtomicU64>>,
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        Self::new(&CacheTtlConfig::default())
+    }
+}
+
+impl ResponseCache {
+    /// Creates an empty cache with given TTLs.
+    pub fn new(config: &CacheTtlConfig) -> Self {
+        Self {
+            entries: Default::default(),
+            ttls: Arc::new(CacheRoute::ALL.into_iter().map(|route| (route, route.ttl(config))).collect()),
+            generations: Arc::new(CacheRoute::ALL.into_iter().map(|route| (route, AtomicU64::new(0))).collect()),
+        }
+    }
+
+    /// Returns the cached response of the route for given query, the routes without parameters use an empty query.\
+    /// The response is made by `make` and cached if there is no fresh response. The errors are not cached.
+    pub async fn get_or_insert<T, F, Fut>(&self, route: CacheRoute, query: &str, make: F) -> Result<CachedResponse, TNRAppError>
+    where
+        T: Serialize,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<TNRAppSuccessResponse<T>, TNRAppError>>,
+    {
+        let key = format!("{}?{}", route.as_str(), query);
+
+        // The generation is read before the response is made, so a response made from the data before an invalidation isn't cached.
+        let generation = self.generation(route);
+
+        if let Some(cached) = self.entries.get(&key) {
+            if cached.is_fresh(generation) {
+                return Ok(cached.clone());
+            }
+        }
+
+        let body = serde_json::to_string(&make().await?).map_err(|e| format!("Cannot serialize the response: {e}"))?;
+        let ttl = self.ttls.get(&route).copied().unwrap_or_default();
+
+        let cached = CachedResponse {
+            route,
+            body: Arc::new(body),
+            expires_at: Instant::now() + ttl,
+            generation,
+        };
+
+        // The route is invalidated while the response is made, it is returned without caching and not to be reused by the clients.
+        if self.generation(route) != generation {
+            return Ok(CachedResponse {
+                expires_at: Instant::now(),
+                ..cached
+            });
+        }
+
+        if self.entries.len() >= MAX_CACHED_RESPONSES {
+            self.entries.retain(|_, cached| cached.is_fresh(self.generation(cached.route)));
+        }
+
+        if self.entries.len() < MAX_CACHED_RESPONSES {
+            self.entries.insert(key, cached.clone());
+        }
+
+        Ok(cached)
+    }
+
+    /// Invalidates the cached responses of given routes, after the data they are made of is updated.\
+    /// The stale responses are not dropped at once, they are replaced by the next responses of their queries.
+    pub fn invalidate(&self, routes: &[CacheRoute]) {
+        for route in routes {
+            if let Some(generation) = self.generations.get(route) {
+                generation.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    /// Returns the count of the invalidations of given route.
+    fn generation(&self, route: CacheRoute) -> u64 {
+        self.generations
+            .get(&route)
+            .map(|generation| generation.load(Ordering::SeqCst))
+            .unwrap_or_default()
+    }
+}
+
+/// A cached JSON response.
+#[derive(Clone)]
+pub struct CachedResponse {
+    route: CacheRoute,
+    body: Arc<String>,
+    expires_at: Instant,
+    /// The generation of the route the response is made in.
+    generation: u64,
+}
+
+impl CachedResponse {
+    /// Returns `true` if the response isn't expired and its route isn't invalidated after it was made.
+    fn is_fresh(&self, generation: u64) -> bool {
+        self.generation == generation && self.expires_at > Instant::now()
+    }
+
+    /// Returns the JSON body of the response.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Returns the data of the response, parsed from its JSON body.
+    pub fn data<T: DeserializeOwned>(&self) -> Result<T, String> {
+        let mut body: Value = serde_json::from_str(&self.body).map_err(|e| format!("Cannot parse the cached response: {e}"))?;
+
+        serde_json::from_value(body["data"].take()).map_err(|e| format!("Cannot parse the cached response data: {e}"))
+    }
+}
+
+impl Responder for CachedResponse {
+    type Body = BoxBody;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+        // The clients may reuse the response until the cached one expires.
+        let max_age = self.expires_at.saturating_duration_since(Instant::now());
+
+        json_response(req, self.body.to_string(), Some(max_age))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache() -> ResponseCache {
+        ResponseCache::new(&CacheTtlConfig {
+            dashboard: 60,
+            stats: 60,
+            validators_bonded: 60,
+            params: 60,
+            inflation: 60,
+        })
+    }
+
+    /// Returns the cached body of the route, a new response has the count of the calls of `make` so far.
+    async fn get(cache: &ResponseCache, route: CacheRoute, query: &str, calls: &AtomicU64) -> String {
+        let cached = cache
+            .get_or_insert(route, query, move || async move {
+                let call = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                Ok(TNRAppSuccessResponse::new(call, None))
+            })
+            .await
+            .unwrap();
+
+        cached.body().to_string()
+    }
+
+    #[tokio::test]
+    async fn get_or_insert_reuses_the_response_until_its_route_is_invalidated() {
+        let (cache, calls) = (cache(), AtomicU64::new(0));
+
+        let first = get(&cache, CacheRoute::Stats, "", &calls).await;
+        assert_eq!(get(&cache, CacheRoute::Stats, "", &calls).await, first);
+
+        cache.invalidate(&[CacheRoute::Dashboard]);
+        assert_eq!(get(&cache, CacheRoute::Stats, "", &calls).await, first);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        cache.invalidate(&[CacheRoute::Stats]);
+        assert_ne!(get(&cache, CacheRoute::Stats, "", &calls).await, first);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn get_or_insert_keeps_a_response_for_each_route_and_query() {
+        let (cache, calls) = (cache(), AtomicU64::new(0));
+
+        get(&cache, CacheRoute::ValidatorsBonded, "a", &calls).await;
+        get(&cache, CacheRoute::ValidatorsBonded, "b", &calls).await;
+        get(&cache, CacheRoute::Params, "a", &calls).await;
+        get(&cache, CacheRoute::ValidatorsBonded, "a", &calls).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn get_or_insert_does_not_cache_the_response_made_during_an_invalidation() {
+        let (cache, calls) = (cache(), AtomicU64::new(0));
+        let cache_ref = &cache;
+
+        let cached = cache
+            .get_or_insert(CacheRoute::Stats, "", move || async move {
+                cache_ref.invalidate(&[CacheRoute::Stats]);
+                Ok(TNRAppSuccessResponse::new(0, None))
+            })
+            .await
+            .unwrap();
+
+        assert!(!cached.is_fresh(cache.generation(CacheRoute::Stats)));
+
+        get(&cache, CacheRoute::Stats, "", &calls).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn get_or_insert_does_not_cache_the_errors() {
+        let (cache, calls) = (cache(), AtomicU64::new(0));
+
+        let result = cache
+            .get_or_insert(CacheRoute::Inflation, "", || async {
+                Err::<TNRAppSuccessResponse<u64>, _>(TNRAppError::from("Cannot reach the node."))
+            })
+            .await;
+
+        assert!(result.is_err());
+
+        get(&cache, CacheRoute::Inflation, "", &calls).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use utoipa::ToSchema;
 use versions::SemVer;
 
+use crate::cache::ResponseCache;
 use crate::database::DatabaseTR;
+use crate::fetch::blocks::BlockTxResultsCache;
+use crate::fetch::validator_signing::ValidatorSetCache;
+use crate::health::HealthTracker;
 
 /// The struct that represents any Cosmos based chain.
 #[derive(Clone)]
@@ -12,6 +17,14 @@ pub struct Chain {
     /// The request client.
     pub database: DatabaseTR,
     pub config: ChainConfig,
+    /// The cached responses of the chain routes.
+    pub cache: ResponseCache,
+    /// The records of the cron jobs and the event subscription, read by the health routes.
+    pub health: HealthTracker,
+    /// The transaction results of the last block fetched for the transactions received by the event subscription.
+    pub block_tx_results: BlockTxResultsCache,
+    /// The active validator sets of the recent blocks, by their hashes.
+    pub validator_sets: ValidatorSetCache,
 }
 
 async fn get_sdk_ver(rest_url: &str, client: reqwest::Client) -> Result<SemVer, String> {
@@ -83,12 +96,17 @@ impl Chain {
             manual_versioning,
             decimals,
             decimals_pow,
+            webhooks: ic.webhooks.unwrap_or_default(),
         };
 
         Ok(Self {
             client: Default::default(),
             database,
             config: chain_config,
+            cache: ResponseCache::default(),
+            health: HealthTracker::default(),
+            block_tx_results: BlockTxResultsCache::default(),
+            validator_sets: ValidatorSetCache::default(),
         })
     }
 }
@@ -134,9 +152,12 @@ pub struct ChainConfig {
     pub decimals: u8,
     /// The decimals of the native coin of the chain.
     pub decimals_pow: u64,
+    /// The URLs the ingested data is posted to.
+    pub webhooks: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+/// The configuration of a chain, as it is written in `Chains.yml`.
+#[derive(Serialize, Deserialize, Clone, ToSchema)]
 pub struct IntermediateChainConfig {
     pub name: String,
     pub logo: String,
@@ -150,8 +171,11 @@ pub struct IntermediateChainConfig {
     pub grpc_url: Option<String>,
     pub archive_url: String,
     pub decimals: Option<u8>,
+    /// The Cosmos SDK version, it is read from the node if it is not set. Eg: `"0.45.16"`
+    #[schema(value_type = Option<String>)]
     pub sdk_version: Option<SemVer>,
     pub jsonrpc_url: Option<String>,
     pub symbol: String,
     pub main_denom: Option<String>,
+    pub webhooks: Option<Vec<String>>,
 }
@@ -0,0 +1,365 @@
+use std::net::{IpAddr, SocketAddr};
+use std::path::Path;
+use std::str::FromStr;
+
+use openssl::ssl::{SslAcceptor, SslAcceptorBuilder, SslFiletype, SslMethod};
+use serde::Deserialize;
+
+/// The config file read if `SERVER_CONFIG` is not set. The defaults are used if it doesn't exist.
+const DEFAULT_CONFIG_PATH: &str = "Server.yml";
+
+/// The origin that allows any origin.
+const ANY_ORIGIN: &str = "*";
+
+/// The configuration of the servers.\
+/// It is read from `Server.yml`, or the file at `SERVER_CONFIG`, and the `SERVER_*` environment variables override it.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    /// The address the REST API listens on. Eg: `"127.0.0.1:8080"`
+    pub http_address: SocketAddr,
+    /// The address the websocket server listens on. Eg: `"127.0.0.1:8081"`
+    pub ws_address: SocketAddr,
+    /// The address the gRPC server listens on. Eg: `"127.0.0.1:8082"`
+    pub grpc_address: SocketAddr,
+    /// The certificate of the REST API. It is served over plain HTTP if not set.
+    pub tls: Option<TlsConfig>,
+    /// The origins allowed to request the REST API from browsers. `"*"` allows any origin.
+    pub allowed_origins: Vec<String>,
+    /// The count of the REST API workers. It is the count of the CPU cores if not set.
+    pub workers: Option<usize>,
+    pub cron: CronConfig,
+    /// The count of the socket events kept for the slow websocket and gRPC clients, before they lag behind.
+    pub broadcast_capacity: usize,
+    /// The addresses of the proxies the `X-Forwarded-For` header is trusted from. Eg: `["10.0.0.2"]`\
+    /// The clients are rate limited by their connection addresses if it is empty.
+    pub trusted_proxies: Vec<IpAddr>,
+    pub cache_ttl: CacheTtlConfig,
+    pub rate_limit: RateLimitConfig,
+    pub health: HealthConfig,
+    pub batch: BatchConfig,
+    /// The count of the websocket connections accepted at once, the ones over it are closed right away.
+    pub ws_max_connections: usize,
+    /// The seconds between the retries of the chains failed to initialize.
+    pub chain_init_retry_secs: u64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+    /// The path of the PEM encoded certificate chain.
+    pub cert_path: String,
+    /// The path of the PEM encoded private key.
+    pub key_path: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct CronConfig {
+    /// The seconds between the runs of the validator, params, price and dashboard jobs.
+    pub interval_secs: u64,
+    /// The seconds between the runs of the blocks backfill job.
+    pub backfill_interval_secs: u64,
+}
+
+/// The seconds the responses of the cached routes are kept for, if they are not invalidated before.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheTtlConfig {
+    pub dashboard: u64,
+    pub stats: u64,
+    pub validators_bonded: u64,
+    pub params: u64,
+    pub inflation: u64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct RateLimitConfig {
+    /// The requests per minute of the clients without an API key.
+    pub anonymous_rpm: u32,
+    /// The requests per minute of the clients with an API key, unless the key has its own limit.
+    pub keyed_rpm: u32,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct HealthConfig {
+    /// The count of the blocks the ingestion may lag behind the node, before the chain is degraded.
+    pub max_block_lag: u64,
+    /// The seconds the websocket of a node may not send an event for, before the chain is degraded.
+    pub max_event_age_secs: i64,
+    /// The seconds a health report is reused for, so the nodes are not probed by every request. `0` disables it.
+    pub cache_secs: u64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct BatchConfig {
+    /// The maximum count of the items in a batch.
+    pub max_items: usize,
+    /// The count of the items of a batch that are run at the same time.
+    pub concurrency: usize,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            http_address: SocketAddr::from(([127, 0, 0, 1], 8080)),
+            ws_address: SocketAddr::from(([127, 0, 0, 1], 8081)),
+            grpc_address: SocketAddr::from(([127, 0, 0, 1], 8082)),
+            tls: None,
+            allowed_origins: vec![ANY_ORIGIN.to_string()],
+            workers: None,
+            cron: CronConfig::default(),
+            broadcast_capacity: 100,
+            trusted_proxies: vec![],
+            cache_ttl: CacheTtlConfig::default(),
+            rate_limit: RateLimitConfig::default(),
+            health: HealthConfig::default(),
+            batch: BatchConfig::default(),
+            ws_max_connections: 1000,
+            chain_init_retry_secs: 60,
+        }
+    }
+}
+
+impl Default for CronConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 120,
+            backfill_interval_secs: 120,
+        }
+    }
+}
+
+impl Default for CacheTtlConfig {
+    fn default() -> Self {
+        Self {
+            dashboard: 60,
+            stats: 5,
+            validators_bonded: 60,
+            params: 300,
+            inflation: 300,
+        }
+    }
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            anonymous_rpm: 120,
+            keyed_rpm: 1200,
+        }
+    }
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            max_block_lag: 20,
+            max_event_age_secs: 120,
+            cache_secs: 5,
+        }
+    }
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        Self {
+            max_items: 20,
+            concurrency: 5,
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Reads the config file, applies the environment variables and validates the result.\
+    /// All the problems are returned at once, one per line.
+    pub fn load() -> Result<Self, String> {
+        let mut config = match std::env::var("SERVER_CONFIG") {
+            Ok(path) => Self::from_file(&path)?,
+            Err(_) if Path::new(DEFAULT_CONFIG_PATH).exists() => Self::from_file(DEFAULT_CONFIG_PATH)?,
+            Err(_) => Self::default(),
+        };
+
+        let mut errors = config.apply_env();
+        errors.extend(config.validate());
+
+        match errors.is_empty() {
+            true => Ok(config),
+            false => Err(errors.join("\n")),
+        }
+    }
+
+    /// Reads the config from given YAML file. The missing fields have their default values.
+    fn from_file(path: &str) -> Result<Self, String> {
+        let yml = std::fs::read_to_string(path).map_err(|e| format!("Cannot read the server config at {path}: {e}"))?;
+
+        serde_yaml::from_str(&yml).map_err(|e| format!("Invalid server config at {path}: {e}"))
+    }
+
+    /// Overrides the config by the `SERVER_*` environment variables, and returns the invalid ones.
+    fn apply_env(&mut self) -> Vec<String> {
+        let mut errors = vec![];
+
+        override_from_env("SERVER_HTTP_ADDRESS", &mut self.http_address, &mut errors);
+        override_from_env("SERVER_WS_ADDRESS", &mut self.ws_address, &mut errors);
+        override_from_env("SERVER_GRPC_ADDRESS", &mut self.grpc_address, &mut errors);
+        override_from_env("SERVER_BROADCAST_CAPACITY", &mut self.broadcast_capacity, &mut errors);
+        override_from_env("SERVER_CRON_INTERVAL_SECS", &mut self.cron.interval_secs, &mut errors);
+        override_from_env("SERVER_CRON_BACKFILL_INTERVAL_SECS", &mut self.cron.backfill_interval_secs, &mut errors);
+        override_from_env("SERVER_CACHE_TTL_DASHBOARD", &mut self.cache_ttl.dashboard, &mut errors);
+        override_from_env("SERVER_CACHE_TTL_STATS", &mut self.cache_ttl.stats, &mut errors);
+        override_from_env("SERVER_CACHE_TTL_VALIDATORS_BONDED", &mut self.cache_ttl.validators_bonded, &mut errors);
+        override_from_env("SERVER_CACHE_TTL_PARAMS", &mut self.cache_ttl.params, &mut errors);
+        override_from_env("SERVER_CACHE_TTL_INFLATION", &mut self.cache_ttl.inflation, &mut errors);
+        override_from_env("SERVER_RATE_LIMIT_ANONYMOUS_RPM", &mut self.rate_limit.anonymous_rpm, &mut errors);
+        override_from_env("SERVER_RATE_LIMIT_KEYED_RPM", &mut self.rate_limit.keyed_rpm, &mut errors);
+        override_from_env("SERVER_HEALTH_MAX_BLOCK_LAG", &mut self.health.max_block_lag, &mut errors);
+        override_from_env("SERVER_HEALTH_MAX_EVENT_AGE_SECS", &mut self.health.max_event_age_secs, &mut errors);
+        override_from_env("SERVER_HEALTH_CACHE_SECS", &mut self.health.cache_secs, &mut errors);
+        override_from_env("SERVER_BATCH_MAX_ITEMS", &mut self.batch.max_items, &mut errors);
+        override_from_env("SERVER_BATCH_CONCURRENCY", &mut self.batch.concurrency, &mut errors);
+        override_from_env("SERVER_WS_MAX_CONNECTIONS", &mut self.ws_max_connections, &mut errors);
+        override_from_env("SERVER_CHAIN_INIT_RETRY_SECS", &mut self.chain_init_retry_secs, &mut errors);
+
+        if let Ok(workers) = std::env::var("SERVER_WORKERS") {
+            match workers.parse() {
+                Ok(workers) => self.workers = Some(workers),
+                Err(e) => errors.push(format!("SERVER_WORKERS is not valid: {e}")),
+            }
+        }
+
+        if let Ok(origins) = std::env::var("SERVER_ALLOWED_ORIGINS") {
+            self.allowed_origins = origins.split(',').map(|origin| origin.trim().to_string()).collect();
+        }
+
+        if let Ok(proxies) = std::env::var("SERVER_TRUSTED_PROXIES") {
+            let proxies: Result<Vec<IpAddr>, _> = proxies
+                .split(',')
+                .map(str::trim)
+                .filter(|proxy| !proxy.is_empty())
+                .map(str::parse)
+                .collect();
+
+            match proxies {
+                Ok(proxies) => self.trusted_proxies = proxies,
+                Err(e) => errors.push(format!("SERVER_TRUSTED_PROXIES is not valid: {e}")),
+            }
+        }
+
+        match (std::env::var("SERVER_TLS_CERT_PATH"), std::env::var("SERVER_TLS_KEY_PATH")) {
+            (Ok(cert_path), Ok(key_path)) => self.tls = Some(TlsConfig { cert_path, key_path }),
+            (Err(_), Err(_)) => {}
+            _ => errors.push("SERVER_TLS_CERT_PATH and SERVER_TLS_KEY_PATH must be set together.".to_string()),
+        }
+
+        errors
+    }
+
+    /// Returns the problems of the config.
+    fn validate(&self) -> Vec<String> {
+        let mut errors = vec![];
+
+        let addresses = [
+            ("http_address", self.http_address),
+            ("ws_address", self.ws_address),
+            ("grpc_address", self.grpc_address),
+        ];
+        for (i, (name, address)) in addresses.iter().enumerate() {
+            if let Some((other, _)) = addresses[..i].iter().find(|(_, other)| other == address) {
+                errors.push(format!("{name} and {other} are the same address, {address}."));
+            }
+        }
+
+        if let Some(tls) = &self.tls {
+            if let Err(e) = tls.acceptor() {
+                errors.push(e);
+            }
+        }
+
+        if self.allowed_origins.is_empty() {
+            errors.push(format!("allowed_origins is empty, use [\"{ANY_ORIGIN}\"] to allow any origin."));
+        }
+        if self.allowed_origins.len() > 1 && self.allowed_origins.iter().any(|origin| origin == ANY_ORIGIN) {
+            errors.push(format!("allowed_origins cannot have \"{ANY_ORIGIN}\" with other origins."));
+        }
+        for origin in self.allowed_origins.iter().filter(|origin| *origin != ANY_ORIGIN) {
+            let is_valid = (origin.starts_with("http://") || origin.starts_with("https://")) && !origin.ends_with('/');
+            if !is_valid {
+                errors.push(format!("{origin} is not a valid origin. Eg: \"https://explorer.testnetrun.com\""));
+            }
+        }
+
+        if self.workers == Some(0) {
+            errors.push("workers must be greater than 0.".to_string());
+        }
+        if self.cron.interval_secs == 0 {
+            errors.push("cron.interval_secs must be greater than 0.".to_string());
+        }
+        if self.cron.backfill_interval_secs == 0 {
+            errors.push("cron.backfill_interval_secs must be greater than 0.".to_string());
+        }
+        if self.broadcast_capacity == 0 {
+            errors.push("broadcast_capacity must be greater than 0.".to_string());
+        }
+        if self.rate_limit.anonymous_rpm == 0 {
+            errors.push("rate_limit.anonymous_rpm must be greater than 0.".to_string());
+        }
+        if self.rate_limit.keyed_rpm == 0 {
+            errors.push("rate_limit.keyed_rpm must be greater than 0.".to_string());
+        }
+        if self.health.max_event_age_secs <= 0 {
+            errors.push("health.max_event_age_secs must be greater than 0.".to_string());
+        }
+        if self.batch.max_items == 0 {
+            errors.push("batch.max_items must be greater than 0.".to_string());
+        }
+        if self.batch.concurrency == 0 {
+            errors.push("batch.concurrency must be greater than 0.".to_string());
+        }
+        if self.ws_max_connections == 0 {
+            errors.push("ws_max_connections must be greater than 0.".to_string());
+        }
+        if self.chain_init_retry_secs == 0 {
+            errors.push("chain_init_retry_secs must be greater than 0.".to_string());
+        }
+
+        errors
+    }
+
+    /// Returns `true` if any origin is allowed.
+    pub fn allows_any_origin(&self) -> bool {
+        self.allowed_origins.iter().any(|origin| origin == ANY_ORIGIN)
+    }
+}
+
+impl TlsConfig {
+    /// Returns the TLS acceptor of the certificate.
+    pub fn acceptor(&self) -> Result<SslAcceptorBuilder, String> {
+        let mut builder = SslAcceptor::mozilla_intermediate(SslMethod::tls()).map_err(|e| format!("Cannot create the TLS acceptor: {e}"))?;
+
+        builder
+            .set_private_key_file(&self.key_path, SslFiletype::PEM)
+            .map_err(|e| format!("Cannot read the TLS key at {}: {e}", self.key_path))?;
+        builder
+            .set_certificate_chain_file(&self.cert_path)
+            .map_err(|e| format!("Cannot read the TLS certificate at {}: {e}", self.cert_path))?;
+
+        Ok(builder)
+    }
+}
+
+/// Overrides the value by given environment variable, if it is set.
+fn override_from_env<T: FromStr>(var: &str, value: &mut T, errors: &mut Vec<String>)
+where
+    T::Err: std::fmt::Display,
+{
+    if let Ok(raw) = std::env::var(var) {
+        match raw.parse() {
+            Ok(parsed) => *value = parsed,
+            Err(e) => errors.push(format!("{var} is not valid: {e}")),
+        }
+    }
+}
@@ -1,45 +1,66 @@
-use std::time::Duration;
+use std::fmt::Display;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
 use tokio::{spawn, time::sleep};
 
 use crate::chain::Chain;
+use crate::config::CronConfig;
+use crate::metrics::METRICS;
 
 impl Chain {
-    pub fn cron_jobs_all(&self) {
+    /// Spawns all the cron jobs of the chain, and returns their tasks to be aborted when the chain is stopped.
+    pub fn cron_jobs_all(&self, config: &CronConfig) -> Vec<JoinHandle<()>> {
         // ALL THE cron jobs will be spawned here.
 
+        // The jobs are registered before their first runs, so a job that never succeeds is reported by the health routes.
+        for job in ["validator", "params", "val_supported_chains", "chain_price_history", "chain_dashboard"] {
+            self.health.register_cron_job(job, config.interval_secs);
+        }
+        self.health.register_cron_job("backfill_blocks", config.backfill_interval_secs);
+
         // Validator cron job.
-        let duration = Duration::from_secs(120);
+        let duration = Duration::from_secs(config.interval_secs);
         let clone_chain = self.clone();
-        spawn(async move {
+        let jobs = spawn(async move {
             loop {
                 tokio::join!(
                     async {
                         let name = clone_chain.config.name.clone();
-                        if let Err(error) = clone_chain.cron_job_validator().await {
+                        let result = clone_chain.run_cron_job("validator", clone_chain.cron_job_validator()).await;
+                        if let Err(error) = result {
                             tracing::error!("Chain {name} validator cronjob error: {error}")
                         };
                     },
                     async {
                         let name = clone_chain.config.name.clone();
-                        if let Err(error) = clone_chain.cron_job_params().await {
+                        let result = clone_chain.run_cron_job("params", clone_chain.cron_job_params()).await;
+                        if let Err(error) = result {
                             tracing::error!("Chain {name} params cronjob error: {error}")
                         };
                     },
                     async {
                         let name = clone_chain.config.name.clone();
-                        if let Err(error) = clone_chain.cron_job_val_supported_chains().await {
+                        let result = clone_chain
+                            .run_cron_job("val_supported_chains", clone_chain.cron_job_val_supported_chains())
+                            .await;
+                        if let Err(error) = result {
                             tracing::error!("Chain {name} validator supported chains error: {error}")
                         };
                     },
                     async {
                         let name = clone_chain.config.name.clone();
-                        if let Err(error) = clone_chain.cron_job_chain_price_history().await {
+                        let result = clone_chain
+                            .run_cron_job("chain_price_history", clone_chain.cron_job_chain_price_history())
+                            .await;
+                        if let Err(error) = result {
                             tracing::error!("Chain {name} chain price history cronjob error: {error}")
                         };
                     },
                     async {
                         let name = clone_chain.config.name.clone();
-                        if let Err(error) = clone_chain.cron_job_chain_dashboard().await {
+                        let result = clone_chain.run_cron_job("chain_dashboard", clone_chain.cron_job_chain_dashboard()).await;
+                        if let Err(error) = result {
                             tracing::error!("Chain {name} chain dashboard info cronjob error: {error}")
                         };
                     },
@@ -47,5 +68,33 @@ impl Chain {
                 sleep(duration).await;
             }
         });
+
+        // Block backfill cron job.
+        // It runs in its own loop, since filling a big gap takes much longer than the jobs above.
+        let duration = Duration::from_secs(config.backfill_interval_secs);
+        let clone_chain = self.clone();
+        let backfill = spawn(async move {
+            loop {
+                let name = clone_chain.config.name.clone();
+                let result = clone_chain.run_cron_job("backfill_blocks", clone_chain.cron_job_backfill_blocks()).await;
+                if let Err(error) = result {
+                    tracing::error!("Chain {name} blocks backfill cronjob error: {error}")
+                };
+                sleep(duration).await;
+            }
+        });
+
+        vec![jobs, backfill]
+    }
+
+    /// Runs a cron job, and records its result for the health routes and its duration in the metrics.
+    async fn run_cron_job<T, E: Display>(&self, job: &'static str, run: impl Future<Output = Result<T, E>>) -> Result<T, E> {
+        let started_at = Instant::now();
+        let result = run.await;
+
+        self.health.record_cron_job(job, &result);
+        METRICS.observe_cron_job(&self.config.name, job, started_at, result.is_ok());
+
+        result
     }
 }
@@ -0,0 +1,119 @@
+use futures::{stream, StreamExt};
+
+use crate::chain::Chain;
+use crate::database::CheckpointForDb;
+use crate::routes::TNRAppError;
+
+/// The name of the checkpoint that keeps the height up to which the blocks collection has no gaps.
+const BLOCKS_BACKFILL_CHECKPOINT: &str = "blocks_backfill";
+
+/// The name of the checkpoint that keeps the height the latest run of the ingested blocks with no gaps starts at.
+const BLOCKS_INGESTION_CHECKPOINT: &str = "blocks_ingestion";
+
+/// The count of heights checked for gaps at once.
+const BLOCKS_BACKFILL_WINDOW: u64 = 1000;
+
+/// The count of blocks fetched from the RPC node at the same time.
+const BLOCKS_BACKFILL_CONCURRENCY: usize = 8;
+
+impl Chain {
+    /// Fills the missing blocks in the blocks collection, starting from the saved checkpoint.
+    /// The checkpoint is only moved forward when a whole window is filled, so a failed run is resumed from the same window.
+    pub async fn cron_job_backfill_blocks(&self) -> Result<(), TNRAppError> {
+        let sync_info = self.get_node_sync_info().await?;
+
+        let lowest_height = sync_info.earliest_block_height.max(1);
+
+        // The signatures of the latest block are not committed yet, the socket saves it when the next block arrives.
+        let highest_height = sync_info.latest_block_height.saturating_sub(1);
+
+        let mut from = match self.database.find_checkpoint(BLOCKS_BACKFILL_CHECKPOINT).await? {
+            Some(checkpoint) => (checkpoint.height + 1).max(lowest_height),
+            None => lowest_height,
+        };
+
+        while from <= highest_height {
+            let to = (from + BLOCKS_BACKFILL_WINDOW - 1).min(highest_height);
+
+            let missing_heights = self.database.find_missing_block_heights(from, to).await?;
+
+            if !missing_heights.is_empty() {
+                tracing::info!(
+                    "{}: backfilling {} blocks between {from} and {to}",
+                    self.config.name,
+                    missing_heights.len()
+                );
+            }
+
+            let failed_count = stream::iter(missing_heights)
+                .map(|height| async move {
+                    let block = self.get_block_for_db_by_height(height).await?;
+
+                    // The transactions are saved before the block, so the height stays missing and is retried if they fail.
+                    if block.tx_count > 0 {
+                        self.store_txs_by_height(height)
+                            .await
+                            .map_err(|error| format!("Cannot backfill transactions at {height}: {error}"))?;
+                    }
+
+                    if let Err(error) = self.store_block_signing(&block).await {
+                        tracing::error!("{}: cannot backfill validator signing at {height}: {error}", self.config.name);
+                    }
+
+                    if let Err(error) = self.store_block_events_by_height(height).await {
+                        tracing::error!("{}: cannot backfill block events at {height}: {error}", self.config.name);
+                    }
+
+                    self.database.upsert_block(block).await?;
+
+                    Ok::<(), TNRAppError>(())
+                })
+                .buffer_unordered(BLOCKS_BACKFILL_CONCURRENCY)
+                .filter(|result| futures::future::ready(result.is_err()))
+                .count()
+                .await;
+
+            if failed_count > 0 {
+                return Err(format!("Cannot backfill {failed_count} blocks between {from} and {to}.").into());
+            }
+
+            self.database
+                .upsert_checkpoint(CheckpointForDb {
+                    name: BLOCKS_BACKFILL_CHECKPOINT.to_string(),
+                    height: to,
+                })
+                .await?;
+
+            from = to + 1;
+        }
+
+        Ok(())
+    }
+
+    /// Saves the height of an ingested block as the start of the ingested blocks with no gaps, if the block before it is missing.
+    pub async fn update_ingestion_checkpoint(&self, height: u64) -> Result<(), String> {
+        if height > 1 && self.database.find_missing_block_heights(height - 1, height - 1).await?.is_empty() {
+            return Ok(());
+        }
+
+        self.database
+            .upsert_checkpoint(CheckpointForDb {
+                name: BLOCKS_INGESTION_CHECKPOINT.to_string(),
+                height,
+            })
+            .await
+    }
+
+    /// Returns the lowest height the blocks collection has every block since, up to the latest ingested block.\
+    /// It is the start of the ingested blocks until the backfill reaches them, and the first saved block after that.
+    /// `None` is returned if no block is saved yet.
+    pub async fn find_contiguous_blocks_start(&self) -> Result<Option<u64>, TNRAppError> {
+        let backfilled = self.database.find_checkpoint(BLOCKS_BACKFILL_CHECKPOINT).await?;
+        let ingested = self.database.find_checkpoint(BLOCKS_INGESTION_CHECKPOINT).await?;
+
+        match ingested {
+            Some(ingested) if backfilled.map_or(true, |backfilled| backfilled.height + 1 < ingested.height) => Ok(Some(ingested.height)),
+            _ => Ok(self.database.find_first_block_height_since(0).await?),
+        }
+    }
+}
@@ -1,3 +1,4 @@
 mod all;
+mod backfill;
 mod params;
 mod validator;
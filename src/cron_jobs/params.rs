@@ -1,13 +1,17 @@
+use chrono::Utc;
 use tokio::join;
 
+use crate::cache::CacheRoute;
 use crate::chain::Chain;
 use crate::database::{
-    ChainDashboardInfoForDb, DistributionParamsForDb, GovParamsForDb, ParamsForDb, SlashingParamsForDb, StakingParamsForDb,
+    ChainDashboardInfoForDb, DailyTokenPriceForDb, DistributionParamsForDb, GovParamsForDb, ParamsForDb, SlashingParamsForDb, StakingParamsForDb,
     TokenMarketPriceHistoriesForDb,
 };
+use crate::routes::TNRAppError;
+use crate::utils::{day_start_ms, DAY_MS};
 
 impl Chain {
-    pub async fn cron_job_params(&self) -> Result<(), String> {
+    pub async fn cron_job_params(&self) -> Result<(), TNRAppError> {
         let all_params = self.get_params_all().await?;
 
         self.database
@@ -42,16 +46,22 @@ impl Chain {
             })
             .await?;
 
+        self.cache.invalidate(&[CacheRoute::Params]);
+
         Ok(())
     }
 
-    pub async fn cron_job_chain_price_history(&self) -> Result<(), String> {
+    pub async fn cron_job_chain_price_history(&self) -> Result<(), TNRAppError> {
         let token_id = self
             .config
             .gecko
             .clone()
             .ok_or(format!("{} gecko token id not found", self.config.name))?;
 
+        if let Err(e) = self.save_daily_token_prices(token_id.clone()).await {
+            tracing::error!("Error occured on saving the daily token prices {}", e);
+        }
+
         let market_chart = match self.gecko_token_market_chart(token_id, None, None).await {
             Ok(res) => res,
             Err(e) => {
@@ -64,7 +74,7 @@ impl Chain {
             .insert_market_price_history(TokenMarketPriceHistoriesForDb::for_db(market_chart, self.config.name.clone()))
             .await
         {
-            Ok(_) => {}
+            Ok(_) => self.cache.invalidate(&[CacheRoute::Dashboard, CacheRoute::Stats]),
             Err(e) => {
                 tracing::error!("Error occured on inserting token prices to db {}", e);
             }
@@ -72,7 +82,33 @@ impl Chain {
         Ok(())
     }
 
-    pub async fn cron_job_chain_dashboard(&self) -> Result<(), String> {
+    /// Saves the daily prices of the chain token since the last saved day, or its whole price history if none is saved.\
+    /// The prices are kept for the exports, the market chart of the dashboard covers only the last day.
+    async fn save_daily_token_prices(&self, token_id: String) -> Result<(), TNRAppError> {
+        // The day of the last saved price is fetched again, it might be saved before the day ended.
+        let days = match self.database.find_last_daily_token_price(&self.config.name).await? {
+            Some(last_price) => ((Utc::now().timestamp_millis() - last_price.timestamp) / DAY_MS + 1).to_string(),
+            None => String::from("max"),
+        };
+
+        // The latest price is saved as the price of the current day, until the day ends.
+        let prices = self
+            .gecko_token_daily_prices(token_id, days)
+            .await?
+            .into_iter()
+            .map(|price| DailyTokenPriceForDb {
+                token: self.config.name.clone(),
+                timestamp: day_start_ms(price.timestamp as i64),
+                value: price.value,
+            })
+            .collect();
+
+        self.database.upsert_daily_token_prices(prices).await?;
+
+        Ok(())
+    }
+
+    pub async fn cron_job_chain_dashboard(&self) -> Result<(), TNRAppError> {
         let (inflation_rate, apr, staking_poll, total_supply, community_poll) = join!(
             self.get_inflation_rate(),
             self.get_apr(),
@@ -120,8 +156,9 @@ impl Chain {
             community_pool,
         };
 
-        if let Err(e) = self.database.upsert_chain_dashboard_info(chain_dashboard_info).await {
-            tracing::error!("Error occured on inserting chain dashboard info to db {}", e);
+        match self.database.upsert_chain_dashboard_info(chain_dashboard_info).await {
+            Ok(_) => self.cache.invalidate(&[CacheRoute::Dashboard]),
+            Err(e) => tracing::error!("Error occured on inserting chain dashboard info to db {}", e),
         };
 
         Ok(())
@@ -6,23 +6,25 @@ use mongodb::bson::doc;
 use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
 
+use crate::cache::CacheRoute;
 use crate::chain::Chain;
 use crate::database::{ValidatorForDb, VotingPowerForDb};
 use crate::fetch::amount_util::TnrDecimal;
 use crate::fetch::evm::EvmSupportedChains;
 use crate::fetch::others::InternalStakingPool;
 use crate::fetch::validators::{ValidatorListValidator, ValidatorListValidatorCommission, ValidatorListValidatorCommissionRates, ValidatorStatus};
-use crate::routes::PaginationData;
+use crate::routes::{PaginationData, TNRAppError};
 use crate::utils::{convert_consensus_pubkey_to_consensus_address, convert_consensus_pubkey_to_hex_address, get_validator_logo, str_to_dec};
 
 impl Chain {
-    pub async fn cron_job_validator(&self) -> Result<(), String> {
+    pub async fn cron_job_validator(&self) -> Result<(), TNRAppError> {
         let resp = self
             .get_validators_unspecified(PaginationData {
                 cursor: None,
                 offset: None,
                 limit: Some(10000),
                 direction: None,
+                ..Default::default()
             })
             .await?;
         let staking_pool = self.get_staking_pool().await?.value;
@@ -64,10 +66,12 @@ impl Chain {
 
         join_all(db_jobs).await;
 
+        self.cache.invalidate(&[CacheRoute::ValidatorsBonded, CacheRoute::Stats]);
+
         Ok(())
     }
 
-    async fn to_job_validator(&self, validator: ValidatorListValidator, staking_pool: InternalStakingPool) -> Result<JobValidator, String> {
+    async fn to_job_validator(&self, validator: ValidatorListValidator, staking_pool: InternalStakingPool) -> Result<JobValidator, TNRAppError> {
         let val_delegator_shares = self.format_delegator_share(&str_to_dec(validator.delegator_shares.as_str()));
         let tokens = self.format_delegator_share(&validator.tokens.as_str());
         let tokens_f64 = tokens.to_f64().unwrap_or(0.0);
@@ -130,6 +134,7 @@ impl Chain {
             logo_url,
             uptime,
             name: validator.description.moniker,
+            identity: validator.description.identity.clone(),
             operator_address: validator.operator_address.clone(),
             is_active,
             self_delegation_amount,
@@ -149,7 +154,7 @@ impl Chain {
 
         Ok(job_val)
     }
-    pub async fn cron_job_val_supported_chains(&self) -> Result<(), String> {
+    pub async fn cron_job_val_supported_chains(&self) -> Result<(), TNRAppError> {
         if self.config.name != "axelar" {
             return Ok(());
         };
@@ -198,6 +203,7 @@ impl Chain {
 pub struct JobValidator {
     pub rank: u64,
     pub name: String,
+    pub identity: String,
     pub logo_url: String,
     pub bonded_height: Option<u64>,
     pub change_24h: Option<u64>,
@@ -247,6 +253,7 @@ impl From<JobValidator> for ValidatorForDb {
         Self {
             rank: value.rank,
             name: value.name,
+            identity: value.identity,
             logo_url: value.logo_url,
             bonded_height: value.bonded_height,
             change_24h: value.change_24h,
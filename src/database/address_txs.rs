@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{fetch::transactions::TransactionItem, routes::ChainAmountItem};
+
+/// The role of an address in a transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressTxRole {
+    /// The address signed the transaction or sent a message of it.
+    Sender,
+    /// The address received tokens or a packet in the transaction.
+    Recipient,
+    /// The address appears anywhere else in the messages or the events of the transaction.
+    Involved,
+}
+
+impl AddressTxRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AddressTxRole::Sender => "sender",
+            AddressTxRole::Recipient => "recipient",
+            AddressTxRole::Involved => "involved",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AddressTransaction {
+    pub address: String,
+    pub roles: Vec<AddressTxRole>,
+    pub height: u64,
+    #[serde(rename = "type")]
+    pub tx_type: String,
+    pub message_types: Vec<String>,
+    pub hash: String,
+    pub amount: ChainAmountItem,
+    pub fee: ChainAmountItem,
+    pub result: String,
+    pub code: u32,
+    pub codespace: String,
+    pub raw_log: Option<String>,
+    pub time: i64,
+}
+
+impl AddressTransaction {
+    pub fn new(address: String, roles: Vec<AddressTxRole>, tx: TransactionItem) -> Self {
+        Self {
+            address,
+            roles,
+            height: tx.height,
+            tx_type: tx.tx_type,
+            message_types: tx.message_types,
+            hash: tx.hash,
+            amount: tx.amount,
+            fee: tx.fee,
+            result: tx.result,
+            code: tx.code,
+            codespace: tx.codespace,
+            raw_log: tx.raw_log,
+            time: tx.time,
+        }
+    }
+}
+
+impl From<AddressTransaction> for TransactionItem {
+    fn from(value: AddressTransaction) -> Self {
+        Self {
+            height: value.height,
+            tx_type: value.tx_type,
+            message_types: value.message_types,
+            hash: value.hash,
+            amount: value.amount,
+            fee: value.fee,
+            result: value.result,
+            code: value.code,
+            codespace: value.codespace,
+            raw_log: value.raw_log,
+            time: value.time,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// An API key of a client. The keys are added to the `api_keys` collection of the shared database by hand.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ApiKey {
+    /// The hex encoded SHA-256 hash of the key, the key itself is not saved.
+    pub key_hash: String,
+    /// The owner of the key. Eg: `"Testnetrun UI"`
+    pub owner: String,
+    /// The requests per minute the key is allowed. The keyed tier is used if it is not set.
+    #[serde(default)]
+    pub requests_per_minute: Option<u32>,
+    /// The disabled keys are rejected.
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+    /// The admin keys can also use the `/admin` routes.
+    #[serde(default)]
+    pub admin: bool,
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
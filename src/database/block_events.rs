@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::fetch::blocks::CosmosEvent;
+
+/// The phase of a block in which an event is emitted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockEventPhase {
+    /// The event is emitted in `BeginBlock`, like rewards and slashing.
+    Begin,
+    /// The event is emitted in `EndBlock`, like validator set and proposal status changes.
+    End,
+}
+
+/// The begin and end block events of a block, saved in a single document so they are replaced at once.\
+/// A block without any events has an empty list, so it is known that its events are saved.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BlockEvents {
+    /// The height of the block. Eg: `4611328`
+    pub height: u64,
+    /// The events in the order they are emitted.
+    pub events: Vec<BlockEvent>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[schema(as = BlockEventForDb)]
+pub struct BlockEvent {
+    /// The height of the block. Eg: `4611328`
+    pub height: u64,
+    pub phase: BlockEventPhase,
+    /// The order of the event in its phase. Eg: `3`
+    pub index: u32,
+    /// The type of the event. Eg: `"slash"`
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub attributes: Vec<BlockEventAttribute>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+pub struct BlockEventAttribute {
+    pub key: String,
+    pub value: String,
+}
+
+impl BlockEvent {
+    /// Creates the block events of a phase from the events in a block result.
+    pub fn from_cosmos_events(height: u64, phase: BlockEventPhase, events: Vec<CosmosEvent>) -> Vec<Self> {
+        events
+            .into_iter()
+            .enumerate()
+            .map(|(index, event)| Self {
+                height,
+                phase,
+                index: index as u32,
+                event_type: event.r#type,
+                attributes: event
+                    .attributes
+                    .into_iter()
+                    .map(|attribute| BlockEventAttribute {
+                        key: attribute.key,
+                        value: attribute.value,
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
@@ -1,7 +1,9 @@
 use crate::fetch::blocks::BlockLastCommitSignatures;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[schema(as = BlockForDb)]
 pub struct Block {
     pub proposer_address: String,
     pub proposer_name: String,
@@ -11,4 +13,7 @@ pub struct Block {
     pub tx_count: u64,
     pub timestamp: i64,
     pub signatures: Vec<BlockLastCommitSignatures>,
+    /// HEX encoded hash of the validator set that signed the block. It is empty for the blocks saved before it was added.
+    #[serde(default)]
+    pub validators_hash: String,
 }
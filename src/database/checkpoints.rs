@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Checkpoint {
+    /// The name of the process that owns the checkpoint. Eg: `"blocks_backfill"`
+    pub name: String,
+    /// The last height processed. Eg: `4611328`
+    pub height: u64,
+}
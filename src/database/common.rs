@@ -1,9 +1,12 @@
-use crate::routes::{PaginationData, PaginationDirection, TNRAppSuccessResponse};
+use crate::routes::{ChainAmountItem, PaginationData, PaginationDirection, TNRAppSuccessResponse};
 use mongodb_cursor_pagination::{FindResult, PageInfo};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use crate::fetch::PaginationResponse;
+use crate::fetch::validators::ValidatorListValidator;
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
+#[aliases(SupplyPage = ListDbResult<ChainAmountItem>, ValidatorPage = ListDbResult<ValidatorListValidator>)]
 pub struct ListDbResult<T> {
     /// Array of validators.
     pub data: Vec<T>,
@@ -19,10 +22,13 @@ pub struct PaginatedListResult<T> {
 
 impl<T> From<FindResult<T>> for ListDbResult<T> {
     fn from(value: FindResult<T>) -> Self {
+        // The page info is in the order of the items, even if the page is read backwards.
         let pagination = PaginationData {
-            cursor: value.page_info.next_cursor,
+            cursor: value.page_info.next_cursor.filter(|_| value.page_info.has_next_page),
             limit: Some(value.items.len() as u64),
             direction: Some(PaginationDirection::Next),
+            prev_cursor: value.page_info.start_cursor.filter(|_| value.page_info.has_previous_page),
+            total: Some(value.total_count),
             ..Default::default()
         };
 
@@ -36,8 +42,9 @@ impl<T> From<FindResult<T>> for ListDbResult<T> {
 impl From<PageInfo> for PaginationData {
     fn from(value: PageInfo) -> Self {
         Self {
-            cursor: value.next_cursor,
+            cursor: value.next_cursor.filter(|_| value.has_next_page),
             direction: Some(PaginationDirection::Next),
+            prev_cursor: value.start_cursor.filter(|_| value.has_previous_page),
             ..Default::default()
         }
     }
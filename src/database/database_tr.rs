@@ -1,6 +1,10 @@
+use std::collections::HashSet;
+
 use futures::StreamExt;
 use mongodb::bson::{from_document, to_bson, to_document};
-use mongodb::options::FindOptions;
+use mongodb::options::{
+    ClientOptions, CreateCollectionOptions, FindOneAndUpdateOptions, FindOneOptions, FindOptions, IndexOptions, ReplaceOptions, ReturnDocument,
+};
 use mongodb::IndexModel;
 use mongodb::{
     bson::{doc, Document},
@@ -11,15 +15,26 @@ use mongodb_cursor_pagination::{FindResult, PaginatedCursor};
 use crate::database::blocks::Block;
 use crate::database::params::{HistoricalValidatorData, VotingPower};
 use crate::database::{
-    ChainDashboardInfoForDb, EvmPollForDb, EvmPollParticipantForDb, HeartbeatForDb, ListDbResult, TokenMarketPriceHistoriesForDb, TransactionForDb,
-    ValidatorForDb,
+    AddressTransactionForDb, ApiKeyForDb, BlockEventForDb, BlockEventsForDb, ChainDashboardInfoForDb, CheckpointForDb, DailyTokenPriceForDb,
+    EventLogEntryForDb, EvmPollForDb, EvmPollParticipantForDb, HeartbeatForDb, ListDbResult, TokenMarketPriceHistoriesForDb, TransactionDetailsForDb,
+    TransactionForDb, ValidatorForDb, ValidatorSigningWindowForDb, WebhookDeadLetterForDb,
 };
 use crate::fetch::evm::{EvmSupportedChains, PollStatus};
-use crate::routes::PaginationData;
+use crate::metrics::DbCommandMetrics;
+use crate::routes::{PaginationData, TNRAppError, TNRAppErrorType};
 
 use super::ProposalVoteForDb;
 use super::{params::Params, validators::Validator};
 
+/// The maximum size of the event log collection of a chain in bytes.
+const EVENT_LOG_MAX_SIZE: u64 = 256 * 1024 * 1024;
+
+/// The maximum count of events kept in the event log collection of a chain.
+const EVENT_LOG_MAX_COUNT: u64 = 100_000;
+
+/// The name of the database that keeps the data not belonging to a chain, like the API keys.
+pub const SHARED_DB_NAME: &str = "explorer";
+
 // Testnetrun explorer database.
 #[derive(Clone)]
 pub struct DatabaseTR {
@@ -31,7 +46,8 @@ pub struct DatabaseTR {
 }
 
 impl DatabaseTR {
-    /// Connects to MongoDB instance at given URI and creates a client to work with that instance.
+    /// Connects to MongoDB instance at given URI and creates a client to work with that instance.\
+    /// The durations of the commands sent by the client are recorded in the metrics.
     /// # Usage
     /// ```rs
     /// let database = Database::new();
@@ -40,8 +56,11 @@ impl DatabaseTR {
         // Change this URI and create a database for each chain using chain names.
         let uri = std::env::var("MONGODB_URI").expect("MONGODB_URI must be set in .env file");
 
+        let mut options = ClientOptions::parse(uri).await.expect("Cannot connect to MongoDB instance.");
+        options.command_event_handler = Some(DbCommandMetrics::handler());
+
         DatabaseTR {
-            mongo: (Client::with_uri_str(uri).await.expect("Cannot connect to MongoDB instance.")),
+            mongo: (Client::with_options(options).expect("Cannot connect to MongoDB instance.")),
             db_name: "unexpected_db".to_string(),
         }
     }
@@ -54,6 +73,95 @@ impl DatabaseTR {
         }
     }
 
+    /// Creates the indexes the background jobs and the lists of the chain rely on. The existing indexes are left as they are.\
+    /// It is called once, when the chain is initialized.\
+    /// The keys of the list indexes are ordered by the equality filters, the sort field and then the range filters,
+    /// so the filtered lists are read in the sort order of the indexes. The keys the documents are upserted by are unique.
+    /// # Usage
+    /// ```rs
+    /// database.create_indexes().await?;
+    /// ```
+    pub async fn create_indexes(&self) -> Result<(), String> {
+        self.db()
+            .collection::<Document>("blocks")
+            .create_indexes(
+                [
+                    unique_index(doc! {"hash": 1}),
+                    index(doc! {"height": 1}),
+                    index(doc! {"timestamp": -1, "height": 1, "tx_count": 1}),
+                    index(doc! {"proposer_address": 1, "timestamp": -1}),
+                ],
+                None,
+            )
+            .await
+            .map_err(|e| format!("Cannot create the indexes of the blocks: {e}"))?;
+
+        self.db()
+            .collection::<Document>("transactions")
+            .create_indexes(
+                [
+                    unique_index(doc! {"hash": 1}),
+                    index(doc! {"height": 1}),
+                    index(doc! {"time": -1, "height": 1}),
+                    index(doc! {"time": -1, "main_amount": 1}),
+                    index(doc! {"message_types": 1, "time": -1}),
+                    index(doc! {"result": 1, "time": -1}),
+                ],
+                None,
+            )
+            .await
+            .map_err(|e| format!("Cannot create the indexes of the transactions: {e}"))?;
+
+        self.transaction_details_collection()
+            .create_index(unique_index(doc! {"hash": 1}), None)
+            .await
+            .map_err(|e| format!("Cannot create the indexes of the transaction details: {e}"))?;
+
+        self.block_events_collection()
+            .create_index(unique_index(doc! {"height": 1}), None)
+            .await
+            .map_err(|e| format!("Cannot create the indexes of the block events: {e}"))?;
+
+        self.daily_token_prices_collection()
+            .create_index(unique_index(doc! {"token": 1, "timestamp": 1}), None)
+            .await
+            .map_err(|e| format!("Cannot create the indexes of the daily token prices: {e}"))?;
+
+        self.db()
+            .collection::<Document>("address_txs")
+            .create_indexes(
+                [unique_index(doc! {"address": 1, "hash": 1}), index(doc! {"address": 1, "time": -1})],
+                None,
+            )
+            .await
+            .map_err(|e| format!("Cannot create the indexes of the address transactions: {e}"))?;
+
+        self.db()
+            .collection::<Document>("validators")
+            .create_index(index(doc! {"rank": 1}), None)
+            .await
+            .map_err(|e| format!("Cannot create the indexes of the validators: {e}"))?;
+
+        self.validator_signing_collection()
+            .create_index(unique_index(doc! {"hex_address": 1, "window_start": 1}), None)
+            .await
+            .map_err(|e| format!("Cannot create the indexes of the validator signing: {e}"))?;
+
+        self.db()
+            .collection::<Document>("evm_polls")
+            .create_index(index(doc! {"timestamp": -1}), None)
+            .await
+            .map_err(|e| format!("Cannot create the indexes of the EVM polls: {e}"))?;
+
+        self.db()
+            .collection::<Document>("evm_poll_participants")
+            .create_index(index(doc! {"poll_id": -1}), None)
+            .await
+            .map_err(|e| format!("Cannot create the indexes of the EVM poll participants: {e}"))?;
+
+        Ok(())
+    }
+
     /// Returns the MongoDB database.
     /// # Usage
     /// ```rs
@@ -63,6 +171,18 @@ impl DatabaseTR {
         self.mongo.database(&self.db_name)
     }
 
+    /// Checks that the MongoDB instance responds.
+    /// # Usage
+    /// ```rs
+    /// database.ping().await?;
+    /// ```
+    pub async fn ping(&self) -> Result<(), String> {
+        match self.db().run_command(doc! {"ping": 1}, None).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(format!("Cannot make request to DB: {e}")),
+        }
+    }
+
     /// Returns the validators collection.
     /// # Usage
     /// ```rs
@@ -81,6 +201,15 @@ impl DatabaseTR {
         self.db().collection("transactions")
     }
 
+    /// Returns the transaction details collection.
+    /// # Usage
+    /// ```rs
+    /// let collection = database.transaction_details_collection();
+    /// ```
+    fn transaction_details_collection(&self) -> Collection<TransactionDetailsForDb> {
+        self.db().collection("transaction_details")
+    }
+
     /// Returns the historical data collection.
     /// # Usage
     /// ```rs
@@ -144,6 +273,78 @@ impl DatabaseTR {
         self.db().collection("market_price_history")
     }
 
+    /// Returns the daily token prices collection.
+    /// # Usage
+    /// ```rs
+    /// let collection = database.daily_token_prices_collection();
+    /// ```
+    fn daily_token_prices_collection(&self) -> Collection<DailyTokenPriceForDb> {
+        self.db().collection("daily_token_prices")
+    }
+
+    /// Returns the block events collection.
+    /// # Usage
+    /// ```rs
+    /// let collection = database.block_events_collection();
+    /// ```
+    fn block_events_collection(&self) -> Collection<BlockEventsForDb> {
+        self.db().collection("block_events")
+    }
+
+    /// Returns the validator signing collection.
+    /// # Usage
+    /// ```rs
+    /// let collection = database.validator_signing_collection();
+    /// ```
+    fn validator_signing_collection(&self) -> Collection<ValidatorSigningWindowForDb> {
+        self.db().collection("validator_signing")
+    }
+
+    /// Returns the checkpoints collection.
+    /// # Usage
+    /// ```rs
+    /// let collection = database.checkpoints_collection();
+    /// ```
+    fn checkpoints_collection(&self) -> Collection<CheckpointForDb> {
+        self.db().collection("checkpoints")
+    }
+
+    /// Returns the event log collection.
+    /// # Usage
+    /// ```rs
+    /// let collection = database.event_log_collection();
+    /// ```
+    fn event_log_collection(&self) -> Collection<EventLogEntryForDb> {
+        self.db().collection("event_log")
+    }
+
+    /// Returns the counters collection.
+    /// # Usage
+    /// ```rs
+    /// let collection = database.counters_collection();
+    /// ```
+    fn counters_collection(&self) -> Collection<Document> {
+        self.db().collection("counters")
+    }
+
+    /// Returns the webhook dead letters collection.
+    /// # Usage
+    /// ```rs
+    /// let collection = database.webhook_dead_letters_collection();
+    /// ```
+    fn webhook_dead_letters_collection(&self) -> Collection<WebhookDeadLetterForDb> {
+        self.db().collection("webhook_dead_letters")
+    }
+
+    /// Returns the API keys collection. It is only used on the shared database.
+    /// # Usage
+    /// ```rs
+    /// let collection = database.api_keys_collection();
+    /// ```
+    fn api_keys_collection(&self) -> Collection<ApiKeyForDb> {
+        self.db().collection("api_keys")
+    }
+
     pub async fn upsert_validator(&self, validator: Validator) -> Result<(), String> {
         let doc = to_document(&validator).unwrap();
         let command = doc! {"update":"validators","updates":[{"q":{"operator_address":&validator.operator_address},"u":doc,"upsert":true}]};
@@ -153,18 +354,118 @@ impl DatabaseTR {
         }
     }
 
-    /// Adds a new transaction to the transactions collection of the database.
+    /// Upserts a transaction to the transactions collection of the database.
     /// # Usage
     /// ```rs
-    /// database.add_transaction(transaction).await;
+    /// database.upsert_transaction(transaction).await;
     /// ```
-    pub async fn add_transaction(&self, transaction: TransactionForDb) -> Result<(), String> {
-        match self.transactions_collection().insert_one(transaction, None).await {
+    pub async fn upsert_transaction(&self, transaction: TransactionForDb) -> Result<(), String> {
+        let doc = to_document(&transaction).unwrap();
+        let command = doc! {"update":"transactions","updates":[{"q":{"hash":&transaction.hash},"u":doc,"upsert":true}]};
+        match self.db().run_command(command, None).await {
             Ok(_) => Ok(()),
             Err(_) => Err("Cannot save the transaction.".into()),
         }
     }
 
+    /// Upserts a fully decoded transaction to the transaction details collection of the database.
+    /// # Usage
+    /// ```rs
+    /// database.upsert_transaction_details(transaction).await;
+    /// ```
+    pub async fn upsert_transaction_details(&self, transaction: TransactionDetailsForDb) -> Result<(), String> {
+        let doc = to_document(&transaction).map_err(|e| format!("Cannot serialize the transaction details: {e}"))?;
+        let command = doc! {"update":"transaction_details","updates":[{"q":{"hash":&transaction.hash},"u":doc,"upsert":true}]};
+        match self.db().run_command(command, None).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(format!("Cannot save the transaction details: {e}")),
+        }
+    }
+
+    /// Finds a fully decoded transaction by hash.
+    /// # Usage
+    /// ```rs
+    /// let transaction = database.find_transaction_details_by_hash(hash).await;
+    /// ```
+    pub async fn find_transaction_details_by_hash(&self, hash: &str) -> Result<TransactionDetailsForDb, TNRAppError> {
+        match self.transaction_details_collection().find_one(doc! {"hash": hash}, None).await {
+            Ok(potential_tx) => match potential_tx {
+                Some(tx) => Ok(tx),
+                None => Err(not_found("transaction")),
+            },
+            Err(e) => Err(db_error(e)),
+        }
+    }
+
+    /// Finds the transaction details with any of given hashes. The hashes not saved are skipped.
+    /// # Usage
+    /// ```rs
+    /// let txs = database.find_transaction_details_by_hashes(vec![hash]).await;
+    /// ```
+    pub async fn find_transaction_details_by_hashes(&self, hashes: Vec<String>) -> Result<Vec<TransactionDetailsForDb>, TNRAppError> {
+        let mut results = self
+            .transaction_details_collection()
+            .find(doc! {"hash": {"$in": hashes}}, None)
+            .await
+            .map_err(db_error)?;
+
+        let mut txs = vec![];
+        while let Some(result) = results.next().await {
+            txs.push(result.map_err(|e| format!("Cannot parse the transaction details: {e}"))?);
+        }
+
+        Ok(txs)
+    }
+
+    /// Upserts the address index items of a transaction to the address transactions collection of the database.
+    /// # Usage
+    /// ```rs
+    /// database.upsert_address_txs(address_txs).await;
+    /// ```
+    pub async fn upsert_address_txs(&self, address_txs: Vec<AddressTransactionForDb>) -> Result<(), String> {
+        if address_txs.is_empty() {
+            return Ok(());
+        }
+
+        let mut updates = vec![];
+        for address_tx in address_txs {
+            let doc = to_document(&address_tx).map_err(|e| format!("Cannot serialize the address transaction: {e}"))?;
+            updates.push(doc! {"q":{"address":&address_tx.address,"hash":&address_tx.hash},"u":doc,"upsert":true});
+        }
+
+        let command = doc! {"update":"address_txs","updates":updates};
+        match self.db().run_command(command, None).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(format!("Cannot save the address transactions: {e}")),
+        }
+    }
+
+    /// Finds a sorted address transactions list by given document.
+    /// # Usage
+    /// ```rs
+    /// let address_txs = database.find_paginated_address_txs(Some(doc! {"address": address}), config).await;
+    /// ```
+    pub async fn find_paginated_address_txs(
+        &self,
+        query: Option<Document>,
+        config: PaginationData,
+    ) -> Result<ListDbResult<AddressTransactionForDb>, TNRAppError> {
+        let collection = self.db().collection("address_txs");
+        let sort_doc = doc! {"time":-1};
+
+        let find_options = FindOptions::builder()
+            .sort(sort_doc)
+            .limit(config.limit.map(|l| l as i64).unwrap_or_else(|| 20))
+            .build();
+
+        let results = PaginatedCursor::new(Some(find_options), config.cursor, config.direction.map(|d| d.into()))
+            .find(&collection, query.as_ref())
+            .await
+            .map_err(db_error)?;
+
+        Ok(ListDbResult::from(results))
+    }
+
     /// Adds new propsal vote to the propsals votes collection of the database.
     /// # Usage
     /// ```rs
@@ -182,7 +483,7 @@ impl DatabaseTR {
     /// ```rs
     /// database.find_last_count_transactions(vec<doc!{}>,10).await;
     /// ```
-    pub async fn find_last_count_transactions(&self, pipeline: Option<Vec<Document>>, count: u16) -> Result<Vec<TransactionForDb>, String> {
+    pub async fn find_last_count_transactions(&self, pipeline: Option<Vec<Document>>, count: u16) -> Result<Vec<TransactionForDb>, TNRAppError> {
         let mut pipeline_docs = vec![];
 
         let sort_pipe = doc! { "$sort": {"time": -1} };
@@ -196,15 +497,11 @@ impl DatabaseTR {
 
         pipeline_docs.push(limit_pipe);
 
-        let mut results = self
-            .transactions_collection()
-            .aggregate(pipeline_docs, None)
-            .await
-            .map_err(|e| e.to_string())?;
+        let mut results = self.transactions_collection().aggregate(pipeline_docs, None).await.map_err(db_error)?;
 
         let mut res: Vec<TransactionForDb> = vec![];
         while let Some(result) = results.next().await {
-            res.push(from_document(result.map_err(|e| e.to_string())?).map_err(|e| e.to_string())?);
+            res.push(from_document(result.map_err(db_error)?).map_err(db_error)?);
         }
 
         Ok(res)
@@ -229,14 +526,11 @@ impl DatabaseTR {
     /// ```rs
     /// let blocks = database.find_paginated_blocks().await;
     /// ```
-    pub async fn find_paginated_blocks(&self, query: Option<Document>, config: PaginationData) -> Result<ListDbResult<Block>, String> {
+    pub async fn find_paginated_blocks(&self, query: Option<Document>, config: PaginationData) -> Result<ListDbResult<Block>, TNRAppError> {
         let collection = self.db().collection("blocks");
 
         let sort_doc = doc! { "timestamp": - 1};
 
-        let index_doc = sort_doc.clone();
-        let _ = collection.create_index(IndexModel::builder().keys(index_doc).build(), None).await;
-
         let find_options = FindOptions::builder()
             .sort(sort_doc)
             .limit(config.limit.map(|l| l as i64).unwrap_or_else(|| 20))
@@ -245,17 +539,111 @@ impl DatabaseTR {
         let results = PaginatedCursor::new(Some(find_options), config.cursor, None)
             .find(&collection, query.as_ref())
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(db_error)?;
 
         Ok(ListDbResult::from(results))
     }
 
+    /// Finds the heights between `from` and `to` (both inclusive) that are missing in the blocks collection.
+    /// # Usage
+    /// ```rs
+    /// let missing_heights = database.find_missing_block_heights(100, 1100).await;
+    /// ```
+    pub async fn find_missing_block_heights(&self, from: u64, to: u64) -> Result<Vec<u64>, String> {
+        let collection = self.db().collection::<Document>("blocks");
+
+        let find_options = FindOptions::builder().projection(doc! {"height": 1, "_id": 0}).build();
+
+        let mut results = collection
+            .find(doc! {"height": {"$gte": from as i64, "$lte": to as i64}}, find_options)
+            .await
+            .map_err(|e| format!("Cannot make request to DB: {e}"))?;
+
+        let mut existing_heights = HashSet::new();
+        while let Some(result) = results.next().await {
+            if let Ok(height) = result.map_err(|e| e.to_string())?.get_i64("height") {
+                existing_heights.insert(height as u64);
+            }
+        }
+
+        Ok(missing_heights(from, to, &existing_heights))
+    }
+
+    /// Replaces the begin and end block events of the block at given height.
+    /// # Usage
+    /// ```rs
+    /// database.upsert_block_events(height, events).await;
+    /// ```
+    pub async fn upsert_block_events(&self, height: u64, events: Vec<BlockEventForDb>) -> Result<(), String> {
+        let block_events = BlockEventsForDb { height, events };
+        let options = ReplaceOptions::builder().upsert(true).build();
+
+        match self
+            .block_events_collection()
+            .replace_one(doc! {"height": height as i64}, block_events, options)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(format!("Cannot save the block events: {e}")),
+        }
+    }
+
+    /// Finds the begin and end block events of the block at given height, in the order they are emitted.\
+    /// Returns `None` if the events of the block are not saved yet.
+    /// # Usage
+    /// ```rs
+    /// let events = database.find_block_events(height).await;
+    /// ```
+    pub async fn find_block_events(&self, height: u64) -> Result<Option<Vec<BlockEventForDb>>, TNRAppError> {
+        self.block_events_collection()
+            .find_one(doc! {"height": height as i64}, None)
+            .await
+            .map(|block_events| block_events.map(|block_events| block_events.events))
+            .map_err(db_error)
+    }
+
+    /// Finds the height of the first block saved at or after given unix timestamp in milliseconds.
+    /// # Usage
+    /// ```rs
+    /// let height = database.find_first_block_height_since(1667497514193).await;
+    /// ```
+    pub async fn find_first_block_height_since(&self, timestamp: i64) -> Result<Option<u64>, String> {
+        let collection = self.db().collection::<Document>("blocks");
+
+        let find_options = FindOneOptions::builder()
+            .sort(doc! {"timestamp": 1})
+            .projection(doc! {"height": 1, "_id": 0})
+            .build();
+
+        let block = collection
+            .find_one(doc! {"timestamp": {"$gte": timestamp}}, find_options)
+            .await
+            .map_err(|e| format!("Cannot make request to DB: {e}"))?;
+
+        Ok(block.and_then(|block| block.get_i64("height").ok()).map(|height| height as u64))
+    }
+
+    /// Finds a block by given document.
+    /// # Usage
+    /// ```rs
+    /// let block = database.find_block(doc! {"height": height as i64}).await;
+    /// ```
+    pub async fn find_block(&self, doc: Document) -> Result<Block, TNRAppError> {
+        match self.blocks_collection().find_one(doc, None).await {
+            Ok(potential_block) => match potential_block {
+                Some(block) => Ok(block),
+                None => Err(not_found("block")),
+            },
+            Err(e) => Err(db_error(e)),
+        }
+    }
+
     /// Finds counted blocks in the blocks collection
     /// # Usage
     /// ```rs
     /// database.find_last_count_blocks(vec<doc!{}>,10).await;
     /// ```
-    pub async fn find_last_count_blocks(&self, pipeline: Option<Vec<Document>>, count: u16) -> Result<Vec<Block>, String> {
+    pub async fn find_last_count_blocks(&self, pipeline: Option<Vec<Document>>, count: u16) -> Result<Vec<Block>, TNRAppError> {
         let mut pipeline_docs = vec![];
 
         let sort_pipe = doc! { "$sort": {"height": -1} };
@@ -268,11 +656,11 @@ impl DatabaseTR {
         };
 
         pipeline_docs.push(limit_pipe);
-        let mut results = self.blocks_collection().aggregate(pipeline_docs, None).await.map_err(|e| e.to_string())?;
+        let mut results = self.blocks_collection().aggregate(pipeline_docs, None).await.map_err(db_error)?;
 
         let mut res: Vec<Block> = vec![];
         while let Some(result) = results.next().await {
-            res.push(from_document(result.map_err(|e| e.to_string())?).map_err(|e| e.to_string())?);
+            res.push(from_document(result.map_err(db_error)?).map_err(db_error)?);
         }
 
         Ok(res)
@@ -283,13 +671,10 @@ impl DatabaseTR {
     /// ```rs
     /// let txs = database.find_paginated_txs().await;
     /// ```
-    pub async fn find_paginated_txs(&self, query: Option<Document>, config: PaginationData) -> Result<ListDbResult<TransactionForDb>, String> {
+    pub async fn find_paginated_txs(&self, query: Option<Document>, config: PaginationData) -> Result<ListDbResult<TransactionForDb>, TNRAppError> {
         let collection = self.db().collection("transactions");
         let sort_doc = doc! {"time":-1};
 
-        let index_doc = sort_doc.clone();
-        let _ = collection.create_index(IndexModel::builder().keys(index_doc).build(), None).await;
-
         let find_options = FindOptions::builder()
             .sort(sort_doc)
             .limit(config.limit.map(|l| l as i64).unwrap_or_else(|| 20))
@@ -298,23 +683,58 @@ impl DatabaseTR {
         let results = PaginatedCursor::new(Some(find_options), config.cursor, None)
             .find(&collection, query.as_ref())
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(db_error)?;
 
         Ok(ListDbResult::from(results))
     }
 
+    /// Finds a transaction by given document.
+    /// # Usage
+    /// ```rs
+    /// let tx = database.find_transaction(doc! {"hash": hash}).await;
+    /// ```
+    pub async fn find_transaction(&self, doc: Document) -> Result<TransactionForDb, TNRAppError> {
+        match self.transactions_collection().find_one(doc, None).await {
+            Ok(potential_tx) => match potential_tx {
+                Some(tx) => Ok(tx),
+                None => Err(not_found("transaction")),
+            },
+            Err(e) => Err(db_error(e)),
+        }
+    }
+
+    /// Finds the transactions at any of given heights.
+    /// # Usage
+    /// ```rs
+    /// let txs = database.find_transactions_by_heights(vec![4611328, 4611329]).await;
+    /// ```
+    pub async fn find_transactions_by_heights(&self, heights: Vec<u64>) -> Result<Vec<TransactionForDb>, TNRAppError> {
+        let collection = self.transactions_collection();
+
+        let heights: Vec<i64> = heights.into_iter().map(|height| height as i64).collect();
+
+        let mut results = collection.find(doc! {"height": {"$in": heights}}, None).await.map_err(db_error)?;
+
+        let mut txs = vec![];
+        while let Some(result) = results.next().await {
+            txs.push(result.map_err(|e| format!("Cannot parse the transaction: {e}"))?);
+        }
+
+        Ok(txs)
+    }
+
     /// Finds a validator by given document.
     /// # Usage
     /// ```rs
     /// let validator = database.find_validator(doc!("operator_address": address)).await;
     /// ```
-    pub async fn find_validator(&self, doc: Document) -> Result<Validator, String> {
+    pub async fn find_validator(&self, doc: Document) -> Result<Validator, TNRAppError> {
         match self.validators_collection().find_one(doc, None).await {
             Ok(potential_validator) => match potential_validator {
                 Some(validator) => Ok(validator),
-                None => Err("No validator is found.".into()),
+                None => Err(not_found("validator")),
             },
-            Err(_) => Err("Cannot make request to DB.".into()),
+            Err(e) => Err(db_error(e)),
         }
     }
 
@@ -323,13 +743,14 @@ impl DatabaseTR {
     /// ```rs
     /// let validator = database.find_paginated_validators(doc!{"$match":{"operator_address":{"$exists":true}}}).await;
     /// ```
-    pub async fn find_paginated_validators(&self, query: Option<Document>, config: PaginationData) -> Result<ListDbResult<ValidatorForDb>, String> {
+    pub async fn find_paginated_validators(
+        &self,
+        query: Option<Document>,
+        config: PaginationData,
+    ) -> Result<ListDbResult<ValidatorForDb>, TNRAppError> {
         let collection = self.db().collection("validators");
         let sort_doc = doc! {"rank":1};
 
-        let index_doc = sort_doc.clone();
-        let _ = collection.create_index(IndexModel::builder().keys(index_doc).build(), None).await;
-
         let find_options = FindOptions::builder()
             .sort(sort_doc)
             .limit(config.limit.map(|l| l as i64).unwrap_or_else(|| 20))
@@ -338,7 +759,7 @@ impl DatabaseTR {
         let results = PaginatedCursor::new(Some(find_options), config.cursor, None)
             .find(&collection, query.as_ref())
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(db_error)?;
 
         Ok(ListDbResult::from(results))
     }
@@ -347,7 +768,7 @@ impl DatabaseTR {
     /// ```rs
     /// let validator = database.find_validators(doc!{"$match":{"operator_address":{"$exists":true}}}).await;
     /// ```
-    pub async fn find_validators(&self, pipe: Option<Document>) -> Result<Vec<Validator>, String> {
+    pub async fn find_validators(&self, pipe: Option<Document>) -> Result<Vec<Validator>, TNRAppError> {
         let default_filter = doc! {"$match":{"operator_address":{"$exists":true}}};
         //default filter necessary when using aggregate
         let mut pipeline: Vec<Document> = vec![default_filter];
@@ -357,11 +778,11 @@ impl DatabaseTR {
             Some(val) => pipeline.push(val),
         };
 
-        let mut results = self.validators_collection().aggregate(pipeline, None).await.map_err(|e| e.to_string())?;
+        let mut results = self.validators_collection().aggregate(pipeline, None).await.map_err(db_error)?;
 
         let mut res: Vec<Validator> = vec![];
         while let Some(result) = results.next().await {
-            res.push(from_document(result.map_err(|e| e.to_string())?).map_err(|e| e.to_string())?);
+            res.push(from_document(result.map_err(db_error)?).map_err(db_error)?);
         }
 
         Ok(res)
@@ -371,7 +792,7 @@ impl DatabaseTR {
     /// ```rs
     /// let validator = database.find_validator_by_operator_addr(operator_address).await;
     /// ```
-    pub async fn find_validator_by_operator_addr(&self, operator_address: &str) -> Result<Validator, String> {
+    pub async fn find_validator_by_operator_addr(&self, operator_address: &str) -> Result<Validator, TNRAppError> {
         self.find_validator(doc! {"operator_address": operator_address}).await
     }
 
@@ -380,7 +801,7 @@ impl DatabaseTR {
     /// ```rs
     /// let validator = database.find_validator_by_hex_addr(hex_address).await;
     /// ```
-    pub async fn find_validator_by_hex_addr(&self, hex_address: &str) -> Result<Validator, String> {
+    pub async fn find_validator_by_hex_addr(&self, hex_address: &str) -> Result<Validator, TNRAppError> {
         self.find_validator(doc! {"hex_address": hex_address.to_ascii_uppercase()}).await
     }
 
@@ -416,19 +837,16 @@ impl DatabaseTR {
     /// ```rs
     /// database.find_paginated_evm_polls(evm_poll).await;
     /// ```
-    pub async fn find_paginated_evm_polls(&self, pipe: Option<Document>, config: PaginationData) -> Result<ListDbResult<EvmPollForDb>, String> {
+    pub async fn find_paginated_evm_polls(&self, pipe: Option<Document>, config: PaginationData) -> Result<ListDbResult<EvmPollForDb>, TNRAppError> {
         let collection = self.db().collection("evm_polls");
         let sort_doc = doc! { "timestamp": -1};
 
-        let index_doc = sort_doc.clone();
-        let _ = collection.create_index(IndexModel::builder().keys(index_doc).build(), None).await;
-
         let options = FindOptions::builder().limit(config.limit.map(|l| l as i64)).sort(sort_doc).build();
 
         let results: FindResult<EvmPollForDb> = PaginatedCursor::new(Some(options), config.cursor, config.direction.map(|d| d.into()))
             .find(&collection, pipe.as_ref())
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(db_error)?;
 
         Ok(ListDbResult::from(results))
     }
@@ -437,14 +855,14 @@ impl DatabaseTR {
     /// ```rs
     /// database.find_paginated_evm_polls(evm_poll).await;
     /// ```
-    pub async fn find_validator_supported_chains(&self, operator_address: &String) -> Result<EvmSupportedChains, String> {
+    pub async fn find_validator_supported_chains(&self, operator_address: &String) -> Result<EvmSupportedChains, TNRAppError> {
         let pipeline: Vec<Document> = vec![doc! {"$match":{"operator_address": operator_address}}];
 
-        let mut results = self.validators_collection().aggregate(pipeline, None).await.map_err(|e| e.to_string())?;
+        let mut results = self.validators_collection().aggregate(pipeline, None).await.map_err(db_error)?;
 
         let mut res: Vec<String> = vec![];
         while let Some(result) = results.next().await {
-            let val = from_document::<Validator>(result.map_err(|e| e.to_string())?).map_err(|e| e.to_string())?;
+            let val = from_document::<Validator>(result.map_err(db_error)?).map_err(db_error)?;
             res = val.supported_evm_chains.unwrap_or(vec![]);
         }
 
@@ -470,13 +888,13 @@ impl DatabaseTR {
     /// ```rs
     /// let evm_poll = database.find_validator(doc!("operator_address": address)).await;
     /// ```
-    pub async fn find_evm_poll(&self, doc: Document) -> Result<EvmPollForDb, String> {
+    pub async fn find_evm_poll(&self, doc: Document) -> Result<EvmPollForDb, TNRAppError> {
         match self.evm_poll_collection().find_one(doc, None).await {
             Ok(potential_validator) => match potential_validator {
                 Some(poll) => Ok(poll),
-                None => Err("No poll is found.".into()),
+                None => Err(not_found("poll")),
             },
-            Err(_) => Err("Cannot make request to DB.".into()),
+            Err(e) => Err(db_error(e)),
         }
     }
 
@@ -511,19 +929,16 @@ impl DatabaseTR {
         &self,
         pipe: Option<Document>,
         config: PaginationData,
-    ) -> Result<ListDbResult<EvmPollParticipantForDb>, String> {
+    ) -> Result<ListDbResult<EvmPollParticipantForDb>, TNRAppError> {
         let collection = self.db().collection("evm_poll_participants");
         let sort_doc = doc! { "poll_id": -1};
 
-        let index_doc = sort_doc.clone();
-        let _ = collection.create_index(IndexModel::builder().keys(index_doc).build(), None).await;
-
         let options = FindOptions::builder().limit(config.limit.map(|l| l as i64)).sort(sort_doc).build();
 
         let results: FindResult<EvmPollParticipantForDb> = PaginatedCursor::new(Some(options), config.cursor, config.direction.map(|d| d.into()))
             .find(&collection, pipe.as_ref())
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(db_error)?;
 
         Ok(ListDbResult::from(results))
     }
@@ -578,13 +993,10 @@ impl DatabaseTR {
         &self,
         filter: Option<Document>,
         config: Option<PaginationData>,
-    ) -> Result<ListDbResult<HeartbeatForDb>, String> {
+    ) -> Result<ListDbResult<HeartbeatForDb>, TNRAppError> {
         let collection = self.db().collection("heartbeats");
         let sort_doc = doc! { "_id": -1};
 
-        let index_doc = sort_doc.clone();
-        let _ = collection.create_index(IndexModel::builder().keys(index_doc).build(), None).await;
-
         let config = config.unwrap_or_default();
         let options = FindOptions::builder()
             .limit(config.limit.map(|l| l as i64).unwrap_or_else(|| 20))
@@ -594,7 +1006,7 @@ impl DatabaseTR {
         let results: FindResult<HeartbeatForDb> = PaginatedCursor::new(Some(options), config.cursor, config.direction.map(|d| d.into()))
             .find(&collection, filter.as_ref())
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(db_error)?;
 
         Ok(ListDbResult::from(results))
     }
@@ -655,32 +1067,111 @@ impl DatabaseTR {
 
     /// # Usage
     /// ```rs
-    /// database.insertfind_market_history_market_price_history(token_market_price_histories).await;
+    /// database.find_market_history(token).await;
     /// ```
-    pub async fn find_market_history(&self, token: String) -> Result<TokenMarketPriceHistoriesForDb, String> {
+    pub async fn find_market_history(&self, token: String) -> Result<TokenMarketPriceHistoriesForDb, TNRAppError> {
         let filter = doc! {"token":token};
 
         match self.market_price_history().find_one(filter, None).await {
             Ok(history) => match history {
                 Some(history) => Ok(history),
-                None => Err("No validator is found.".into()),
+                None => Err(not_found("market history")),
             },
-            Err(e) => return Err(format!("Cannot make request to DB: {e}")),
+            Err(e) => Err(db_error(e)),
         }
     }
 
+    /// Saves the daily prices of a token, the price of a day that is already saved is replaced.
+    /// # Usage
+    /// ```rs
+    /// database.upsert_daily_token_prices(prices).await;
+    /// ```
+    pub async fn upsert_daily_token_prices(&self, prices: Vec<DailyTokenPriceForDb>) -> Result<(), String> {
+        if prices.is_empty() {
+            return Ok(());
+        }
+
+        let updates = prices
+            .iter()
+            .map(|price| {
+                let price_doc = to_document(price).map_err(|e| format!("Cannot serialize the daily token price: {e}"))?;
+
+                Ok(doc! {
+                    "q": {"token": &price.token, "timestamp": price.timestamp},
+                    "u": price_doc,
+                    "upsert": true,
+                })
+            })
+            .collect::<Result<Vec<Document>, String>>()?;
+
+        match self
+            .db()
+            .run_command(doc! {"update": "daily_token_prices", "updates": updates}, None)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(format!("Cannot save the daily token prices: {e}")),
+        }
+    }
+
+    /// Finds the latest saved daily price of a token.
+    /// # Usage
+    /// ```rs
+    /// let price = database.find_last_daily_token_price("axelar").await;
+    /// ```
+    pub async fn find_last_daily_token_price(&self, token: &str) -> Result<Option<DailyTokenPriceForDb>, TNRAppError> {
+        let find_options = FindOneOptions::builder().sort(doc! {"timestamp": -1}).build();
+
+        self.daily_token_prices_collection()
+            .find_one(doc! {"token": token}, find_options)
+            .await
+            .map_err(db_error)
+    }
+
+    /// Finds the daily prices of a token between given days, from the oldest to the newest.\
+    /// The bounds are milliseconds since the Unix epoch of the starts of the days, both are inclusive.
+    /// # Usage
+    /// ```rs
+    /// let prices = database.find_daily_token_prices("axelar", Some(1667433600000), None).await;
+    /// ```
+    pub async fn find_daily_token_prices(&self, token: &str, from: Option<i64>, to: Option<i64>) -> Result<Vec<DailyTokenPriceForDb>, TNRAppError> {
+        let mut query = doc! {"token": token};
+
+        let mut timestamp = Document::new();
+        if let Some(from) = from {
+            timestamp.insert("$gte", from);
+        }
+        if let Some(to) = to {
+            timestamp.insert("$lte", to);
+        }
+        if !timestamp.is_empty() {
+            query.insert("timestamp", timestamp);
+        }
+
+        let find_options = FindOptions::builder().sort(doc! {"timestamp": 1}).build();
+
+        let mut results = self.daily_token_prices_collection().find(query, find_options).await.map_err(db_error)?;
+
+        let mut prices = vec![];
+        while let Some(result) = results.next().await {
+            prices.push(result.map_err(|e| format!("Cannot parse the daily token price: {e}"))?);
+        }
+
+        Ok(prices)
+    }
+
     /// Finds a historical data by given document.
     /// # Usage
     /// ```rs
     /// database.find_historical_data(doc).await;
     /// ```
-    pub async fn find_historical_data(&self, doc: Document) -> Result<HistoricalValidatorData, String> {
+    pub async fn find_historical_data(&self, doc: Document) -> Result<HistoricalValidatorData, TNRAppError> {
         match self.historical_data_collection().find_one(doc, None).await {
             Ok(potential_h_data) => match potential_h_data {
                 Some(historical_data) => Ok(historical_data),
-                None => Err("No validator is found.".into()),
+                None => Err(not_found("validator")),
             },
-            Err(_) => Err("Cannot make request to DB.".into()),
+            Err(e) => Err(db_error(e)),
         }
     }
 
@@ -689,7 +1180,7 @@ impl DatabaseTR {
     /// ```rs
     /// database.find_historical_data_by_operator_address(operator_address).await;
     /// ```
-    pub async fn find_historical_data_by_operator_address(&self, operator_address: &str) -> Result<HistoricalValidatorData, String> {
+    pub async fn find_historical_data_by_operator_address(&self, operator_address: &str) -> Result<HistoricalValidatorData, TNRAppError> {
         self.find_historical_data(doc! {"operator_address":operator_address}).await
     }
 
@@ -698,13 +1189,13 @@ impl DatabaseTR {
     /// ```rs
     /// database.find_chain_dashboard_info(operator_address).await;
     /// ```
-    pub async fn find_chain_dashboard_info(&self) -> Result<ChainDashboardInfoForDb, String> {
+    pub async fn find_chain_dashboard_info(&self) -> Result<ChainDashboardInfoForDb, TNRAppError> {
         match self.dashboard_info_collection().find_one(doc! {}, None).await {
             Ok(potential_dashboard) => match potential_dashboard {
                 Some(dashboard) => Ok(dashboard),
-                None => Err("No dashboard is found.".into()),
+                None => Err(not_found("dashboard")),
             },
-            Err(_) => Err("Cannot make request to DB.".into()),
+            Err(e) => Err(db_error(e)),
         }
     }
 
@@ -721,4 +1212,281 @@ impl DatabaseTR {
             Err(e) => Err(format!("Cannot save the dashboard: {e}")),
         }
     }
+
+    /// Upserts a checkpoint to the checkpoints collection.
+    /// # Usage
+    /// ```rs
+    /// database.upsert_checkpoint(checkpoint).await;
+    /// ```
+    pub async fn upsert_checkpoint(&self, checkpoint: CheckpointForDb) -> Result<(), String> {
+        let doc = to_document(&checkpoint).unwrap();
+        let command = doc! {"update":"checkpoints","updates":[{"q":{"name":&checkpoint.name},"u":doc,"upsert":true}]};
+        match self.db().run_command(command, None).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(format!("Cannot save the checkpoint: {e}")),
+        }
+    }
+
+    /// Finds a checkpoint by name. Returns `None` if the checkpoint is not saved yet.
+    /// # Usage
+    /// ```rs
+    /// let checkpoint = database.find_checkpoint("blocks_backfill").await;
+    /// ```
+    pub async fn find_checkpoint(&self, name: &str) -> Result<Option<CheckpointForDb>, String> {
+        self.checkpoints_collection()
+            .find_one(doc! {"name": name}, None)
+            .await
+            .map_err(|e| format!("Cannot make request to DB: {e}"))
+    }
+
+    /// Creates the event log collection as a capped collection, so the oldest events are dropped when it is full.
+    /// Does nothing if the collection already exists.
+    /// # Usage
+    /// ```rs
+    /// database.create_event_log().await;
+    /// ```
+    pub async fn create_event_log(&self) -> Result<(), String> {
+        let collection_names = self
+            .db()
+            .list_collection_names(doc! {"name": "event_log"})
+            .await
+            .map_err(|e| format!("Cannot make request to DB: {e}"))?;
+
+        if collection_names.is_empty() {
+            let options = CreateCollectionOptions::builder()
+                .capped(true)
+                .size(EVENT_LOG_MAX_SIZE)
+                .max(EVENT_LOG_MAX_COUNT)
+                .build();
+
+            self.db()
+                .create_collection("event_log", options)
+                .await
+                .map_err(|e| format!("Cannot create the event log: {e}"))?;
+        }
+
+        self.event_log_collection()
+            .create_index(unique_index(doc! {"seq": 1}), None)
+            .await
+            .map_err(|e| format!("Cannot create the indexes of the event log: {e}"))?;
+
+        Ok(())
+    }
+
+    /// Increments the counter with the given name by `count` and returns its new value, so the `count` values up to it are reserved for the caller.\
+    /// The first reserved value of a counter is `1`.
+    /// # Usage
+    /// ```rs
+    /// let last_seq = database.reserve_sequences("event_log", 1000).await;
+    /// ```
+    pub async fn reserve_sequences(&self, name: &str, count: u64) -> Result<u64, String> {
+        let options = FindOneAndUpdateOptions::builder()
+            .upsert(true)
+            .return_document(ReturnDocument::After)
+            .build();
+
+        let counter = self
+            .counters_collection()
+            .find_one_and_update(doc! {"name": name}, doc! {"$inc": {"seq": count as i64}}, options)
+            .await
+            .map_err(|e| format!("Cannot increment the counter: {e}"))?
+            .ok_or_else(|| format!("No counter is found for {name}."))?;
+
+        counter
+            .get_i64("seq")
+            .map(|seq| seq as u64)
+            .map_err(|e| format!("Invalid counter {name}: {e}"))
+    }
+
+    /// Adds an event to the event log collection.
+    /// # Usage
+    /// ```rs
+    /// database.add_event_log_entry(entry).await;
+    /// ```
+    pub async fn add_event_log_entry(&self, entry: EventLogEntryForDb) -> Result<(), String> {
+        match self.event_log_collection().insert_one(entry, None).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(format!("Cannot save the event log entry: {e}")),
+        }
+    }
+
+    /// Adds a webhook event that couldn't be delivered to the webhook dead letters collection.
+    /// # Usage
+    /// ```rs
+    /// database.add_webhook_dead_letter(dead_letter).await;
+    /// ```
+    pub async fn add_webhook_dead_letter(&self, dead_letter: WebhookDeadLetterForDb) -> Result<(), String> {
+        match self.webhook_dead_letters_collection().insert_one(dead_letter, None).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(format!("Cannot save the webhook dead letter: {e}")),
+        }
+    }
+
+    /// Finds the events with a greater sequence number than the given one, in ascending order.
+    /// # Usage
+    /// ```rs
+    /// let entries = database.find_event_log_since(1024, 1000).await;
+    /// ```
+    pub async fn find_event_log_since(&self, seq: u64, limit: i64) -> Result<Vec<EventLogEntryForDb>, String> {
+        let options = FindOptions::builder().sort(doc! {"seq": 1}).limit(limit).build();
+
+        let mut cursor = self
+            .event_log_collection()
+            .find(doc! {"seq": {"$gt": seq as i64}}, options)
+            .await
+            .map_err(|e| format!("Cannot make request to DB: {e}"))?;
+
+        let mut entries = vec![];
+        while let Some(entry) = cursor.next().await {
+            entries.push(entry.map_err(|e| format!("Cannot parse the event log entry: {e}"))?);
+        }
+
+        Ok(entries)
+    }
+
+    /// Saves whether each given validator signed the block at given height to the signing bitmaps.
+    /// The validators are given as HEX addresses with `true` if they missed the block.\
+    /// The validators of a block are saved by a single update command, the windows are unique by their validators and starts.
+    /// # Usage
+    /// ```rs
+    /// database.upsert_validator_signing(height, vec![(hex_address, false)]).await;
+    /// ```
+    pub async fn upsert_validator_signing(&self, height: u64, validators: Vec<(String, bool)>) -> Result<(), String> {
+        if validators.is_empty() {
+            return Ok(());
+        }
+
+        let window_start = ValidatorSigningWindowForDb::window_start_of(height) as i64;
+        let word_key = ValidatorSigningWindowForDb::word_key_of(height);
+        let bit_mask = ValidatorSigningWindowForDb::bit_mask_of(height);
+
+        let mut updates = vec![];
+        for (hex_address, missed) in validators {
+            let mut bits = Document::new();
+            bits.insert(format!("recorded.{word_key}"), doc! {"or": bit_mask});
+
+            if missed {
+                bits.insert(format!("missed.{word_key}"), doc! {"or": bit_mask});
+            }
+
+            updates.push(doc! {"q":{"hex_address":hex_address,"window_start":window_start},"u":{"$bit":bits},"upsert":true});
+        }
+
+        let command = doc! {"update":"validator_signing","updates":updates};
+        match self.db().run_command(command, None).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(format!("Cannot save the validator signing: {e}")),
+        }
+    }
+
+    /// Finds the signing windows of a validator that cover the heights between given ones, in ascending order.
+    /// # Usage
+    /// ```rs
+    /// let windows = database.find_validator_signing_windows(hex_address, 100, 1100).await;
+    /// ```
+    pub async fn find_validator_signing_windows(
+        &self,
+        hex_address: &str,
+        from: u64,
+        to: u64,
+    ) -> Result<Vec<ValidatorSigningWindowForDb>, TNRAppError> {
+        let collection = self.validator_signing_collection();
+
+        let query = doc! {
+            "hex_address": hex_address.to_ascii_uppercase(),
+            "window_start": {"$gte": ValidatorSigningWindowForDb::window_start_of(from) as i64, "$lte": to as i64},
+        };
+
+        let find_options = FindOptions::builder().sort(doc! {"window_start": 1}).build();
+
+        let mut results = collection.find(query, find_options).await.map_err(db_error)?;
+
+        let mut windows = vec![];
+        while let Some(result) = results.next().await {
+            windows.push(result.map_err(|e| format!("Cannot parse the validator signing: {e}"))?);
+        }
+
+        Ok(windows)
+    }
+
+    /// Finds an API key by the hash of the key.
+    /// # Usage
+    /// ```rs
+    /// let api_key = database.find_api_key(key_hash).await;
+    /// ```
+    pub async fn find_api_key(&self, key_hash: &str) -> Result<ApiKeyForDb, TNRAppError> {
+        match self.api_keys_collection().find_one(doc! {"key_hash": key_hash}, None).await {
+            Ok(api_key) => match api_key {
+                Some(api_key) => Ok(api_key),
+                None => Err(not_found("API key")),
+            },
+            Err(e) => Err(db_error(e)),
+        }
+    }
+}
+
+/// Returns the error of an item that is not in the database.
+fn not_found(item: &str) -> TNRAppError {
+    TNRAppError::new(TNRAppErrorType::NotFoundError, format!("No {item} is found."))
+}
+
+/// Returns the error of a failed request to the database, or a document of it that cannot be read.
+fn db_error(e: impl std::fmt::Display) -> TNRAppError {
+    TNRAppError::new(TNRAppErrorType::DbError, format!("Cannot make request to DB: {e}"))
+}
+
+/// Returns the heights between `from` and `to` (both inclusive) that are not in `existing_heights`, in ascending order.
+fn missing_heights(from: u64, to: u64, existing_heights: &HashSet<u64>) -> Vec<u64> {
+    (from..=to).filter(|height| !existing_heights.contains(height)).collect()
+}
+
+/// Returns an index of given keys.
+fn index(keys: Document) -> IndexModel {
+    IndexModel::builder().keys(keys).build()
+}
+
+/// Returns a unique index of given keys, the upserts by these keys cannot make duplicates.
+fn unique_index(keys: Document) -> IndexModel {
+    IndexModel::builder()
+        .keys(keys)
+        .options(IndexOptions::builder().unique(true).build())
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_heights_returns_the_gaps_in_order() {
+        let existing_heights = HashSet::from([10, 11, 13, 16]);
+
+        assert_eq!(missing_heights(10, 16, &existing_heights), vec![12, 14, 15]);
+    }
+
+    #[test]
+    fn missing_heights_includes_both_ends() {
+        let existing_heights = HashSet::from([11]);
+
+        assert_eq!(missing_heights(10, 12, &existing_heights), vec![10, 12]);
+    }
+
+    #[test]
+    fn missing_heights_is_empty_without_gaps() {
+        let existing_heights = HashSet::from([1, 2, 3]);
+
+        assert!(missing_heights(1, 3, &existing_heights).is_empty());
+    }
+
+    #[test]
+    fn missing_heights_ignores_heights_out_of_range() {
+        let existing_heights = HashSet::from([5, 100]);
+
+        assert_eq!(missing_heights(4, 6, &existing_heights), vec![4, 6]);
+    }
+
+    #[test]
+    fn missing_heights_of_an_empty_range_is_empty() {
+        assert!(missing_heights(10, 9, &HashSet::new()).is_empty());
+    }
 }
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::WsEvent;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EventLogEntry {
+    /// The sequence number of the event, increasing for each event of the chain. Eg: `1024`
+    pub seq: u64,
+    /// The event sent to the websocket clients.
+    pub event: WsEvent,
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::fetch::chain_socket::EvmPollVote;
 
@@ -14,7 +15,8 @@ pub struct EvmPoll {
     pub evm_deposit_address: String,
 }
 
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ToSchema)]
+#[schema(as = EvmPollParticipantForDb)]
 pub struct EvmPollParticipant {
     pub operator_address: String,
     pub confirmation: bool,
@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::fetch::heartbeats::HeartbeatStatus;
 
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ToSchema)]
+#[schema(as = HeartbeatForDb)]
 pub struct Heartbeat {
     pub period_height: u64,
     pub status: HeartbeatStatus,
@@ -11,7 +13,7 @@ pub struct Heartbeat {
     pub heartbeat_raw: Option<HeartbeatRaw>,
 }
 
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ToSchema)]
 pub struct HeartbeatRaw {
     pub tx_hash: String,
     pub height: u64,
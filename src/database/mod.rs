@@ -1,23 +1,44 @@
+mod address_txs;
+mod api_keys;
+mod block_events;
 mod blocks;
 mod chains;
+mod checkpoints;
 mod common;
 mod database_tr;
+mod event_log;
 mod evm;
 mod heartbeats;
 mod params;
 mod proposals;
 mod transactions;
+mod validator_signing;
 mod validators;
+mod webhooks;
 
-pub use database_tr::DatabaseTR;
+pub use database_tr::{DatabaseTR, SHARED_DB_NAME};
+
+pub use address_txs::AddressTransaction as AddressTransactionForDb;
+pub use address_txs::AddressTxRole as AddressTxRoleForDb;
+
+pub use api_keys::ApiKey as ApiKeyForDb;
+
+pub use block_events::BlockEvent as BlockEventForDb;
+pub use block_events::BlockEventAttribute as BlockEventAttributeForDb;
+pub use block_events::BlockEventPhase as BlockEventPhaseForDb;
+pub use block_events::BlockEvents as BlockEventsForDb;
 
 pub use blocks::Block as BlockForDb;
 
 pub use chains::Chain as ChainForDb;
 pub use chains::ChainDashboardInfo as ChainDashboardInfoForDb;
 
+pub use checkpoints::Checkpoint as CheckpointForDb;
+
 pub use common::*;
 
+pub use event_log::EventLogEntry as EventLogEntryForDb;
+
 pub use evm::EvmPoll as EvmPollForDb;
 pub use evm::EvmPollParticipant as EvmPollParticipantForDb;
 
@@ -26,6 +47,8 @@ pub use heartbeats::HeartbeatRaw as HeartbeatRawForDb;
 
 pub use params::DistributionParams as DistributionParamsForDb;
 pub use params::GovParams as GovParamsForDb;
+pub use params::DailyTokenPrice as DailyTokenPriceForDb;
+pub use params::MarketChart as MarketChartForDb;
 pub use params::Params as ParamsForDb;
 pub use params::SlashingParams as SlashingParamsForDb;
 pub use params::StakingParams as StakingParamsForDb;
@@ -35,5 +58,11 @@ pub use params::VotingPower as VotingPowerForDb;
 pub use proposals::{ProposalVote as ProposalVoteForDb, ProposalVoteOption as ProposalVoteOptionForDb};
 
 pub use transactions::Transaction as TransactionForDb;
+pub use transactions::TransactionDetails as TransactionDetailsForDb;
+
+pub use validator_signing::ValidatorSigningWindow as ValidatorSigningWindowForDb;
+pub use validator_signing::SIGNING_WINDOW_SIZE;
 
 pub use validators::Validator as ValidatorForDb;
+
+pub use webhooks::WebhookDeadLetter as WebhookDeadLetterForDb;
@@ -149,3 +149,14 @@ pub struct MarketChart {
     pub timestamp: u64,
     pub value: f64,
 }
+
+/// The price of a token on a day.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DailyTokenPrice {
+    /// Chain name of the token. Eg: `"axelar"`
+    pub token: String,
+    /// Milliseconds since the Unix epoch of the start of the day, in UTC.
+    pub timestamp: i64,
+    /// The USD price of the token. Eg: `0.7652`
+    pub value: f64,
+}
@@ -1,16 +1,33 @@
+use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-use crate::{fetch::transactions::TransactionItem, routes::ChainAmountItem};
+use crate::{
+    fetch::transactions::{InternalTransaction, TransactionItem},
+    routes::ChainAmountItem,
+};
 
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ToSchema)]
+#[schema(as = TransactionForDb)]
 pub struct Transaction {
     pub height: u64,
     #[serde(rename = "type")]
     pub tx_type: String,
+    #[serde(default)]
+    pub message_types: Vec<String>,
     pub hash: String,
     pub amount: ChainAmountItem,
+    /// The amount in the display unit, if it is in the main token of the chain. It is the indexed field of the minimum amount filter.
+    #[serde(default)]
+    pub main_amount: Option<f64>,
     pub fee: ChainAmountItem,
     pub result: String,
+    #[serde(default)]
+    pub code: u32,
+    #[serde(default)]
+    pub codespace: String,
+    #[serde(default)]
+    pub raw_log: Option<String>,
     pub time: i64,
 }
 
@@ -19,11 +36,78 @@ impl From<TransactionItem> for Transaction {
         Self {
             height: value.height,
             tx_type: value.tx_type.clone(),
+            message_types: value.message_types.clone(),
             hash: value.hash.clone(),
             amount: value.amount.clone(),
+            main_amount: None,
             fee: value.fee.clone(),
             result: value.result.clone(),
+            code: value.code,
+            codespace: value.codespace.clone(),
+            raw_log: value.raw_log.clone(),
             time: value.time,
         }
     }
 }
+
+impl Transaction {
+    /// Sets the amount of the main token, it is left empty if the transaction is in another token.\
+    /// The ticker of the amount is the symbol of the token, or its denom if the token is not in the assets of the chain.
+    pub fn with_main_token(self, main_symbol: &str, main_denom: &str) -> Self {
+        let main_amount = match self.amount.ticker == main_symbol || self.amount.ticker == main_denom {
+            true => self.amount.amount.to_f64(),
+            false => None,
+        };
+
+        Self { main_amount, ..self }
+    }
+}
+
+/// The fully decoded transaction, saved as it is returned from the transaction routes.
+pub type TransactionDetails = InternalTransaction;
+
+#[cfg(test)]
+mod tests {
+    use mongodb::bson::{from_document, to_document};
+
+    use super::*;
+    use crate::fetch::blocks::{CosmosEvent, CosmosEventAttribute};
+
+    #[test]
+    fn transaction_details_keep_their_events_when_saved_and_loaded() {
+        let events = vec![CosmosEvent {
+            r#type: "message".to_string(),
+            attributes: vec![CosmosEventAttribute {
+                key: "sender".to_string(),
+                value: "axelar1qy9uq03rkpqkzwsa4fz7xxetkxttdcj6m6ud5".to_string(),
+                index: true,
+            }],
+        }];
+
+        let tx = TransactionDetails {
+            hash: "A1B2".to_string(),
+            r#type: "Send".to_string(),
+            amount: ChainAmountItem::default(),
+            height: 100,
+            time: 1_658_208_386_000,
+            fee: ChainAmountItem::default(),
+            gas_wanted: 80_000,
+            gas_used: 74_032,
+            result: "Success".to_string(),
+            code: 0,
+            codespace: String::new(),
+            message_types: vec!["Send".to_string()],
+            memo: String::new(),
+            signatures: vec![],
+            signers: vec![],
+            content: vec![],
+            logs: vec![],
+            events: events.clone(),
+            raw: String::new(),
+        };
+
+        let loaded: TransactionDetails = from_document(to_document(&tx).unwrap()).unwrap();
+
+        assert_eq!(loaded.events, events);
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The count of heights kept in a single signing window.
+pub const SIGNING_WINDOW_SIZE: u64 = 1024;
+
+/// The signing history of a validator for `SIGNING_WINDOW_SIZE` heights, kept as bitmaps of 64 bit words.
+/// The words are keyed by their index in the window, like `"w3"` for the heights between `window_start + 192` and `window_start + 255`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ValidatorSigningWindow {
+    /// HEX encoded address of the validator. Eg: `"E42125451E65AC3931726936026F295677DB5D07"`
+    pub hex_address: String,
+    /// The first height of the window. Eg: `4611072`
+    pub window_start: u64,
+    /// The heights at which the validator is in the active set.
+    #[serde(default)]
+    pub recorded: BTreeMap<String, i64>,
+    /// The heights at which the validator is in the active set, but its signature is not committed.
+    #[serde(default)]
+    pub missed: BTreeMap<String, i64>,
+}
+
+impl ValidatorSigningWindow {
+    /// Returns the first height of the window that contains given height.
+    pub fn window_start_of(height: u64) -> u64 {
+        height - height % SIGNING_WINDOW_SIZE
+    }
+
+    /// Returns the key of the word that contains given height.
+    pub fn word_key_of(height: u64) -> String {
+        format!("w{}", (height % SIGNING_WINDOW_SIZE) / 64)
+    }
+
+    /// Returns the mask of the bit of given height in its word.
+    pub fn bit_mask_of(height: u64) -> i64 {
+        (1_u64 << (height % 64)) as i64
+    }
+
+    /// Returns `true` if the validator is in the active set at given height.
+    pub fn is_recorded(&self, height: u64) -> bool {
+        Self::is_set(&self.recorded, height)
+    }
+
+    /// Returns `true` if the validator missed the block at given height.
+    pub fn is_missed(&self, height: u64) -> bool {
+        Self::is_set(&self.missed, height)
+    }
+
+    /// Returns the count of the recorded and the missed heights of the window between given heights, both inclusive.
+    pub fn count_between(&self, from: u64, to: u64) -> (u64, u64) {
+        let mut recorded = 0;
+        let mut missed = 0;
+
+        for word_index in 0..(SIGNING_WINDOW_SIZE / 64) {
+            let word_start = self.window_start + word_index * 64;
+            let word_end = word_start + 63;
+
+            if word_end < from || word_start > to {
+                continue;
+            }
+
+            // The bits of the heights out of the range are cleared.
+            let first_bit = from.saturating_sub(word_start);
+            let last_bit = to.min(word_end) - word_start;
+            let mask = (u64::MAX >> (63 - last_bit)) & (u64::MAX << first_bit);
+
+            let key = format!("w{word_index}");
+            let recorded_word = self.recorded.get(&key).copied().unwrap_or_default() as u64;
+            let missed_word = self.missed.get(&key).copied().unwrap_or_default() as u64;
+
+            recorded += (recorded_word & mask).count_ones() as u64;
+            missed += (missed_word & mask).count_ones() as u64;
+        }
+
+        (recorded, missed)
+    }
+
+    fn is_set(words: &BTreeMap<String, i64>, height: u64) -> bool {
+        words
+            .get(&Self::word_key_of(height))
+            .map_or(false, |word| word & Self::bit_mask_of(height) != 0)
+    }
+}
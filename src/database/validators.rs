@@ -1,12 +1,17 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::fetch::evm::EvmSupportedChains;
 use crate::fetch::validators::ValidatorListValidatorCommission;
 
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ToSchema)]
+#[schema(as = ValidatorForDb)]
 pub struct Validator {
     pub rank: u64,
     pub name: String,
+    /// Keybase identity of the validator, the same across chains. Eg: `"367960C067E253A4"`
+    #[serde(default)]
+    pub identity: String,
     pub logo_url: String,
     pub bonded_height: Option<u64>,
     pub change_24h: Option<u64>,
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WebhookDeadLetter {
+    /// The webhook URL the event couldn't be posted to. Eg: `"https://example.com/hooks/axelar"`
+    pub url: String,
+    /// The name of the event. Eg: `"block"`
+    pub event: String,
+    /// The body of the webhook request.
+    pub payload: Value,
+    /// The error of the last attempt. Eg: `"Webhook https://example.com/hooks/axelar responded 503 Service Unavailable for block"`
+    pub error: String,
+    /// The count of the attempts made. Eg: `5`
+    pub attempts: u32,
+    /// The time the event is given up, as milliseconds. Eg: `1667497514115`
+    pub time: i64,
+}
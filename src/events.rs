@@ -1,18 +1,22 @@
-use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
 use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 use futures::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
 use serde_querystring::de::ParseMode;
 use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast::error::RecvError;
 use tokio::sync::broadcast::Sender;
-use tokio::sync::oneshot;
+use tokio::sync::{oneshot, Semaphore};
 use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
 use tokio_tungstenite::tungstenite::Message;
 
-use crate::database::{BlockForDb, EvmPollForDb, EvmPollParticipantForDb};
+use crate::database::{BlockForDb, DatabaseTR, EventLogEntryForDb, EvmPollForDb, EvmPollParticipantForDb};
 use crate::fetch::transactions::TransactionItem;
+use crate::metrics::METRICS;
+use crate::state::Chains;
 
 #[derive(Serialize, Deserialize, Debug)]
 struct SubscriptionMode {
@@ -22,13 +26,31 @@ struct SubscriptionMode {
     block: bool,
     #[serde(default)]
     poll: bool,
+    /// The sequence number of the last event received by the client. The events after it are replayed before the live ones.
+    #[serde(default)]
+    since: Option<u64>,
+}
+
+impl SubscriptionMode {
+    /// Returns `true` if the client is subscribed to the given event.
+    fn wants(&self, event: &WsEvent) -> bool {
+        match event {
+            WsEvent::NewTX(_) => self.tx,
+            WsEvent::NewBLock(_) => self.block,
+            WsEvent::NewEvmPoll(_) => self.poll,
+            WsEvent::UpdateEvmPollParticipant(_) => self.poll,
+        }
+    }
 }
 
+/// The maximum count of events read from the event log at once while replaying.
+const REPLAY_PAGE_SIZE: i64 = 1000;
+
 pub async fn handle_connection(
-    tx: Sender<(String, WsEvent)>,
+    tx: Sender<(String, SequencedWsEvent)>,
     raw_stream: TcpStream,
     addr: SocketAddr,
-    chains: HashSet<String>,
+    chains: Chains,
 ) -> Result<(), String> {
     tracing::info!("Incoming TCP connection from: {addr}");
 
@@ -38,7 +60,7 @@ pub async fn handle_connection(
             return Err(ErrorResponse::new(Some("No chain specified".to_string())));
         };
 
-        if !chains.contains(&chain) {
+        if !chains.contains_key(&chain) {
             return Err(ErrorResponse::new(Some("Chain is not found".to_string())));
         }
 
@@ -69,8 +91,27 @@ pub async fn handle_connection(
 
     let (mut outgoing, mut incoming) = ws_stream.split();
 
+    // The channel is subscribed before the replay, so the events produced during the replay are not lost.
     let mut rx = tx.subscribe();
 
+    // The chain is read once, it may be removed by the admin routes while the connection is open.
+    let database = chains
+        .get(&wanted_chain)
+        .map(|chain| chain.database.clone())
+        .ok_or_else(|| format!("{wanted_chain} is not a supported chain"))?;
+
+    // The sequence number of the last event sent to the client.
+    let mut last_seq = mode.since;
+
+    if let Some(since) = mode.since {
+        for event in replay_events(&database, since).await? {
+            last_seq = Some(event.seq);
+            if mode.wants(&event.event) {
+                let _ = outgoing.send(Message::Text(serde_json::to_string(&event).unwrap())).await;
+            }
+        }
+    }
+
     // while let Ok(msg) = rx.recv() {
     //     tracing::debug!("Got message from channel: {msg}");
     //     let msg = serde_json::to_string(&msg) else {
@@ -88,17 +129,40 @@ pub async fn handle_connection(
                     _ => {}
                 };
             },
-            Ok((chain, msg)) = rx.recv() => {
-                tracing::debug!("Got message from channel for chain {chain}: {msg}");
-
-                let should_send = match msg {
-                    WsEvent::NewTX(_) => mode.tx,
-                    WsEvent::NewBLock(_) => mode.block,
-                    WsEvent::NewEvmPoll(_) => mode.poll,
-                    WsEvent::UpdateEvmPollParticipant(_) => mode.poll,
+            received = rx.recv() => {
+                let events = match received {
+                    Ok((chain, msg)) => {
+                        tracing::debug!("Got message from channel for chain {chain}: {}", msg.event);
+
+                        if chain != wanted_chain || last_seq.map_or(false, |seq| msg.seq <= seq) {
+                            continue;
+                        }
+
+                        vec![msg]
+                    }
+                    // The skipped events are read from the event log, if any event is sent to the client before.
+                    // Otherwise the client is told about the gap, so it can reload its data.
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!("WebSocket connection {addr} lagged behind {skipped} events");
+                        METRICS.inc_ws_lagged_events(&wanted_chain, skipped);
+
+                        match last_seq {
+                            Some(seq) => replay_events(&database, seq).await?,
+                            None => {
+                                let resync = WsControlMessage::Resync { skipped };
+                                let _ = outgoing.send(Message::Text(serde_json::to_string(&resync).unwrap())).await;
+                                continue;
+                            }
+                        }
+                    }
+                    Err(RecvError::Closed) => break,
                 };
-                if chain == wanted_chain && should_send {
-                    let _ = outgoing.send(Message::Text(serde_json::to_string(&msg).unwrap())).await;
+
+                for event in events {
+                    last_seq = Some(event.seq);
+                    if mode.wants(&event.event) {
+                        let _ = outgoing.send(Message::Text(serde_json::to_string(&event).unwrap())).await;
+                    }
                 }
             }
         }
@@ -107,11 +171,56 @@ pub async fn handle_connection(
     Ok(())
 }
 
-pub async fn run_ws(tx: Sender<(String, WsEvent)>, chains: HashSet<String>) -> Result<(), String> {
-    let listener = TcpListener::bind("127.0.0.1:8081").await.map_err(|e| format!("Error binding: {e}"))?;
+/// Returns the events after the given sequence number from the event log.
+/// The events older than the event log retention are not returned.
+pub async fn replay_events(database: &DatabaseTR, since: u64) -> Result<Vec<SequencedWsEvent>, String> {
+    let mut events = vec![];
+    let mut last_seq = since;
+
+    loop {
+        let entries = database.find_event_log_since(last_seq, REPLAY_PAGE_SIZE).await?;
+        let is_last_page = (entries.len() as i64) < REPLAY_PAGE_SIZE;
+
+        for entry in entries {
+            last_seq = entry.seq;
+            events.push(SequencedWsEvent::from(entry));
+        }
+
+        if is_last_page {
+            return Ok(events);
+        }
+    }
+}
+
+/// Accepts the websocket connections.\
+/// The connections over `max_connections` are closed right away. The open connections are counted by `clients`.
+pub async fn run_ws(
+    tx: Sender<(String, SequencedWsEvent)>,
+    chains: Chains,
+    address: SocketAddr,
+    max_connections: usize,
+    clients: Arc<AtomicUsize>,
+) -> Result<(), String> {
+    let listener = TcpListener::bind(address).await.map_err(|e| format!("Error binding: {e}"))?;
+
+    let connections = Arc::new(Semaphore::new(max_connections));
 
     while let Ok((stream, addr)) = listener.accept().await {
-        tokio::spawn(handle_connection(tx.clone(), stream, addr, chains.clone()));
+        // The permit is released when the connection is closed.
+        let Ok(permit) = connections.clone().try_acquire_owned() else {
+            tracing::warn!("Rejected websocket connection from {addr}, {max_connections} connections are open");
+            continue;
+        };
+
+        let tx = tx.clone();
+        let chains = chains.clone();
+        let clients = clients.clone();
+        tokio::spawn(async move {
+            clients.fetch_add(1, Ordering::Relaxed);
+            let _ = handle_connection(tx, stream, addr, chains).await;
+            clients.fetch_sub(1, Ordering::Relaxed);
+            drop(permit);
+        });
     }
 
     Ok(())
@@ -125,6 +234,31 @@ pub enum WsEvent {
     UpdateEvmPollParticipant((String, EvmPollParticipantForDb)),
 }
 
+/// A message sent to a websocket client about its subscription, not about the chain.
+#[derive(Debug, Clone, Serialize)]
+pub enum WsControlMessage {
+    /// The client lagged behind the given count of events before any event is sent to it, so they can't be replayed.\
+    /// The client should reload its data, or reconnect with `since` set to the last sequence number it received.
+    Resync { skipped: u64 },
+}
+
+/// An event with its sequence number in the event log of the chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SequencedWsEvent {
+    pub seq: u64,
+    #[serde(flatten)]
+    pub event: WsEvent,
+}
+
+impl From<EventLogEntryForDb> for SequencedWsEvent {
+    fn from(entry: EventLogEntryForDb) -> Self {
+        Self {
+            seq: entry.seq,
+            event: entry.event,
+        }
+    }
+}
+
 impl Display for WsEvent {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1,29 +1,35 @@
 use mongodb::bson::doc;
 use serde::{Deserialize, Serialize};
 use tokio::join;
-use tonic::transport::Endpoint;
 
 use crate::{
     chain::Chain,
+    database::ListDbResult,
     fetch::cosmos::{
         auth::v1beta1::{query_client::QueryClient, QueryAccountRequest},
+        base::query::v1beta1::PageRequest,
         vesting::v1beta1::{BaseVestingAccount, ContinuousVestingAccount, DelayedVestingAccount, PeriodicVestingAccount, PermanentLockedAccount},
     },
-    routes::ChainAmountItem,
+    routes::{ChainAmountItem, TNRAppError, TNRAppErrorType},
 };
 
 use super::{amount_util::TnrDecimal, others::Pagination};
 use crate::routes::PaginationData;
 use prost::Message;
+use utoipa::ToSchema;
+
+/// The count of the balances returned if the request has no limit. The accounts rarely have more denoms.
+const BALANCES_DEFAULT_LIMIT: u64 = 1000;
 
 impl Chain {
-    pub async fn get_account_info(&self, account_address: &String) -> Result<AccountInfo, String> {
+    pub async fn get_account_info(&self, account_address: &String) -> Result<AccountInfo, TNRAppError> {
         let main_symbol = self.config.main_symbol.clone();
         let default_pagination_config = PaginationData {
             cursor: None,
             offset: None,
             limit: Some(10000),
             direction: None,
+            ..Default::default()
         };
         let (account_balance_resp, delegation_resp, delegetor_rewards_resp, delegation_unbonding_resp, find_val_db_resp) = join!(
             self.get_account_balance_by_denom(account_address, &self.config.main_denom),
@@ -90,24 +96,26 @@ impl Chain {
         })
     }
 
-    pub async fn get_account_balances(&self, account_address: &str, config: PaginationData) -> Result<Vec<ChainAmountItem>, String> {
+    /// Returns the balances of given account, paginated by offset cursors.
+    pub async fn get_account_balances(&self, account_address: &str, config: PaginationData) -> Result<ListDbResult<ChainAmountItem>, TNRAppError> {
         use crate::fetch::cosmos::bank::v1beta1::{query_client::QueryClient, QueryAllBalancesRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
+
+        let (offset, limit) = config.to_offset_page(BALANCES_DEFAULT_LIMIT)?;
 
         let req = QueryAllBalancesRequest {
             address: account_address.to_string(),
-            pagination: Some(config.into()),
+            pagination: Some(PageRequest {
+                offset,
+                limit,
+                count_total: true,
+                ..Default::default()
+            }),
             resolve_denom: false,
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
-            .all_balances(req)
-            .await
-            .map_err(|e| format!("{}", e))?
-            .into_inner();
+        let resp = QueryClient::new(channel).all_balances(req).await.map_err(TNRAppError::from)?.into_inner();
 
         let mut balances: Vec<ChainAmountItem> = vec![];
 
@@ -116,58 +124,56 @@ impl Chain {
             balances.push(amount);
         }
 
-        Ok(balances)
+        let total = resp.pagination.map(|pagination| pagination.total);
+
+        Ok(ListDbResult {
+            pagination: PaginationData::from_offset_page(offset, limit, balances.len() as u64, total),
+            data: balances,
+        })
     }
 
-    pub async fn get_account_balance_by_denom(&self, account_address: &str, denom: &str) -> Result<ChainAmountItem, String> {
+    pub async fn get_account_balance_by_denom(&self, account_address: &str, denom: &str) -> Result<ChainAmountItem, TNRAppError> {
         use crate::fetch::cosmos::bank::v1beta1::{query_client::QueryClient, QueryBalanceRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryBalanceRequest {
             address: account_address.to_string(),
             denom: denom.to_string(),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
-            .balance(req)
-            .await
-            .map_err(|e| format!("{}", e))?
-            .into_inner();
+        let resp = QueryClient::new(channel).balance(req).await.map_err(TNRAppError::from)?.into_inner();
 
-        let balance = resp.balance.ok_or(format!("Denom not found: {}", denom))?;
+        let balance = resp
+            .balance
+            .ok_or_else(|| TNRAppError::new(TNRAppErrorType::NotFoundError, format!("Denom not found: {}", denom)))?;
         let amount = self.string_amount_parser(balance.amount, Some(balance.denom)).await?;
 
         Ok(amount)
     }
 
-    pub async fn get_account_vesting_info(&self, account_address: String) -> Result<InternalVestingAccount, String> {
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+    pub async fn get_account_vesting_info(&self, account_address: String) -> Result<InternalVestingAccount, TNRAppError> {
+        let channel = self.grpc_channel().await?;
         let account_request = QueryAccountRequest { address: account_address };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .map_err(|e| format!("{}", e))?
-            .account(account_request)
-            .await
-            .map_err(|e| format!("{}", e))?;
+        let resp = QueryClient::new(channel).account(account_request).await.map_err(TNRAppError::from)?;
 
         let account_resp = resp.into_inner();
-        let account = account_resp.account.ok_or_else(|| "account not found".to_string())?;
+        let account = account_resp
+            .account
+            .ok_or_else(|| TNRAppError::new(TNRAppErrorType::NotFoundError, "No account is found."))?;
         let internal_vesting_account = InternalVestingAccount::from_grpc(self, account).await?;
         Ok(internal_vesting_account)
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct AccountInfo {
     pub native_token_balance: NativeTokenBalanceInfo,
     pub total_amount: ChainAmountItem,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct NativeTokenBalanceInfo {
     pub available: ChainAmountItem,
     pub delegated: ChainAmountItem,
@@ -177,7 +183,7 @@ pub struct NativeTokenBalanceInfo {
     pub validator_comission: Option<ChainAmountItem>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct InternalVestingAccount {
     pub original_vesting: Option<ChainAmountItem>,
     pub delegated_free: Option<ChainAmountItem>,
@@ -280,7 +286,7 @@ impl InternalVestingAccount {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct InternalPeriod {
     pub end_time: i64,
     pub amount: ChainAmountItem,
@@ -0,0 +1,392 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use mongodb::bson::{doc, Document};
+use serde_json::Value;
+use tokio::join;
+
+use crate::chain::Chain;
+use crate::database::{AddressTransactionForDb, AddressTxRoleForDb, ListDbResult};
+use crate::routes::{PaginationData, PaginationDirection, TNRAppError};
+use crate::utils::bech32_address_prefix;
+
+use super::others::PaginationConfig;
+use super::transactions::{InternalTransaction, TransactionItem};
+
+/// Message fields and event attribute keys that hold the sender of a message.
+const SENDER_KEYS: [&str; 3] = ["sender", "from_address", "spender"];
+
+/// Message fields and event attribute keys that hold the recipient of a message.
+const RECIPIENT_KEYS: [&str; 4] = ["recipient", "receiver", "to_address", "destination_address"];
+
+/// The prefix of the cursors of the pages read from the node, after the address index is exhausted.
+const NODE_CURSOR_PREFIX: &str = "node:";
+
+/// The count of the address transactions in a page, if it is not given.
+const DEFAULT_PAGE_LIMIT: u64 = 20;
+
+impl Chain {
+    /// Returns the transactions given address is involved in, from the address index.
+    /// Only the transactions the address has given role in are returned, if a role is given.\
+    /// The transactions older than the ingestion are read from the node once the index is exhausted, see `find_address_txs`.
+    pub async fn get_address_txs(
+        &self,
+        address: &str,
+        role: Option<AddressTxRoleForDb>,
+        config: PaginationData,
+    ) -> Result<ListDbResult<TransactionItem>, TNRAppError> {
+        let query = match role {
+            Some(role) => doc! {"address": address, "roles": role.as_str()},
+            None => doc! {"address": address},
+        };
+
+        let address_txs = self.find_address_txs(address, role, query, config).await?;
+
+        Ok(ListDbResult {
+            data: address_txs.data.into_iter().map(TransactionItem::from).collect(),
+            pagination: address_txs.pagination,
+        })
+    }
+
+    /// Returns a page of the address transactions matching given query, from the address index.\
+    /// The index has every transaction since the blocks with no gaps start. When it is exhausted, the next cursor points to the pages of the node,
+    /// which have the transactions before these blocks. The node pages are not filtered by the query.
+    pub(super) async fn find_address_txs(
+        &self,
+        address: &str,
+        role: Option<AddressTxRoleForDb>,
+        mut query: Document,
+        config: PaginationData,
+    ) -> Result<ListDbResult<AddressTransactionForDb>, TNRAppError> {
+        let limit = config.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        let index_start = self.find_contiguous_blocks_start().await?;
+
+        if let Some(cursor) = config.cursor.as_deref().and_then(NodeCursor::parse) {
+            return self.get_node_address_txs(address, role, index_start, cursor, limit).await;
+        }
+
+        // The transactions saved before a gap are read from the node, so they are not listed twice.
+        if let Some(index_start) = index_start {
+            query.insert("height", doc! {"$gte": index_start as i64});
+        }
+
+        let is_prev = matches!(config.direction, Some(PaginationDirection::Prev));
+        let mut address_txs = self.database.find_paginated_address_txs(Some(query), config).await?;
+
+        let is_exhausted = !is_prev && (address_txs.data.len() as u64) < limit;
+
+        // The chain has blocks before the index start, or no block is saved yet.
+        let has_history_before_index = index_start.map_or(true, |height| height > 1);
+
+        if is_exhausted && has_history_before_index {
+            match address_txs.data.is_empty() {
+                true => return self.get_node_address_txs(address, role, index_start, NodeCursor::default(), limit).await,
+                false => address_txs.pagination.cursor = Some(NodeCursor::default().to_string()),
+            }
+        }
+
+        Ok(address_txs)
+    }
+
+    /// Returns a page of the transactions of the address before given height, from the node.\
+    /// The node can't search by the other roles, so the sent and the received transactions are merged into a single list if no role is given.
+    /// The merged pages can only be read forward, as the position of a page depends on the pages before it.
+    async fn get_node_address_txs(
+        &self,
+        address: &str,
+        role: Option<AddressTxRoleForDb>,
+        before_height: Option<u64>,
+        cursor: NodeCursor,
+        limit: u64,
+    ) -> Result<ListDbResult<AddressTransactionForDb>, TNRAppError> {
+        let page_config = |offset: u64| PaginationConfig {
+            offset: offset.min(u32::MAX as u64) as u32,
+            ..PaginationConfig::new().limit(limit.min(u16::MAX as u64) as u16)
+        };
+
+        let (address_txs, next_cursor, prev_cursor) = match role {
+            Some(role @ (AddressTxRoleForDb::Sender | AddressTxRoleForDb::Recipient)) => {
+                let resp = match role {
+                    AddressTxRoleForDb::Sender => self.get_txs_by_sender(address, before_height, page_config(cursor.sent)).await?,
+                    _ => self.get_txs_by_recipient(address, before_height, page_config(cursor.sent)).await?,
+                };
+
+                let address_txs = to_address_txs(address, role, resp.value);
+
+                let next_cursor = NodeCursor {
+                    sent: cursor.sent + address_txs.len() as u64,
+                    received: 0,
+                };
+                let prev_cursor = NodeCursor {
+                    sent: cursor.sent.saturating_sub(limit),
+                    received: 0,
+                };
+
+                let has_next = address_txs.len() as u64 == limit;
+
+                (address_txs, has_next.then_some(next_cursor), (cursor.sent > 0).then_some(prev_cursor))
+            }
+            Some(AddressTxRoleForDb::Involved) | None => {
+                let (sent, received) = join!(
+                    self.get_txs_by_sender(address, before_height, page_config(cursor.sent)),
+                    self.get_txs_by_recipient(address, before_height, page_config(cursor.received))
+                );
+
+                let sent = to_address_txs(address, AddressTxRoleForDb::Sender, sent?.value);
+                let received = to_address_txs(address, AddressTxRoleForDb::Recipient, received?.value);
+
+                // A full page of the node may have more transactions after it.
+                let has_next = |page_len: usize, taken_count: usize| taken_count < page_len || page_len as u64 == limit;
+                let (sent_len, received_len) = (sent.len(), received.len());
+
+                let (address_txs, sent_count, received_count) = merge_node_txs(sent, received, limit as usize);
+
+                let next_cursor = NodeCursor {
+                    sent: cursor.sent + sent_count as u64,
+                    received: cursor.received + received_count as u64,
+                };
+
+                let has_next = has_next(sent_len, sent_count) || has_next(received_len, received_count);
+
+                (address_txs, has_next.then_some(next_cursor), None)
+            }
+        };
+
+        Ok(ListDbResult {
+            data: address_txs,
+            pagination: PaginationData {
+                cursor: next_cursor.map(|cursor| cursor.to_string()),
+                limit: Some(limit),
+                prev_cursor: prev_cursor.map(|cursor| cursor.to_string()),
+                ..Default::default()
+            },
+        })
+    }
+}
+
+/// Returns the address index items of the transactions read from the node.
+fn to_address_txs(address: &str, role: AddressTxRoleForDb, txs: Vec<TransactionItem>) -> Vec<AddressTransactionForDb> {
+    txs.into_iter()
+        .map(|tx| AddressTransactionForDb::new(address.to_string(), vec![role], tx))
+        .collect()
+}
+
+/// Merges the pages of the sent and the received transactions from the newest to the oldest, up to given limit.\
+/// A transaction in both pages is listed once with both roles.
+/// Returns the merged transactions, and the counts of the transactions taken from the sent and the received pages.
+fn merge_node_txs(
+    sent: Vec<AddressTransactionForDb>,
+    received: Vec<AddressTransactionForDb>,
+    limit: usize,
+) -> (Vec<AddressTransactionForDb>, usize, usize) {
+    let (mut sent, mut received) = (sent.into_iter().peekable(), received.into_iter().peekable());
+    let (mut sent_count, mut received_count) = (0, 0);
+    let mut address_txs: Vec<AddressTransactionForDb> = vec![];
+
+    loop {
+        let is_listed = |tx: &AddressTransactionForDb| address_txs.iter().any(|address_tx| address_tx.hash == tx.hash);
+
+        // The other role of a listed transaction is taken even if the page is full, so it isn't listed again in the next page.
+        let is_sent = match (sent.peek(), received.peek()) {
+            (Some(sent_tx), _) if is_listed(sent_tx) => true,
+            (_, Some(received_tx)) if is_listed(received_tx) => false,
+            _ if address_txs.len() >= limit => break,
+            (Some(sent_tx), Some(received_tx)) => sent_tx.height >= received_tx.height,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => break,
+        };
+
+        let tx = match is_sent {
+            true => {
+                sent_count += 1;
+                sent.next()
+            }
+            false => {
+                received_count += 1;
+                received.next()
+            }
+        };
+
+        if let Some(tx) = tx {
+            match address_txs.iter_mut().find(|address_tx| address_tx.hash == tx.hash) {
+                Some(address_tx) => address_tx.roles.extend(tx.roles),
+                None => address_txs.push(tx),
+            }
+        }
+    }
+
+    (address_txs, sent_count, received_count)
+}
+
+/// The position of a page of the node, as the counts of the sent and the received transactions before it.\
+/// Only the sent count is used if the transactions of a single role are read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct NodeCursor {
+    sent: u64,
+    received: u64,
+}
+
+impl NodeCursor {
+    /// Returns the position of a cursor of the node, or `None` if it is a cursor of the address index.
+    fn parse(cursor: &str) -> Option<Self> {
+        let (sent, received) = cursor.strip_prefix(NODE_CURSOR_PREFIX)?.split_once(':')?;
+
+        Some(Self {
+            sent: sent.parse().ok()?,
+            received: received.parse().ok()?,
+        })
+    }
+}
+
+impl std::fmt::Display for NodeCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{NODE_CURSOR_PREFIX}{}:{}", self.sent, self.received)
+    }
+}
+
+/// Returns `true` if the cursor points to a page of the node, not the address index.
+pub(super) fn is_node_cursor(cursor: &str) -> bool {
+    NodeCursor::parse(cursor).is_some()
+}
+
+impl InternalTransaction {
+    /// Returns the address index items of the transaction, one for each bech32 address appearing in its messages or events.
+    pub fn to_address_txs(&self) -> Vec<AddressTransactionForDb> {
+        let mut roles: BTreeMap<String, BTreeSet<AddressTxRoleForDb>> = BTreeMap::new();
+
+        for signer in &self.signers {
+            roles.entry(signer.clone()).or_default().insert(AddressTxRoleForDb::Sender);
+        }
+
+        if let Ok(content) = serde_json::to_value(&self.content) {
+            collect_content_addresses(None, &content, &mut roles);
+        }
+
+        let log_attributes = self
+            .logs
+            .iter()
+            .flat_map(|log| log.events.iter())
+            .flat_map(|event| event.attributes.iter())
+            .map(|attribute| (attribute.key.as_str(), attribute.value.as_str()));
+
+        let event_attributes = self
+            .events
+            .iter()
+            .flat_map(|event| event.attributes.iter())
+            .map(|attribute| (attribute.key.as_str(), attribute.value.as_str()));
+
+        for (key, value) in log_attributes.chain(event_attributes) {
+            if is_bech32_address(value) {
+                roles.entry(value.to_string()).or_default().insert(get_role_by_key(key));
+            }
+        }
+
+        roles
+            .into_iter()
+            .map(|(address, roles)| AddressTransactionForDb::new(address, roles.into_iter().collect(), TransactionItem::from(self.clone())))
+            .collect()
+    }
+}
+
+/// Walks through the JSON value of the transaction messages, and collects the bech32 addresses in it.
+fn collect_content_addresses(key: Option<&str>, value: &Value, roles: &mut BTreeMap<String, BTreeSet<AddressTxRoleForDb>>) {
+    match value {
+        Value::String(value) => {
+            if is_bech32_address(value) {
+                let role = key.map(get_role_by_key).unwrap_or(AddressTxRoleForDb::Involved);
+                roles.entry(value.to_string()).or_default().insert(role);
+            }
+        }
+        Value::Array(values) => values.iter().for_each(|value| collect_content_addresses(key, value, roles)),
+        Value::Object(map) => map.iter().for_each(|(key, value)| collect_content_addresses(Some(key), value, roles)),
+        _ => {}
+    }
+}
+
+/// Returns the role of an address by the message field or the event attribute key it is found in.
+fn get_role_by_key(key: &str) -> AddressTxRoleForDb {
+    if SENDER_KEYS.contains(&key) {
+        AddressTxRoleForDb::Sender
+    } else if RECIPIENT_KEYS.contains(&key) {
+        AddressTxRoleForDb::Recipient
+    } else {
+        AddressTxRoleForDb::Involved
+    }
+}
+
+/// Returns `true` if given value is a valid bech32 encoded address of any chain.
+fn is_bech32_address(value: &str) -> bool {
+    bech32_address_prefix(value).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::ChainAmountItem;
+
+    fn address_tx(role: AddressTxRoleForDb, height: u64, hash: &str) -> AddressTransactionForDb {
+        let tx = TransactionItem {
+            height,
+            tx_type: "Send".to_string(),
+            message_types: vec!["Send".to_string()],
+            hash: hash.to_string(),
+            amount: ChainAmountItem::default(),
+            fee: ChainAmountItem::default(),
+            result: "Success".to_string(),
+            code: 0,
+            codespace: String::new(),
+            raw_log: None,
+            time: 0,
+        };
+
+        AddressTransactionForDb::new("axelar1address".to_string(), vec![role], tx)
+    }
+
+    fn hashes(address_txs: &[AddressTransactionForDb]) -> Vec<&str> {
+        address_txs.iter().map(|address_tx| address_tx.hash.as_str()).collect()
+    }
+
+    #[test]
+    fn merge_node_txs_lists_up_to_limit_from_the_newest() {
+        let sent = vec![
+            address_tx(AddressTxRoleForDb::Sender, 9, "a"),
+            address_tx(AddressTxRoleForDb::Sender, 5, "c"),
+        ];
+        let received = vec![
+            address_tx(AddressTxRoleForDb::Recipient, 7, "b"),
+            address_tx(AddressTxRoleForDb::Recipient, 3, "d"),
+        ];
+
+        let (address_txs, sent_count, received_count) = merge_node_txs(sent, received, 3);
+
+        assert_eq!(hashes(&address_txs), ["a", "b", "c"]);
+        assert_eq!((sent_count, received_count), (2, 1));
+    }
+
+    #[test]
+    fn merge_node_txs_lists_a_transaction_in_both_pages_once() {
+        let sent = vec![
+            address_tx(AddressTxRoleForDb::Sender, 9, "a"),
+            address_tx(AddressTxRoleForDb::Sender, 5, "b"),
+        ];
+        let received = vec![
+            address_tx(AddressTxRoleForDb::Recipient, 9, "a"),
+            address_tx(AddressTxRoleForDb::Recipient, 3, "c"),
+        ];
+
+        let (address_txs, sent_count, received_count) = merge_node_txs(sent, received, 1);
+
+        assert_eq!(hashes(&address_txs), ["a"]);
+        assert_eq!(address_txs[0].roles, [AddressTxRoleForDb::Sender, AddressTxRoleForDb::Recipient]);
+        assert_eq!((sent_count, received_count), (1, 1));
+    }
+
+    #[test]
+    fn node_cursor_is_parsed_back() {
+        let cursor = NodeCursor { sent: 300, received: 41 };
+
+        assert_eq!(NodeCursor::parse(&cursor.to_string()), Some(cursor));
+        assert_eq!(NodeCursor::parse("node:3"), None);
+        assert_eq!(NodeCursor::parse("eyJ0aW1lIjoxfQ"), None);
+    }
+}
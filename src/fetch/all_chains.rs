@@ -0,0 +1,194 @@
+use std::fmt::Display;
+use std::future::Future;
+use std::time::Duration;
+
+use futures::future::join_all;
+use mongodb::bson::doc;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::chain::Chain;
+use crate::database::{BlockForDb, ValidatorForDb};
+use crate::fetch::chain::ChainDashboardInfoResponse;
+use crate::routes::{self, TNRAppError};
+use crate::state::State;
+
+/// The time limit of the job of a single chain in the cross-chain requests.
+const ALL_CHAINS_TIMEOUT: Duration = Duration::from_secs(10);
+
+impl State {
+    /// Runs the job for every chain concurrently, sorted by chain name.\
+    /// A failing or slow chain doesn't affect the others, its error is returned in its place.\
+    /// The jobs are given the chains at the moment, the chains added or removed meanwhile are not affected.
+    async fn on_all_chains<T, E, F, Fut>(&self, job: F) -> Vec<ChainOutcome<T>>
+    where
+        F: Fn(Chain) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: Display,
+    {
+        let mut chains: Vec<(String, Chain)> = self.get_chains().into_iter().collect();
+        chains.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let jobs = chains.into_iter().map(|(name, chain)| {
+            let job = job(chain);
+
+            async move {
+                let result = match tokio::time::timeout(ALL_CHAINS_TIMEOUT, job).await {
+                    Ok(result) => result.map_err(|e| e.to_string()),
+                    Err(_) => Err(format!("{name} didn't respond in {} seconds.", ALL_CHAINS_TIMEOUT.as_secs())),
+                };
+
+                if let Err(e) = &result {
+                    tracing::warn!("Cross-chain request failed for {name}: {e}");
+                }
+
+                ChainOutcome::new(&name, result)
+            }
+        });
+
+        join_all(jobs).await
+    }
+
+    /// Returns the dashboard info of every chain, with the totals of the chains that responded.\
+    /// The cached dashboards of the chains are used, like `{chain}/dashboard` does.
+    pub async fn get_all_dashboard_info(&self) -> AllChainsDashboard {
+        let chains = self
+            .on_all_chains(|chain| async move {
+                let info: ChainDashboardInfoResponse = routes::dashboard_response(&chain).await?.data()?;
+                Ok::<_, TNRAppError>(ChainDashboardSummary::from(info))
+            })
+            .await;
+
+        let summaries: Vec<&ChainDashboardSummary> = chains.iter().filter_map(|outcome| outcome.data.as_ref()).collect();
+
+        let total_market_cap: f64 = summaries.iter().map(|summary| summary.market_cap).sum();
+        let total_staked_value: f64 = summaries.iter().map(|summary| summary.staked_value).sum();
+
+        // The APRs are weighted by the staked values, so the chains without a price don't skew the average.
+        let weighted_apr = match total_staked_value > 0.0 {
+            true => summaries.iter().map(|summary| summary.apr * summary.staked_value).sum::<f64>() / total_staked_value,
+            false => 0.0,
+        };
+
+        AllChainsDashboard {
+            chain_count: chains.len(),
+            failed_chain_count: chains.len() - summaries.len(),
+            total_market_cap,
+            total_staked_value,
+            weighted_apr,
+            chains,
+        }
+    }
+
+    /// Returns the last saved blocks of every chain.
+    pub async fn get_all_latest_blocks(&self, count: u16) -> Vec<ChainOutcome<Vec<BlockForDb>>> {
+        self.on_all_chains(|chain| async move { chain.get_last_blocks_from_db(count).await })
+            .await
+    }
+
+    /// Returns the validators whose moniker or Keybase identity matches the query on every chain.
+    /// Monikers are matched case insensitively. The chains without any match are not returned.
+    pub async fn get_validators_across_chains(&self, moniker_or_identity: &str) -> Vec<ChainOutcome<Vec<ValidatorForDb>>> {
+        let moniker_pattern = format!("^{}$", escape_regex(moniker_or_identity));
+
+        let filter = doc! {"$match": {"$or": [
+            {"identity": moniker_or_identity.to_uppercase()},
+            {"name": {"$regex": moniker_pattern, "$options": "i"}},
+        ]}};
+
+        self.on_all_chains(|chain| {
+            let filter = filter.clone();
+            async move { chain.database.find_validators(Some(filter)).await }
+        })
+        .await
+        .into_iter()
+        .filter(|outcome| outcome.data.as_ref().map_or(true, |validators| !validators.is_empty()))
+        .collect()
+    }
+}
+
+/// Escapes the characters that have a meaning in a regular expression.
+fn escape_regex(value: &str) -> String {
+    value.chars().fold(String::new(), |mut escaped, c| {
+        if "\\^$.|?*+()[]{}".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+        escaped
+    })
+}
+
+/// The result of a cross-chain request for a single chain.
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+#[aliases(
+    ChainDashboardOutcome = ChainOutcome<ChainDashboardSummary>,
+    ChainBlocksOutcome = ChainOutcome<Vec<BlockForDb>>,
+    ChainValidatorsOutcome = ChainOutcome<Vec<ValidatorForDb>>
+)]
+pub struct ChainOutcome<T> {
+    /// Chain name. Eg: `"axelar"`
+    pub chain: String,
+    /// The data of the chain, if the chain responded.
+    pub data: Option<T>,
+    /// The error of the chain, if the chain failed.
+    pub error: Option<String>,
+}
+
+impl<T> ChainOutcome<T> {
+    pub fn new(chain: &str, result: Result<T, String>) -> Self {
+        match result {
+            Ok(data) => Self {
+                chain: chain.to_string(),
+                data: Some(data),
+                error: None,
+            },
+            Err(e) => Self {
+                chain: chain.to_string(),
+                data: None,
+                error: Some(e),
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct AllChainsDashboard {
+    pub chain_count: usize,
+    /// The count of the chains that didn't respond. They are not counted in the totals.
+    pub failed_chain_count: usize,
+    /// The sum of the market caps in USD.
+    pub total_market_cap: f64,
+    /// The sum of the values of the bonded tokens in USD.
+    pub total_staked_value: f64,
+    /// The average APR of the chains, weighted by their staked values. Eg: `0.1245`
+    pub weighted_apr: f64,
+    #[schema(value_type = Vec<ChainDashboardOutcome>)]
+    pub chains: Vec<ChainOutcome<ChainDashboardSummary>>,
+}
+
+/// The dashboard info of a chain, without its market history.
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct ChainDashboardSummary {
+    pub price: f64,
+    pub market_cap: f64,
+    pub inflation_rate: f64,
+    pub apr: f64,
+    pub total_bonded: f64,
+    pub total_supply: String,
+    /// The value of the bonded tokens in USD.
+    pub staked_value: f64,
+}
+
+impl From<ChainDashboardInfoResponse> for ChainDashboardSummary {
+    fn from(value: ChainDashboardInfoResponse) -> Self {
+        Self {
+            price: value.price,
+            market_cap: value.market_cap,
+            inflation_rate: value.inflation_rate,
+            apr: value.apr,
+            total_bonded: value.total_bonded,
+            total_supply: value.total_supply,
+            staked_value: value.total_bonded * value.price,
+        }
+    }
+}
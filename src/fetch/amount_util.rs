@@ -1,7 +1,7 @@
 use rust_decimal::Decimal;
 
 use crate::chain::Chain;
-use crate::routes::ChainAmountItem;
+use crate::routes::{ChainAmountItem, TNRAppError};
 
 pub struct ChainAmountItemBuilder {
     pub amount: TnrDecimal,
@@ -33,7 +33,7 @@ impl ChainAmountItemBuilder {
         self
     }
 
-    pub async fn build(&self) -> Result<ChainAmountItem, String> {
+    pub async fn build(&self) -> Result<ChainAmountItem, TNRAppError> {
         let mut amount = self.amount;
         let mut ticker = self.ticker.clone();
 
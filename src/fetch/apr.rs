@@ -6,15 +6,19 @@ use std::{
 use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 use tokio::join;
-use tonic::transport::Endpoint;
 
-use crate::{chain::Chain, fetch::amount_util::TnrDecimal, utils::str_to_dec};
+use crate::{
+    chain::Chain,
+    fetch::amount_util::TnrDecimal,
+    routes::{TNRAppError, TNRAppErrorType},
+    utils::str_to_dec,
+};
 
 impl Chain {
     /// Returns the APR rate of the chain.
     ///
     /// Reference: https://github.com/bro-n-bro/prometheus_exporter/blob/main/docs/APR%20calcucation.md#non-epoch-cosmos-based--blockchains
-    pub async fn get_apr(&self) -> Result<f64, String> {
+    pub async fn get_apr(&self) -> Result<f64, TNRAppError> {
         const ANNUAL_PROVISION_MUL_RATIO: f64 = 365.3;
         // If the chain has epochs.
         if self.config.epoch {
@@ -34,16 +38,14 @@ impl Chain {
                 "evmos" => {
                     use crate::fetch::evmos::inflation::v1::{query_client::QueryClient, QueryParamsRequest};
 
-                    let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+                    let channel = self.grpc_channel().await?;
 
                     let req = QueryParamsRequest {};
 
-                    let inflation_dist = QueryClient::connect(endpoint)
-                        .await
-                        .unwrap()
+                    let inflation_dist = QueryClient::new(channel)
                         .params(req)
                         .await
-                        .map_err(|e| format!("{}", e))?
+                        .map_err(TNRAppError::from)?
                         .into_inner()
                         .params
                         .ok_or_else(|| "Missing params".to_string())?
@@ -78,7 +80,10 @@ impl Chain {
                     let apr = annual_provision * staking_rewards_factor / bonded_tokens_amount;
                     Ok(apr)
                 }
-                chain_name => Err(format!("APR for {chain_name} is not implemented.")),
+                chain_name => Err(TNRAppError::new(
+                    TNRAppErrorType::UnsupportedError,
+                    format!("APR for {chain_name} is not implemented."),
+                )),
             }
         } else {
             let (params_all_res, staking_pool_res, inflation_rate_res) =
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
 
 use crate::chain::Chain;
+use crate::routes::TNRAppError;
 
 struct AssetsPool {
     list: Mutex<Vec<ChainAsset>>,
@@ -25,7 +26,7 @@ impl AssetsPool {
 }
 
 impl Chain {
-    pub async fn cosmos_assets(&self) -> Result<Assets, String> {
+    pub async fn cosmos_assets(&self) -> Result<Assets, TNRAppError> {
         let base_assets_url = std::env::var("TNR_EXPLORER_ASSETS_URI").expect("TNR_EXPLORER_ASSETS_URI must be set in .env file");
         let full_cosmos_assets_url = format!("{}/cosmos/chain_assets.json", base_assets_url);
         let current_timestamp = Utc::now().timestamp() as usize;
@@ -0,0 +1,187 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use actix_web::ResponseError;
+use futures::{stream, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_querystring::de::ParseMode;
+use utoipa::ToSchema;
+
+use crate::cache::CachedResponse;
+use crate::routes::{self, TNRAppError, TNRAppErrorResponse, TNRAppErrorType, TNRAppSuccessResponse};
+use crate::state::State;
+
+impl State {
+    /// Runs the items of a batch concurrently, and returns their results in the order of the items.\
+    /// A failing item doesn't affect the others, its error is returned in its place.
+    pub async fn run_batch(&self, items: Vec<BatchItem>) -> Result<Vec<BatchItemResult>, TNRAppError> {
+        let max_items = self.config().batch.max_items;
+
+        if items.len() > max_items {
+            return Err(TNRAppError::new(
+                TNRAppErrorType::BadRequestError,
+                format!("A batch can have at most {max_items} items."),
+            ));
+        }
+
+        let results = stream::iter(items)
+            .map(|item| async move {
+                let result = self.run_batch_item(&item).await;
+                BatchItemResult::new(item, result)
+            })
+            .buffered(self.config().batch.concurrency)
+            .collect()
+            .await;
+
+        Ok(results)
+    }
+
+    /// Runs an item of a batch with the handler of its route, and returns the response of the route.
+    async fn run_batch_item(&self, item: &BatchItem) -> Result<Value, TNRAppError> {
+        let chain = self.get(&item.chain).map_err(|e| TNRAppError::new(TNRAppErrorType::NotFoundError, e))?;
+        let params = BatchParams(&item.params);
+
+        match item.route.as_str() {
+            "dashboard" => from_cached(routes::dashboard_response(&chain).await?),
+            "stats" => from_cached(routes::stats_response(&chain).await?),
+            "params" => from_cached(routes::params_response(&chain).await?),
+            "inflation" => from_cached(routes::inflation_response(&chain).await?),
+            "validators-bonded" => from_cached(routes::validators_bonded_response(&chain, params.query()?).await?),
+            "community-pool" => to_value(routes::community_pool_response(&chain).await?),
+            "staking-pool" => to_value(routes::staking_pool_response(&chain).await?),
+            "supplies" => to_value(routes::supplies_response(&chain, params.query()?).await?),
+            "last-blocks" => to_value(routes::last_blocks_response(&chain, params.query()?).await?),
+            "last-txs" => to_value(routes::last_txs_response(&chain, params.query()?).await?),
+            "block-by-height" => to_value(routes::block_by_height_response(&chain, params.path("height")?).await?),
+            "block-by-hash" => to_value(routes::block_by_hash_response(&chain, &params.path::<String>("hash")?).await?),
+            "tx" => to_value(routes::tx_response(&chain, &params.path::<String>("hash")?).await?),
+            "validator" => to_value(routes::validator_response(&chain, &params.path::<String>("address")?).await?),
+            "validator-uptime" => to_value(routes::validator_uptime_response(&chain, &params.path::<String>("address")?).await?),
+            "account" => to_value(routes::account_response(&chain, &params.path::<String>("account_address")?).await?),
+            "balances" => to_value(routes::balances_response(&chain, &params.path::<String>("account_address")?, params.query()?).await?),
+            "proposals" => to_value(routes::proposals_response(&chain, params.query()?).await?),
+            "proposal-details" => to_value(routes::proposal_details_response(&chain, params.path("id")?).await?),
+            route => Err(TNRAppError::new(
+                TNRAppErrorType::BadRequestError,
+                format!("`{route}` route is not supported in the batches."),
+            )),
+        }
+    }
+}
+
+/// Returns a cached response of a route as JSON, the responses are shared with the route.
+fn from_cached(cached: CachedResponse) -> Result<Value, TNRAppError> {
+    serde_json::from_str(cached.body()).map_err(|e| TNRAppError::from(format!("Cannot parse the cached response: {e}")))
+}
+
+/// Returns the response of a route as JSON.
+fn to_value<T: Serialize>(response: TNRAppSuccessResponse<T>) -> Result<Value, TNRAppError> {
+    serde_json::to_value(response).map_err(|e| TNRAppError::from(format!("Cannot serialize the response: {e}")))
+}
+
+/// The parameters of a batch item, read like the path and the query parameters of its route.
+struct BatchParams<'a>(&'a BTreeMap<String, Value>);
+
+impl BatchParams<'_> {
+    /// Returns a path parameter of the route.
+    fn path<T: FromStr>(&self, name: &str) -> Result<T, TNRAppError> {
+        let value = self
+            .0
+            .get(name)
+            .map(param_to_string)
+            .ok_or_else(|| TNRAppError::new(TNRAppErrorType::BadRequestError, format!("Missing `{name}` parameter.")))?;
+
+        value
+            .parse()
+            .map_err(|_| TNRAppError::new(TNRAppErrorType::BadRequestError, format!("Invalid `{name}` parameter, '{value}'.")))
+    }
+
+    /// Returns the query parameters of the route. The path parameters are ignored.
+    fn query<T: DeserializeOwned>(&self) -> Result<T, TNRAppError> {
+        serde_querystring::from_str(&self.query_string(), ParseMode::UrlEncoded)
+            .map_err(|e| TNRAppError::new(TNRAppErrorType::BadRequestError, format!("Invalid parameters: {e}")))
+    }
+
+    /// Returns the parameters as a query string, sorted by name.
+    fn query_string(&self) -> String {
+        self.0
+            .iter()
+            .map(|(name, value)| format!("{}={}", encode_query_component(name), encode_query_component(&param_to_string(value))))
+            .collect::<Vec<String>>()
+            .join("&")
+    }
+}
+
+/// Returns a parameter as it is written in a URL, the strings are not quoted.
+fn param_to_string(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Percent-encodes a component of a query string, except the unreserved characters.
+fn encode_query_component(value: &str) -> String {
+    value.bytes().fold(String::new(), |mut encoded, byte| {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+        encoded
+    })
+}
+
+/// A request in a batch.
+#[derive(Deserialize, ToSchema)]
+pub struct BatchItem {
+    /// Chain name. Eg: `"axelar"`
+    pub chain: String,
+    /// The route after the chain name, without its path parameters. Eg: `"block-by-height"`
+    pub route: String,
+    /// The path and the query parameters of the route. Eg: `{ "height": 100 }`
+    #[serde(default)]
+    #[schema(value_type = Object)]
+    pub params: BTreeMap<String, Value>,
+}
+
+/// The result of a request in a batch.
+#[derive(Serialize, ToSchema)]
+pub struct BatchItemResult {
+    /// Chain name. Eg: `"axelar"`
+    pub chain: String,
+    /// Eg: `"block-by-height"`
+    pub route: String,
+    /// The HTTP status the route responds with.
+    pub status: u16,
+    /// The response of the route, if it succeeded.
+    #[schema(value_type = Object)]
+    pub response: Option<Value>,
+    /// The error of the route, if it failed.
+    pub error: Option<TNRAppErrorResponse>,
+}
+
+impl BatchItemResult {
+    fn new(item: BatchItem, result: Result<Value, TNRAppError>) -> Self {
+        match result {
+            Ok(response) => Self {
+                chain: item.chain,
+                route: item.route,
+                status: 200,
+                response: Some(response),
+                error: None,
+            },
+            Err(e) => Self {
+                chain: item.chain,
+                route: item.route,
+                status: e.status_code().as_u16(),
+                response: None,
+                error: Some(TNRAppErrorResponse {
+                    code: e.error_type.code().to_string(),
+                    error: e.message(),
+                }),
+            },
+        }
+    }
+}
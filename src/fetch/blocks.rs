@@ -1,17 +1,23 @@
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 use chrono::DateTime;
 use mongodb::bson::doc;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
+use tokio::sync::OnceCell;
+use utoipa::ToSchema;
 
-use crate::database::{BlockForDb, ValidatorForDb};
+use crate::database::{BlockEventForDb, BlockEventPhaseForDb, BlockForDb, ValidatorForDb};
 use crate::utils::{convert_tx_to_hex, Base64Convert};
-use crate::{chain::Chain, routes::OutRestResponse};
+use crate::{
+    chain::Chain,
+    routes::{OutRestResponse, TNRAppError, TNRAppErrorType},
+};
 
 impl Chain {
     /// Returns the block at given height. Returns the latest block, if no height is given.
-    pub async fn get_block_by_height(&self, height: Option<u64>) -> Result<OutRestResponse<InternalBlock>, String> {
+    pub async fn get_block_by_height(&self, height: Option<u64>) -> Result<OutRestResponse<InternalBlock>, TNRAppError> {
         let mut query = vec![];
 
         let height = height.map(|height| height.to_string());
@@ -32,7 +38,7 @@ impl Chain {
     /// ```rs
     /// let block = chain.get_block_by_hash("14b6bb26cf30a559ae3ad18b0e3640bc3fd819b1182830d359969e02bab0f633").await;
     /// ```
-    pub async fn get_block_by_hash(&self, hash: &str) -> Result<OutRestResponse<InternalBlock>, String> {
+    pub async fn get_block_by_hash(&self, hash: &str) -> Result<OutRestResponse<InternalBlock>, TNRAppError> {
         let mut query = vec![];
 
         let hash = if hash.starts_with("0x") {
@@ -50,15 +56,15 @@ impl Chain {
         Ok(OutRestResponse::new(block, 0))
     }
 
-    pub async fn get_latest_block(&self) -> Result<Block, String> {
-        let latest_height = self.get_blockchain(None, None).await.unwrap().last_height;
+    pub async fn get_latest_block(&self) -> Result<Block, TNRAppError> {
+        let latest_height = self.get_blockchain(None, None).await?.last_height;
         let mut query = vec![];
         query.push(("height", latest_height.to_string()));
         let latest_block = self.rpc_request::<BlockResp>("/block", &query).await?.block;
         Ok(latest_block)
     }
 
-    pub async fn get_block_result_by_height(&self, height: Option<u64>) -> Result<OutRestResponse<InternalBlockResult>, String> {
+    pub async fn get_block_result_by_height(&self, height: Option<u64>) -> Result<OutRestResponse<InternalBlockResult>, TNRAppError> {
         let mut query = vec![];
 
         let height = height.map(|height| height.to_string());
@@ -74,8 +80,165 @@ impl Chain {
         Ok(OutRestResponse::new(block, 0))
     }
 
+    /// Returns the block at given height in the format saved to the database.
+    /// Signatures of a block are committed in the next block, so the block at `height + 1` is also requested.
+    pub async fn get_block_for_db_by_height(&self, height: u64) -> Result<BlockForDb, TNRAppError> {
+        let block_resp = self.rpc_request::<BlockResp>("/block", &[("height", height.to_string())]).await?;
+        let next_block_resp = self.rpc_request::<BlockResp>("/block", &[("height", (height + 1).to_string())]).await?;
+
+        let header = block_resp.block.header;
+
+        // Old blocks might be proposed by validators that are not in the database anymore.
+        // Their proposer is left empty, as the operator address can't be derived from the consensus address the block has.
+        let (proposer_name, proposer_logo_url, proposer_address) = match self.database.find_validator_by_hex_addr(&header.proposer_address).await {
+            Ok(proposer) => (proposer.name, proposer.logo_url, proposer.operator_address),
+            Err(_) => (String::new(), String::new(), String::new()),
+        };
+
+        // RPC block ID flags start from 1, while the ones saved by the socket start from 0.
+        let signatures = next_block_resp
+            .block
+            .last_commit
+            .signatures
+            .into_iter()
+            .map(|signature| BlockLastCommitSignatures {
+                block_id_flag: signature.block_id_flag.saturating_sub(1),
+                ..signature
+            })
+            .collect();
+
+        Ok(BlockForDb {
+            proposer_address,
+            proposer_name,
+            proposer_logo_url,
+            height,
+            hash: block_resp.block_id.hash,
+            tx_count: block_resp.block.data.txs.len() as u64,
+            timestamp: DateTime::parse_from_rfc3339(&header.time)
+                .map_err(|_| format!("Cannot parse block datetime, '{}'.", header.time))?
+                .timestamp_millis(),
+            signatures,
+            validators_hash: header.validators_hash,
+        })
+    }
+
+    /// Saves the begin and end block events of the block at given height to the database, and returns them.
+    pub async fn store_block_events_by_height(&self, height: u64) -> Result<Vec<BlockEventForDb>, TNRAppError> {
+        let block_result = self.get_block_result_by_height(Some(height)).await?.value;
+
+        let events = block_result.to_block_events(height);
+
+        self.database.upsert_block_events(height, events.clone()).await?;
+
+        Ok(events)
+    }
+
+    /// Returns the begin and end block events of the block at given height, optionally only the ones with given types.
+    /// The events are requested from the node and saved, if they are not in the database.
+    pub async fn get_block_events(&self, height: u64, event_types: Option<Vec<String>>) -> Result<Vec<BlockEventForDb>, TNRAppError> {
+        let mut events = match self.database.find_block_events(height).await? {
+            Some(events) => events,
+            None => self.store_block_events_by_height(height).await?,
+        };
+
+        if let Some(event_types) = event_types {
+            events.retain(|event| event_types.contains(&event.event_type));
+        }
+
+        Ok(events)
+    }
+
+    /// Returns the time and the execution result of the transaction with given hash, using the block at given height.
+    pub async fn get_block_tx_result(&self, height: u64, hash: &str) -> Result<InternalBlockTxResult, TNRAppError> {
+        self.get_block_tx_results(height).await?.get(hash).cloned().ok_or_else(|| {
+            TNRAppError::new(
+                TNRAppErrorType::NotFoundError,
+                format!("Transaction result of {hash} is not found at height {height}."),
+            )
+        })
+    }
+
+    /// Returns the time and the execution results of the transactions of the block at given height, by their hashes.\
+    /// The results of the last requested block are kept, so the transactions of a block fetch it only once.
+    async fn get_block_tx_results(&self, height: u64) -> Result<Arc<HashMap<String, InternalBlockTxResult>>, TNRAppError> {
+        // The lock is only held to get the cell of the block, the transactions of the same block wait for the first request on the cell.
+        let results = {
+            let mut last_block = self
+                .block_tx_results
+                .last_block
+                .lock()
+                .map_err(|_| "The block results cache is poisoned.".to_string())?;
+
+            match last_block.as_ref() {
+                Some((last_height, results)) if *last_height == height => results.clone(),
+                _ => {
+                    let results = Arc::new(OnceCell::new());
+                    *last_block = Some((height, results.clone()));
+                    results
+                }
+            }
+        };
+
+        results.get_or_try_init(|| self.fetch_block_tx_results(height)).await.cloned()
+    }
+
+    /// Returns the time and the execution results of the transactions of the block at given height from the RPC node, by their hashes.
+    async fn fetch_block_tx_results(&self, height: u64) -> Result<Arc<HashMap<String, InternalBlockTxResult>>, TNRAppError> {
+        let block_resp = self.rpc_request::<BlockResp>("/block", &[("height", height.to_string())]).await?;
+
+        let time = DateTime::parse_from_rfc3339(&block_resp.block.header.time)
+            .map_err(|_| format!("Cannot parse block datetime, '{}'.", block_resp.block.header.time))?
+            .timestamp_millis();
+
+        let block_result = self.get_block_result_by_height(Some(height)).await?.value;
+
+        let results = Arc::new(
+            block_resp
+                .block
+                .data
+                .txs
+                .iter()
+                .zip(block_result.txs_results)
+                .filter_map(|(tx_base64, tx_result)| {
+                    let hash = convert_tx_to_hex(tx_base64)?;
+
+                    Some((
+                        hash,
+                        InternalBlockTxResult {
+                            time,
+                            code: tx_result.code as u32,
+                            codespace: tx_result.codespace,
+                            log: tx_result.log,
+                        },
+                    ))
+                })
+                .collect::<HashMap<_, _>>(),
+        );
+
+        Ok(results)
+    }
+
+    /// Returns the sync info of the RPC node, including the earliest and the latest block heights it has.
+    pub async fn get_node_sync_info(&self) -> Result<InternalNodeSyncInfo, TNRAppError> {
+        let resp = self.rpc_request::<StatusResp>("/status", &[]).await?;
+
+        Ok(InternalNodeSyncInfo {
+            earliest_block_height: resp
+                .sync_info
+                .earliest_block_height
+                .parse()
+                .map_err(|_| format!("Cannot parse earliest block height, '{}'.", resp.sync_info.earliest_block_height))?,
+            latest_block_height: resp
+                .sync_info
+                .latest_block_height
+                .parse()
+                .map_err(|_| format!("Cannot parse latest block height, '{}'.", resp.sync_info.latest_block_height))?,
+            catching_up: resp.sync_info.catching_up,
+        })
+    }
+
     /// Returns the block headers between `min_height` & `max_height`.
-    async fn get_blockchain(&self, min_height: Option<u64>, max_height: Option<u64>) -> Result<InternalBlockchainResp, String> {
+    async fn get_blockchain(&self, min_height: Option<u64>, max_height: Option<u64>) -> Result<InternalBlockchainResp, TNRAppError> {
         let mut query = vec![];
 
         if let Some(min_height) = min_height {
@@ -90,7 +253,7 @@ impl Chain {
             Ok(resp) => {
                 let last_height = match resp.last_height.parse() {
                     Ok(last_height) => last_height,
-                    Err(_) => return Err("Blockchain parsing error.".to_string()),
+                    Err(_) => return Err("Blockchain parsing error.".into()),
                 };
 
                 let mut block_metas = vec![];
@@ -98,22 +261,22 @@ impl Chain {
                 for block_meta in resp.block_metas {
                     let block_size = match block_meta.block_size.parse() {
                         Ok(block_size) => block_size,
-                        Err(_) => return Err("Blockchain parsing error.".to_string()),
+                        Err(_) => return Err("Blockchain parsing error.".into()),
                     };
 
                     let num_txs = match block_meta.num_txs.parse() {
                         Ok(num_txs) => num_txs,
-                        Err(_) => return Err("Block tx count parsing error.".to_string()),
+                        Err(_) => return Err("Block tx count parsing error.".into()),
                     };
 
                     let height = match block_meta.header.height.parse() {
                         Ok(height) => height,
-                        Err(_) => return Err("Block height parsing error.".to_string()),
+                        Err(_) => return Err("Block height parsing error.".into()),
                     };
 
                     let time = match chrono::DateTime::parse_from_rfc3339(&block_meta.header.time) {
                         Ok(time) => time.timestamp_millis() as u32,
-                        Err(_) => return Err("Block time parsing error.".to_string()),
+                        Err(_) => return Err("Block time parsing error.".into()),
                     };
 
                     block_metas.push(InternalBlockMeta {
@@ -141,22 +304,22 @@ impl Chain {
 
                 Ok(InternalBlockchainResp { last_height, block_metas })
             }
-            Err(error) => Err(error),
+            Err(error) => Err(error.into()),
         }
     }
 
     /// Returns the last 20 block headers.
-    pub async fn get_block_headers_last_20(&self) -> Result<InternalBlockchainResp, String> {
+    pub async fn get_block_headers_last_20(&self) -> Result<InternalBlockchainResp, TNRAppError> {
         self.get_blockchain(None, None).await
     }
 
     /// Returns the block headers between max and min height.
-    pub async fn get_block_headers(&self, min_height: u64, max_height: u64) -> Result<InternalBlockchainResp, String> {
+    pub async fn get_block_headers(&self, min_height: u64, max_height: u64) -> Result<InternalBlockchainResp, TNRAppError> {
         self.get_blockchain(Some(min_height), Some(max_height)).await
     }
 
     /// Returns the counted blocks.
-    pub async fn get_last_blocks_from_db(&self, count: u16) -> Result<Vec<BlockForDb>, String> {
+    pub async fn get_last_blocks_from_db(&self, count: u16) -> Result<Vec<BlockForDb>, TNRAppError> {
         let blocks = self.database.find_last_count_blocks(None, count).await?;
 
         Ok(blocks)
@@ -167,7 +330,7 @@ impl Chain {
         &self,
         operator_address: String,
         last_block_count: Option<u16>,
-    ) -> Result<Vec<ValidatorSignatureListElement>, String> {
+    ) -> Result<Vec<ValidatorSignatureListElement>, TNRAppError> {
         let default_last_block_count = 100;
         let last_block_count = last_block_count.unwrap_or(default_last_block_count);
 
@@ -205,18 +368,18 @@ impl Chain {
     ///
     /// Returns average block time as milliseconds.
     ///
-    pub async fn get_avg_block_time(&self) -> Result<f64, String> {
+    pub async fn get_avg_block_time(&self) -> Result<f64, TNRAppError> {
         let block_time_period: u64 = 10000;
         let latest_block = self.get_latest_block().await?;
         let latest_block_height = match latest_block.header.height.parse::<u64>() {
             Ok(res) => res,
-            Err(e) => return Err(format!("Avg block time scope Parsing Error: {e}")),
+            Err(e) => return Err(format!("Avg block time scope Parsing Error: {e}").into()),
         };
         let lower_block_height = latest_block_height - block_time_period;
         let lower_block = self.get_block_by_height(Some(lower_block_height)).await?.value;
         let latest_block_date_time = match DateTime::parse_from_rfc3339(&latest_block.header.time) {
             Ok(res) => res,
-            Err(e) => return Err(format!("Latest block time parsing error: {e}")),
+            Err(e) => return Err(format!("Latest block time parsing error: {e}").into()),
         };
         let avg_block_time = ((latest_block_date_time.timestamp_millis() - lower_block.time) as f64) / block_time_period as f64;
 
@@ -235,7 +398,7 @@ pub struct BlockItem {
     pub timestamp: i64,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, ToSchema)]
 pub struct InternalBlock {
     pub height: u64,
     pub hash: String,
@@ -249,7 +412,7 @@ pub struct InternalBlock {
 }
 
 impl InternalBlock {
-    async fn new(block_resp: BlockResp, chain: &Chain) -> Result<Self, String> {
+    async fn new(block_resp: BlockResp, chain: &Chain) -> Result<Self, TNRAppError> {
         let mut proposer = None;
 
         let mut signatures = vec![];
@@ -301,7 +464,7 @@ impl InternalBlock {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct InternalBlockSignature {
     /// Validator name. `heisenbug`
     pub name: String,
@@ -321,7 +484,7 @@ impl From<ValidatorForDb> for InternalBlockSignature {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct InternalBlockchainResp {
     /// Last block height. `12733014`
     pub last_height: u64,
@@ -329,7 +492,7 @@ pub struct InternalBlockchainResp {
     pub block_metas: Vec<InternalBlockMeta>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct InternalBlockMeta {
     /// Block ID.
     pub block_id: BlockId,
@@ -361,6 +524,53 @@ pub struct BlockMeta {
     pub num_txs: String,
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct InternalBlockTxResult {
+    /// The time of the block the transaction is in, as milliseconds. Eg: `1667497514115`
+    pub time: i64,
+    /// ABCI result code. Eg: `0`
+    pub code: u32,
+    /// ABCI result codespace. Eg: `"sdk"`
+    pub codespace: String,
+    /// Raw log. Eg: `"out of gas in location: ReadFlat; gasWanted: 80000, gasUsed: 80427: out of gas"`
+    pub log: String,
+}
+
+/// The transaction results of the last block requested by [`Chain::get_block_tx_result`].\
+/// The cell of the block is set by the first request, it is left empty if the request fails.
+#[derive(Clone, Default)]
+pub struct BlockTxResultsCache {
+    last_block: Arc<Mutex<Option<(u64, BlockTxResultsCell)>>>,
+}
+
+/// The transaction results of a block, by their hashes.
+type BlockTxResultsCell = Arc<OnceCell<Arc<HashMap<String, InternalBlockTxResult>>>>;
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct InternalNodeSyncInfo {
+    /// The earliest block height the node has. Eg: `4000000`
+    pub earliest_block_height: u64,
+    /// The latest block height the node has. Eg: `12733014`
+    pub latest_block_height: u64,
+    /// Whether the node is still syncing. Eg: `false`
+    pub catching_up: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct StatusResp {
+    pub sync_info: StatusSyncInfo,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct StatusSyncInfo {
+    /// The latest block height. Eg: `"12733014"`
+    pub latest_block_height: String,
+    /// The earliest block height. Eg: `"4000000"`
+    pub earliest_block_height: String,
+    /// Whether the node is still syncing. Eg: `false`
+    pub catching_up: bool,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct BlockResp {
     pub block_id: BlockId,
@@ -385,6 +595,17 @@ impl InternalBlockResult {
             end_block_events: block_result.end_block_events.unwrap_or(vec![]),
         }
     }
+
+    /// Returns the begin and end block events in the format saved to the database.
+    pub fn to_block_events(&self, height: u64) -> Vec<BlockEventForDb> {
+        let mut events = BlockEventForDb::from_cosmos_events(height, BlockEventPhaseForDb::Begin, self.begin_block_events.clone());
+        events.extend(BlockEventForDb::from_cosmos_events(
+            height,
+            BlockEventPhaseForDb::End,
+            self.end_block_events.clone(),
+        ));
+        events
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -431,11 +652,13 @@ impl InternalBlockResultTxsResult {
 pub struct BlockResult {
     pub height: String,
     pub txs_results: Option<Vec<BlockResultTxResult>>,
+    #[serde(default, deserialize_with = "from_base64_events_opt")]
     pub begin_block_events: Option<Vec<CosmosEvent>>,
+    #[serde(default, deserialize_with = "from_base64_events_opt")]
     pub end_block_events: Option<Vec<CosmosEvent>>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct BlockId {
     /// HEX encoded hash.
     pub hash: String,
@@ -451,6 +674,7 @@ pub struct Block {
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct ResultEndBlock {
+    #[serde(deserialize_with = "from_base64_events")]
     pub events: Vec<CosmosEvent>,
     pub consensus_param_updates: HashMap<String, Value>,
     // pub validator_updates: Vec<String>,
@@ -458,24 +682,78 @@ pub struct ResultEndBlock {
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct ResultBeginBlock {
+    #[serde(deserialize_with = "from_base64_events")]
     pub events: Vec<CosmosEvent>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, ToSchema)]
 pub struct CosmosEvent {
     pub attributes: Vec<CosmosEventAttribute>,
     pub r#type: String,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+/// An attribute of an event, decoded. The attributes are base64 encoded by the node, see `from_base64_events`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, ToSchema)]
 pub struct CosmosEventAttribute {
-    #[serde(deserialize_with = "from_base64")]
     pub key: String,
-    #[serde(deserialize_with = "from_base64")]
     pub value: String,
     pub index: bool,
 }
 
+/// An event as it is returned by the node, with base64 encoded attributes.
+#[derive(Deserialize)]
+struct Base64CosmosEvent {
+    attributes: Vec<Base64CosmosEventAttribute>,
+    r#type: String,
+}
+
+#[derive(Deserialize)]
+struct Base64CosmosEventAttribute {
+    #[serde(deserialize_with = "from_base64")]
+    key: String,
+    #[serde(deserialize_with = "from_base64")]
+    value: String,
+    index: bool,
+}
+
+impl From<Base64CosmosEvent> for CosmosEvent {
+    fn from(event: Base64CosmosEvent) -> Self {
+        Self {
+            r#type: event.r#type,
+            attributes: event
+                .attributes
+                .into_iter()
+                .map(|attribute| CosmosEventAttribute {
+                    key: attribute.key,
+                    value: attribute.value,
+                    index: attribute.index,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Deserializes the events returned by the node, and decodes their attributes.\
+/// It is used only on the responses of the node, the events saved to the database are decoded already.
+pub fn from_base64_events<'de, D>(deserializer: D) -> Result<Vec<CosmosEvent>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let events = Vec::<Base64CosmosEvent>::deserialize(deserializer)?;
+
+    Ok(events.into_iter().map(CosmosEvent::from).collect())
+}
+
+/// Deserializes the optional events returned by the node, like `from_base64_events`.
+pub fn from_base64_events_opt<'de, D>(deserializer: D) -> Result<Option<Vec<CosmosEvent>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let events = Option::<Vec<Base64CosmosEvent>>::deserialize(deserializer)?;
+
+    Ok(events.map(|events| events.into_iter().map(CosmosEvent::from).collect()))
+}
+
 pub fn from_base64<'de, D>(deserializer: D) -> Result<String, D::Error>
 where
     D: Deserializer<'de>,
@@ -486,7 +764,7 @@ where
     Ok(String::base64_to_string(&String::from(string_value)))
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct BlockIdParts {
     /// Unknown. Eg: `1`
     pub total: u32,
@@ -494,7 +772,7 @@ pub struct BlockIdParts {
     pub hash: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct InternalBlockHeader {
     /// Block header version.
     pub version: BlockHeaderVersion,
@@ -564,13 +842,13 @@ pub struct BlockData {
     pub txs: Vec<String>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct BlockHeaderVersion {
     /// Unknown. Eg: `"11"`
     pub block: String,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, ToSchema)]
 pub struct BlockLastCommitSignatures {
     /// Unknown. Eg: `2`
     pub block_id_flag: usize,
@@ -602,11 +880,12 @@ pub struct BlockResultTxResult {
     pub info: String,
     pub gas_wanted: String,
     pub gas_used: String,
+    #[serde(deserialize_with = "from_base64_events")]
     pub events: Vec<CosmosEvent>,
     pub codespace: String,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct ValidatorSignatureListElement {
     pub operator_address: String,
     pub missed: bool,
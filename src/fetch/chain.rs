@@ -2,6 +2,7 @@ use mongodb::bson::doc;
 use serde::{Deserialize, Serialize};
 use serde_json::Number;
 use tokio::join;
+use utoipa::ToSchema;
 
 use crate::{
     chain::Chain,
@@ -47,12 +48,12 @@ impl Chain {
         })
     }
 
-    pub async fn get_chain_market_chart_history(&self) -> Result<TokenMarketHistory, String> {
+    pub async fn get_chain_market_chart_history(&self) -> Result<TokenMarketHistory, TNRAppError> {
         let result = match self.database.find_market_history(self.config.name.clone()).await {
             Ok(res) => res,
             Err(e) => {
                 tracing::error!("Error while fetching market history: {}", e);
-                return Err("Error while fetching market history".to_string());
+                return Err(e);
             }
         };
 
@@ -96,7 +97,7 @@ impl Chain {
         token_id: String,
         parity: Option<String>,
         day_period: Option<String>,
-    ) -> Result<TokenMarketHistory, String> {
+    ) -> Result<TokenMarketHistory, TNRAppError> {
         let parity = parity.unwrap_or("usd".to_string());
         let day_period = day_period.unwrap_or("1".to_string());
         let query = vec![("vs_currency", parity.clone()), ("days", day_period.clone())];
@@ -106,9 +107,25 @@ impl Chain {
 
         Ok(TokenMarketHistory::new().gecko_response_from(result, parity, token_id, day_period))
     }
+
+    /// Returns the daily USD prices of a token for the last given days, `"max"` for its whole history.\
+    /// The prices are at the starts of the days, except the last one which is the latest price.
+    pub async fn gecko_token_daily_prices(&self, token_id: String, days: String) -> Result<Vec<InternalMarketChart>, TNRAppError> {
+        let parity = String::from("usd");
+        let query = vec![
+            ("vs_currency", parity.clone()),
+            ("days", days.clone()),
+            ("interval", String::from("daily")),
+        ];
+
+        let url = format!("/coins/{token_id}/market_chart");
+        let result = self.coingecko_rest_client::<GeckoTokenMarketChartResponse>(url, &query).await?;
+
+        Ok(TokenMarketHistory::new().gecko_response_from(result, parity, token_id, days).prices)
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct ChainDashboardInfoResponse {
     pub price: f64,
     pub market_cap: f64,
@@ -121,7 +138,7 @@ pub struct ChainDashboardInfoResponse {
     pub market_history: Option<TokenMarketHistory>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct ChainStatsInfoResponse {
     pub latest_block_height: u64,
     pub average_block_time_ms: f64,
@@ -146,7 +163,7 @@ pub struct GeckoTokenMarketChartResponse {
 
 pub type GeckoMarketChartValue = Vec<Vec<Number>>;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct InternalMarketChart {
     pub timestamp: u64,
     pub value: f64,
@@ -158,7 +175,7 @@ impl Default for InternalMarketChart {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct TokenMarketHistory {
     pub parity: String,
     pub token_id: String,
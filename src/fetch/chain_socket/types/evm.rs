@@ -1,9 +1,9 @@
-use futures::future::join_all;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::{
     chain::Chain,
-    database::{DatabaseTR, EvmPollForDb, EvmPollParticipantForDb},
+    database::{EvmPollForDb, EvmPollParticipantForDb},
     fetch::{blocks::CosmosEvent, evm::PollStatus, transactions::InternalTransaction},
     routes::TNRAppError,
 };
@@ -200,7 +200,7 @@ impl PollVoteEvent {
                             Ok(res) => res.value,
                             Err(e) => {
                                 tracing::error!("tx could not fetched  {}", e);
-                                return Err(TNRAppError::from(e));
+                                return Err(e);
                             }
                         }
                     }
@@ -280,23 +280,16 @@ impl From<EvmPollItem> for EvmPollForDb {
 }
 
 impl EvmPollItem {
-    pub async fn upsert_participants(&self, db: &DatabaseTR) -> Result<(), String> {
-        let participants: Vec<EvmPollParticipantForDb> = self
-            .participants_operator_address
+    /// Returns the participants of the poll, before any of them votes.
+    pub fn initial_participants(&self) -> Vec<EvmPollParticipantForDb> {
+        self.participants_operator_address
             .iter()
             .map(|address| EvmPollParticipantForDb::from_info(address.clone(), self.poll_id.clone(), self.chain_name.clone()))
-            .collect();
-
-        let mut db_jobs = vec![];
-        for participant in participants {
-            db_jobs.push(async move { db.upsert_evm_poll_participant(participant).await });
-        }
-        join_all(db_jobs).await;
-        Ok(())
+            .collect()
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ToSchema)]
 pub enum EvmPollVote {
     UnSubmit,
     Yes,
@@ -3,7 +3,10 @@ use std::{collections::BTreeMap, num::ParseIntError};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
-use crate::{chain::Chain, fetch::transactions::TransactionItem};
+use crate::{
+    chain::Chain,
+    fetch::transactions::{TransactionItem, TxsTransactionMessage},
+};
 
 use super::{
     ConfirmDepositStarted, ConfirmGatewayTxStartedEvents, ConfirmKeyTransferStartedEvents, NewPollEvent, NewProposalVoteEvent, PollVoteEvent,
@@ -55,6 +58,8 @@ pub struct BaseTransaction {
     /// `[ "/ethermint.evm.v1.MsgEthereumTx" ]`
     // #[serde(rename = "message.action")]
     pub message_action: String,
+    /// All the distinct message actions of the transaction. `[ "/cosmos.staking.v1beta1.MsgDelegate", "/cosmos.bank.v1beta1.MsgSend" ]`
+    pub message_actions: Vec<String>,
     /// `[ "1535902500000000aevmos" ]`
     // #[serde(rename = "transfer.amount")]
     pub transfer_amount: String,
@@ -75,11 +80,19 @@ impl BaseTransaction {
             .unwrap_or(&String::from("0.00"))
             .clone();
 
+        let mut message_actions: Vec<String> = vec![];
+        for action in ev.get("message.action")? {
+            if !message_actions.contains(action) {
+                message_actions.push(action.to_string());
+            }
+        }
+
         Some(Self {
             hash: ev.get("tx.hash")?.get(0)?.to_string(),
             fee: tx_fee_denom,
             height: ev.get("tx.height")?.get(0)?.to_string(),
             message_action: ev.get("message.action")?.get(0)?.to_string(),
+            message_actions,
             transfer_amount,
         })
     }
@@ -93,22 +106,34 @@ impl BaseTransaction {
             .string_amount_parser(tx_fee_denom.replace(chain.config.main_denom.as_str(), "").clone(), None)
             .await?;
 
+        let height = self
+            .height
+            .parse::<u64>()
+            .map_err(|e| format!("Cannot parse tx height {}: {e}", self.height))?;
+
+        // Tx events don't have the block time and the result code, they are taken from the block.
+        let tx_result = chain.get_block_tx_result(height, &self.hash).await.map_err(|e| e.to_string())?;
+
         Ok(TransactionItem {
             amount,
             fee,
             hash: self.hash.clone(),
-            height: self
-                .height
-                .parse::<u64>()
-                .map_err(|e| format!("Cannot parse tx height {}: {e}", self.height))?,
-            time: std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis() as i64,
-            result: "Success".to_string(),
-            tx_type: self
-                .message_action
-                .split_once("Msg")
-                .map(|(_, r)| r)
-                .unwrap_or(self.message_action.split('.').last().unwrap_or("Unknown"))
-                .to_string(),
+            height,
+            time: tx_result.time,
+            result: if tx_result.code == 0 {
+                "Success".to_string()
+            } else {
+                "Failed".to_string()
+            },
+            raw_log: if tx_result.code == 0 { None } else { Some(tx_result.log) },
+            code: tx_result.code,
+            codespace: tx_result.codespace,
+            tx_type: TxsTransactionMessage::get_type_by_type_url(&self.message_action),
+            message_types: self
+                .message_actions
+                .iter()
+                .map(|action| TxsTransactionMessage::get_type_by_type_url(action))
+                .collect(),
         })
     }
 }
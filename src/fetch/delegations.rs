@@ -2,32 +2,30 @@ use chrono::{DateTime, NaiveDateTime};
 use mongodb::bson::doc;
 use serde::{Deserialize, Serialize};
 use tokio::join;
-use tonic::transport::Endpoint;
+use utoipa::ToSchema;
 
 use crate::chain::Chain;
 use crate::database::ListDbResult;
-use crate::routes::{ChainAmountItem, PaginationData};
+use crate::routes::{ChainAmountItem, PaginationData, TNRAppError};
 
 use super::others::{DenomAmount, Pagination};
 
 impl Chain {
     /// Returns the delegations of given address.
-    pub async fn get_delegations(&self, delegator_addr: &str, config: PaginationData) -> Result<ListDbResult<InternalDelegation>, String> {
+    pub async fn get_delegations(&self, delegator_addr: &str, config: PaginationData) -> Result<ListDbResult<InternalDelegation>, TNRAppError> {
         use crate::fetch::cosmos::staking::v1beta1::{query_client::QueryClient, QueryDelegatorDelegationsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryDelegatorDelegationsRequest {
             delegator_addr: delegator_addr.to_string(),
             pagination: Some(config.into()),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .delegator_delegations(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let mut delegations = vec![];
@@ -59,10 +57,10 @@ impl Chain {
     }
 
     /// Returns the redelegations of given address.
-    pub async fn get_redelegations(&self, delegator_addr: &str, config: PaginationData) -> Result<ListDbResult<InternalRedelegation>, String> {
+    pub async fn get_redelegations(&self, delegator_addr: &str, config: PaginationData) -> Result<ListDbResult<InternalRedelegation>, TNRAppError> {
         use crate::fetch::cosmos::staking::v1beta1::{query_client::QueryClient, QueryRedelegationsRequest};
 
-        let endoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryRedelegationsRequest {
             delegator_addr: delegator_addr.to_string(),
@@ -71,12 +69,10 @@ impl Chain {
             pagination: Some(config.into()),
         };
 
-        let resp = QueryClient::connect(endoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .redelegations(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let mut redelegations = vec![];
@@ -124,22 +120,24 @@ impl Chain {
     }
 
     /// Returns the unbonding delegations of given address.
-    pub async fn get_delegations_unbonding(&self, delegator_addr: &str, config: PaginationData) -> Result<ListDbResult<InternalUnbonding>, String> {
+    pub async fn get_delegations_unbonding(
+        &self,
+        delegator_addr: &str,
+        config: PaginationData,
+    ) -> Result<ListDbResult<InternalUnbonding>, TNRAppError> {
         use crate::fetch::cosmos::staking::v1beta1::{query_client::QueryClient, QueryDelegatorUnbondingDelegationsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryDelegatorUnbondingDelegationsRequest {
             delegator_addr: delegator_addr.to_string(),
             pagination: Some(config.into()),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .delegator_unbonding_delegations(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let mut unbondings = vec![];
@@ -200,7 +198,7 @@ pub struct DelegationResponse {
     pub balance: DenomAmount,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct InternalDelegation {
     pub validator_logo_url: String,
     pub validator_name: String,
@@ -208,7 +206,7 @@ pub struct InternalDelegation {
     pub amount: ChainAmountItem,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct InternalRedelegation {
     pub validator_from_logo_url: String,
     pub validator_from_name: String,
@@ -220,7 +218,7 @@ pub struct InternalRedelegation {
     pub completion_time: i64,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct InternalUnbonding {
     pub validator_logo_url: String,
     pub validator_name: String,
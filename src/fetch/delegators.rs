@@ -1,27 +1,25 @@
 use super::others::DenomAmount;
 use crate::chain::Chain;
 use crate::fetch::cosmos::distribution::v1beta1::{DelegationDelegatorReward, QueryDelegationTotalRewardsResponse};
-use crate::routes::ChainAmountItem;
+use crate::routes::{ChainAmountItem, TNRAppError};
 use serde::{Deserialize, Serialize};
-use tonic::transport::Endpoint;
+use utoipa::ToSchema;
 
 impl Chain {
     /// Returns the withdraw address by given delegator address.
-    pub async fn get_delegator_withdraw_address(&self, delegator_addr: &str) -> Result<String, String> {
+    pub async fn get_delegator_withdraw_address(&self, delegator_addr: &str) -> Result<String, TNRAppError> {
         use crate::fetch::cosmos::distribution::v1beta1::{query_client::QueryClient, QueryDelegatorWithdrawAddressRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryDelegatorWithdrawAddressRequest {
             delegator_address: delegator_addr.to_string(),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .delegator_withdraw_address(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let withdraw_address = resp.withdraw_address;
@@ -30,21 +28,19 @@ impl Chain {
     }
 
     /// Returns the rewards of given delegator address.
-    pub async fn get_delegator_rewards(&self, delegator_addr: &str) -> Result<InternalDelegatorRewards, String> {
+    pub async fn get_delegator_rewards(&self, delegator_addr: &str) -> Result<InternalDelegatorRewards, TNRAppError> {
         use crate::fetch::cosmos::distribution::v1beta1::{query_client::QueryClient, QueryDelegationTotalRewardsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryDelegationTotalRewardsRequest {
             delegator_address: delegator_addr.to_string(),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .delegation_total_rewards(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let delegator_rewards = InternalDelegatorRewards::new(resp, &self).await?;
@@ -61,7 +57,7 @@ pub struct DelegatorRewardsResp {
     pub total: Vec<DenomAmount>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct InternalDelegatorRewards {
     /// Array of rewards.
     pub rewards: Vec<InternalDelegatorReward>,
@@ -102,7 +98,7 @@ pub struct DelegatorReward {
     pub reward: Vec<DenomAmount>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct InternalDelegatorReward {
     pub validator_address: String,
     pub reward: ChainAmountItem,
@@ -3,6 +3,7 @@ use std::fmt;
 use futures::future::join_all;
 use mongodb::bson::{doc, Document};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::chain::Chain;
 use crate::database::{EvmPollForDb, EvmPollParticipantForDb, ListDbResult, ValidatorForDb};
@@ -47,7 +48,7 @@ impl Chain {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct EvmPollRespElement {
     pub timestamp: u64,
     pub height: u64,
@@ -62,7 +63,7 @@ pub struct EvmPollRespElement {
 }
 
 impl EvmPollRespElement {
-    pub async fn new(chain: &Chain, value: EvmPollForDb) -> Result<Self, String> {
+    pub async fn new(chain: &Chain, value: EvmPollForDb) -> Result<Self, TNRAppError> {
         let mut vote_count_info = EvmPollVoteCountInfoElement::default();
 
         let participants = chain
@@ -108,7 +109,7 @@ impl EvmPollRespElement {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct EvmPollParticipantRespElement {
     pub confirmation: bool,
     pub poll_id: String,
@@ -137,7 +138,7 @@ impl EvmPollParticipantRespElement {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct EvmPollOperatorInfo {
     pub operator_address: String,
     pub name: String,
@@ -189,7 +190,7 @@ impl fmt::Display for PollStatus {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Default)]
+#[derive(Deserialize, Serialize, Debug, Default, ToSchema)]
 pub struct EvmPollVoteCountInfoElement {
     pub yes: u32,
     pub no: u32,
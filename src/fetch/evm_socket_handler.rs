@@ -1,12 +1,11 @@
 use crate::{
     chain::Chain,
     database::{EvmPollForDb, EvmPollParticipantForDb, HeartbeatForDb, HeartbeatRawForDb, ProposalVoteForDb, ProposalVoteOptionForDb},
-    events::WsEvent,
+    ingest::IngestPipeline,
 };
 use chrono::Utc;
 use futures::future::join_all;
 use mongodb::bson::doc;
-use tokio::sync::broadcast::Sender;
 
 use super::{
     chain_socket::{EvmPollBlockInfo, NewPollEvent, NewProposalVoteEvent, PollVoteEvent, ProposalVoteOption},
@@ -18,27 +17,19 @@ use super::{
 
 pub struct EvmSocketHandler {
     pub chain: Chain,
-    pub ws_tx_sender: Sender<(String, WsEvent)>,
+    pub pipeline: IngestPipeline,
 }
 
 impl EvmSocketHandler {
-    pub fn new(chain: Chain, ws_tx_sender: Sender<(String, WsEvent)>) -> Self {
-        Self { chain, ws_tx_sender }
+    pub fn new(chain: Chain, pipeline: IngestPipeline) -> Self {
+        Self { chain, pipeline }
     }
     pub async fn new_evm_poll_from_block(&self, evm_poll_block_info: EvmPollBlockInfo) {
         if let Some(polls) = &evm_poll_block_info.extract_evm_poll_completed_events() {
             if !polls.is_empty() {
                 for completed_poll in polls.clone() {
-                    match self
-                        .chain
-                        .database
-                        .update_evm_poll_status(&completed_poll.poll_id, &completed_poll.poll_status)
-                        .await
-                    {
-                        Ok(_) => {}
-                        Err(e) => {
-                            tracing::error!("Could not update evm poll cause of {}", e);
-                        }
+                    if let Err(e) = self.pipeline.on_poll_status(&completed_poll.poll_id, &completed_poll.poll_status).await {
+                        tracing::error!("Could not update evm poll cause of {}", e);
                     };
                 }
             };
@@ -53,16 +44,10 @@ impl EvmSocketHandler {
             }
         };
 
-        let _ = evm_poll_item.upsert_participants(&self.chain.database).await;
+        let participants = evm_poll_item.initial_participants();
 
         let evm_poll: EvmPollForDb = evm_poll_item.clone().into();
-        if let Err(e) = self
-            .ws_tx_sender
-            .send((self.chain.config.name.clone(), WsEvent::NewEvmPoll(evm_poll.clone())))
-        {
-            tracing::error!("Error dispatching evm poll event: {e}");
-        }
-        match self.chain.database.upsert_evm_poll(evm_poll).await {
+        match self.pipeline.on_poll(&evm_poll, &participants).await {
             Ok(_) => {
                 tracing::info!("evm poll successfully created by poll id {}", &evm_poll_item.poll_id);
             }
@@ -106,7 +91,7 @@ impl EvmSocketHandler {
                             }
 
                             if let Some(poll_status) = poll_status {
-                                match self.chain.database.update_evm_poll_status(poll_id, &poll_status).await {
+                                match self.pipeline.on_poll_status(poll_id, &poll_status).await {
                                     Ok(_) => {
                                         tracing::info!("Successfully updated evm poll status completed for which poll id is {}", &poll_id);
                                     }
@@ -140,7 +125,7 @@ impl EvmSocketHandler {
                                         voter_address,
                                         confirmation: is_confirmation_tx,
                                     };
-                                    match self.chain.database.upsert_evm_poll_participant(evm_poll_participant.clone()).await {
+                                    match self.pipeline.on_poll_participant(&evm_poll_participant).await {
                                         Ok(_) => {
                                             tracing::info!(
                                                 "Successfully updated evm poll participant {} for which poll id is {}",
@@ -152,13 +137,6 @@ impl EvmSocketHandler {
                                             tracing::error!("Can not updated evm poll participant {}", e);
                                         }
                                     };
-
-                                    if let Err(e) = self.ws_tx_sender.send((
-                                        self.chain.config.name.clone(),
-                                        WsEvent::UpdateEvmPollParticipant((poll_id.clone(), evm_poll_participant)),
-                                    )) {
-                                        tracing::error!("Error dispatching Evm Poll Update event: {e}");
-                                    };
                                 }
                             }
                             AxelarVote::Unknown(_) => {
@@ -215,7 +193,7 @@ impl EvmSocketHandler {
                     };
                 }
 
-                match self.chain.database.add_heartbeat_many(initial_period_heartbeats).await {
+                match self.pipeline.on_period_heartbeats(&initial_period_heartbeats).await {
                     Ok(_) => {
                         tracing::info!("Current period initial heartbeats inserted");
                     }
@@ -255,7 +233,7 @@ impl EvmSocketHandler {
                                 sender,
                                 period_height,
                             };
-                            match self.chain.database.upsert_heartbeat(db_heartbeat).await {
+                            match self.pipeline.on_heartbeat(&db_heartbeat).await {
                                 Ok(_) => {
                                     tracing::info!("Successfully inserted heartbeat id {}", &generated_id)
                                 }
@@ -294,6 +272,6 @@ impl EvmSocketHandler {
             timestamp: Utc::now().timestamp_millis(),
         };
 
-        let _ = self.chain.database.add_propsal_vote(proposal_vote).await;
+        let _ = self.pipeline.on_proposal_vote(&proposal_vote).await;
     }
 }
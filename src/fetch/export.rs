@@ -0,0 +1,462 @@
+use std::collections::HashMap;
+
+use chrono::{TimeZone, Utc};
+use futures::stream::{self, Stream};
+use mongodb::bson::{doc, Document};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::chain::Chain;
+use crate::database::{AddressTransactionForDb, DailyTokenPriceForDb};
+use crate::routes::{ChainAmountItem, PaginationData, TNRAppError};
+use crate::utils::day_start_ms;
+
+use super::address_txs::is_node_cursor;
+use super::transactions::{InternalTransaction, InternalTransactionContent, InternalTransactionContentKnowns};
+
+/// The count of the address transactions read from the database at once while exporting.
+const EXPORT_PAGE_SIZE: u64 = 100;
+
+/// The message types of the delegation events.
+const DELEGATION_MESSAGE_TYPES: [&str; 3] = ["Delegate", "Redelegate", "Undelegate"];
+
+/// The message types of the reward withdrawals.
+const REWARD_MESSAGE_TYPES: [&str; 1] = ["Withdraw Delegator Rewards"];
+
+impl Chain {
+    /// Streams the export rows of given address, page by page, from the newest to the oldest.\
+    /// The rows are filtered by given kind and time range, and priced by the daily price history of the chain token.
+    pub fn export_address_history(&self, address: String, filter: ExportFilter) -> impl Stream<Item = Result<Vec<ExportRow>, String>> {
+        let chain = self.clone();
+
+        // `None` means the export is done, the first page has no cursor.
+        let initial = Some(ExportCursor { cursor: None, prices: None });
+
+        stream::unfold(initial, move |state| {
+            let chain = chain.clone();
+            let address = address.clone();
+            let filter = filter.clone();
+
+            async move {
+                let ExportCursor { cursor, prices } = state?;
+
+                // The prices of the days in the time range are read once, with the first page.
+                let prices = match prices {
+                    Some(prices) => prices,
+                    None => match chain
+                        .database
+                        .find_daily_token_prices(&chain.config.name, filter.from.map(day_start_ms), filter.to)
+                        .await
+                    {
+                        Ok(prices) => prices,
+                        Err(e) => return Some((Err(format!("Cannot read the price history: {e}")), None)),
+                    },
+                };
+
+                let config = PaginationData {
+                    cursor,
+                    limit: Some(EXPORT_PAGE_SIZE),
+                    ..Default::default()
+                };
+
+                let page = match chain.find_address_txs(&address, None, filter.to_query(&address), config).await {
+                    Ok(page) => page,
+                    Err(e) => return Some((Err(e.to_string()), None)),
+                };
+
+                // A partial page of the address index is the last one, unless it points to the older transactions on the node.
+                let is_last_page = (page.data.len() as u64) < EXPORT_PAGE_SIZE && !page.pagination.cursor.as_deref().map_or(false, is_node_cursor);
+
+                // The pages of the node are from the newest to the oldest too, so the export is done once it passes the start time.
+                let is_before_range = filter.from.zip(page.data.last()).map_or(false, |(from, tx)| tx.time < from);
+
+                let next = match is_last_page || is_before_range {
+                    true => None,
+                    false => page.pagination.cursor.map(|cursor| ExportCursor {
+                        cursor: Some(cursor),
+                        prices: Some(prices.clone()),
+                    }),
+                };
+
+                // The pages of the node are not filtered by the query, they are filtered here.
+                let address_txs: Vec<AddressTransactionForDb> = page.data.into_iter().filter(|address_tx| filter.matches(address_tx)).collect();
+
+                if address_txs.is_empty() && next.is_none() {
+                    return None;
+                }
+
+                // The messages of the page are read with a single query, the ones not saved are read from the node by each row.
+                let mut details = match filter.kind {
+                    ExportKind::Txs => HashMap::new(),
+                    _ => match chain.find_saved_tx_details(&address_txs).await {
+                        Ok(details) => details,
+                        Err(e) => return Some((Err(e.to_string()), None)),
+                    },
+                };
+
+                let mut rows = vec![];
+                for address_tx in address_txs {
+                    let tx_details = details.remove(&address_tx.hash.to_uppercase());
+
+                    match chain.get_export_rows(&address, filter.kind, address_tx, tx_details, &prices).await {
+                        Ok(tx_rows) => rows.extend(tx_rows),
+                        Err(e) => return Some((Err(e.to_string()), None)),
+                    }
+                }
+
+                Some((Ok(rows), next))
+            }
+        })
+    }
+
+    /// Returns the saved details of the address transactions, by their uppercase hashes.
+    async fn find_saved_tx_details(&self, address_txs: &[AddressTransactionForDb]) -> Result<HashMap<String, InternalTransaction>, TNRAppError> {
+        // EVM hashes are not saved, they are always resolved by the node.
+        let hashes = address_txs
+            .iter()
+            .filter(|address_tx| !address_tx.hash.starts_with("0x"))
+            .map(|address_tx| address_tx.hash.to_uppercase())
+            .collect();
+
+        let txs = self.database.find_transaction_details_by_hashes(hashes).await?;
+
+        Ok(txs.into_iter().map(|tx| (tx.hash.clone(), tx)).collect())
+    }
+
+    /// Returns the export rows of an address transaction.\
+    /// Delegation events and reward withdrawals are read from the decoded messages, one row for each message of the address.
+    /// The messages are read from given details, or from the node if the transaction is not saved.
+    async fn get_export_rows(
+        &self,
+        address: &str,
+        kind: ExportKind,
+        address_tx: AddressTransactionForDb,
+        tx_details: Option<InternalTransaction>,
+        prices: &[DailyTokenPriceForDb],
+    ) -> Result<Vec<ExportRow>, TNRAppError> {
+        let price = get_price_at(prices, address_tx.time);
+        let row = |r#type: String, validator_address: Option<String>, amount: ChainAmountItem| {
+            ExportRow::new(&address_tx, r#type, validator_address, amount, price, &self.config.main_symbol)
+        };
+
+        if kind == ExportKind::Txs {
+            return Ok(vec![row(address_tx.tx_type.clone(), None, address_tx.amount.clone())]);
+        }
+
+        let tx = match tx_details {
+            Some(tx) => tx,
+            None => self.get_stored_tx_by_hash(&address_tx.hash).await?.value,
+        };
+
+        Ok(flatten_messages(tx.content)
+            .into_iter()
+            .filter_map(|message| match (kind, message) {
+                (
+                    ExportKind::Delegations,
+                    InternalTransactionContentKnowns::Delegate {
+                        delegator_address,
+                        validator_address,
+                        amount,
+                        ..
+                    },
+                ) if delegator_address == address => Some(row(String::from("Delegate"), Some(validator_address), amount)),
+                (
+                    ExportKind::Delegations,
+                    InternalTransactionContentKnowns::Undelegate {
+                        delegator_address,
+                        validator_address,
+                        amount,
+                        ..
+                    },
+                ) if delegator_address == address => Some(row(String::from("Undelegate"), Some(validator_address), amount)),
+                (
+                    ExportKind::Delegations,
+                    InternalTransactionContentKnowns::Redelegate {
+                        delegator_address,
+                        validator_to_address,
+                        amount,
+                        ..
+                    },
+                ) if delegator_address == address => Some(row(String::from("Redelegate"), Some(validator_to_address), amount)),
+                (
+                    ExportKind::Rewards,
+                    InternalTransactionContentKnowns::WithdrawDelegatorReward {
+                        delegator_address,
+                        validator_address,
+                        amount,
+                        ..
+                    },
+                ) if delegator_address == address => Some(row(String::from("Withdraw Delegator Rewards"), Some(validator_address), amount)),
+                _ => None,
+            })
+            .collect())
+    }
+}
+
+/// Returns the known messages of a transaction, including the messages executed on behalf of the granters.
+fn flatten_messages(content: Vec<InternalTransactionContent>) -> Vec<InternalTransactionContentKnowns> {
+    content
+        .into_iter()
+        .flat_map(|message| match message {
+            InternalTransactionContent::Known(InternalTransactionContentKnowns::Exec { msgs, .. }) => flatten_messages(msgs),
+            InternalTransactionContent::Known(message) => vec![message],
+            InternalTransactionContent::Unknown { .. } => vec![],
+        })
+        .collect()
+}
+
+/// Returns the price of the day of given time, if the price history has it.
+fn get_price_at(prices: &[DailyTokenPriceForDb], time: i64) -> Option<f64> {
+    let index = prices.partition_point(|price| price.timestamp <= time);
+
+    match index {
+        0 => None,
+        index => prices
+            .get(index - 1)
+            .filter(|price| price.timestamp == day_start_ms(time))
+            .map(|price| price.value),
+    }
+}
+
+/// The state of an export between the pages.
+struct ExportCursor {
+    cursor: Option<String>,
+    prices: Option<Vec<DailyTokenPriceForDb>>,
+}
+
+/// The kind of the rows of an export.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportKind {
+    /// All the transactions of the address, one row for each transaction.
+    Txs,
+    /// The delegations, undelegations and redelegations of the address, one row for each message.
+    Delegations,
+    /// The reward withdrawals of the address, one row for each validator.
+    Rewards,
+}
+
+impl ExportKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportKind::Txs => "txs",
+            ExportKind::Delegations => "delegations",
+            ExportKind::Rewards => "rewards",
+        }
+    }
+}
+
+impl Default for ExportKind {
+    fn default() -> Self {
+        Self::Txs
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ExportFilter {
+    pub kind: ExportKind,
+    /// The time in milliseconds the export starts from, inclusive.
+    pub from: Option<i64>,
+    /// The time in milliseconds the export ends at, inclusive.
+    pub to: Option<i64>,
+}
+
+impl ExportFilter {
+    /// Returns the address transactions query of the filter.
+    fn to_query(&self, address: &str) -> Document {
+        let mut query = doc! {"address": address};
+
+        let mut time = Document::new();
+        if let Some(from) = self.from {
+            time.insert("$gte", from);
+        }
+        if let Some(to) = self.to {
+            time.insert("$lte", to);
+        }
+        if !time.is_empty() {
+            query.insert("time", time);
+        }
+
+        let message_types = self.message_types();
+        if !message_types.is_empty() {
+            query.insert("message_types", doc! {"$in": message_types});
+        }
+
+        query
+    }
+
+    /// Returns `true` if the address transaction is in the time range and has a message of the kind.\
+    /// It is used for the transactions read from the node, the ones in the address index are filtered by `to_query`.
+    fn matches(&self, address_tx: &AddressTransactionForDb) -> bool {
+        let in_range = self.from.map_or(true, |from| address_tx.time >= from) && self.to.map_or(true, |to| address_tx.time <= to);
+
+        let message_types = self.message_types();
+        let has_message_type = message_types.is_empty()
+            || address_tx
+                .message_types
+                .iter()
+                .any(|message_type| message_types.contains(&message_type.as_str()));
+
+        in_range && has_message_type
+    }
+
+    /// Returns the message types of the kind, all the types are exported if it is empty.
+    fn message_types(&self) -> Vec<&'static str> {
+        // Staking messages sent by an authz grantee, like the restaking bots, are wrapped in `Exec` messages.
+        match self.kind {
+            ExportKind::Txs => vec![],
+            ExportKind::Delegations => DELEGATION_MESSAGE_TYPES.into_iter().chain(["Exec"]).collect(),
+            ExportKind::Rewards => REWARD_MESSAGE_TYPES.into_iter().chain(["Exec"]).collect(),
+        }
+    }
+}
+
+/// A row of an address history export.
+#[derive(Clone, Debug, Serialize)]
+pub struct ExportRow {
+    /// Milliseconds since the Unix epoch.
+    pub time: i64,
+    /// RFC 3339 date of the time. Eg: `"2023-05-09T12:34:56Z"`
+    pub date: String,
+    pub height: u64,
+    pub hash: String,
+    /// The type of the transaction or the message. Eg: `"Withdraw Delegator Rewards"`
+    pub r#type: String,
+    /// The validator of the delegation event or the reward withdrawal.
+    pub validator_address: Option<String>,
+    pub amount: String,
+    pub ticker: String,
+    pub fee: String,
+    pub fee_ticker: String,
+    pub result: String,
+    /// The USD price of the chain token on the day of the transaction.
+    pub price: Option<f64>,
+    /// The USD value of the amount, if the amount is in the chain token.
+    pub value: Option<f64>,
+}
+
+impl ExportRow {
+    /// The header line of the CSV exports.
+    pub const CSV_HEADER: &'static str = "time,date,height,hash,type,validator_address,amount,ticker,fee,fee_ticker,result,price,value\n";
+
+    fn new(
+        address_tx: &AddressTransactionForDb,
+        r#type: String,
+        validator_address: Option<String>,
+        amount: ChainAmountItem,
+        price: Option<f64>,
+        main_symbol: &str,
+    ) -> Self {
+        let value = match amount.ticker == main_symbol {
+            true => price.zip(amount.amount.to_f64()).map(|(price, amount)| price * amount),
+            false => None,
+        };
+
+        Self {
+            time: address_tx.time,
+            date: Utc
+                .timestamp_millis_opt(address_tx.time)
+                .single()
+                .map(|date| date.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+                .unwrap_or_default(),
+            height: address_tx.height,
+            hash: address_tx.hash.clone(),
+            r#type,
+            validator_address,
+            amount: amount.amount.to_string(),
+            ticker: amount.ticker,
+            fee: address_tx.fee.amount.to_string(),
+            fee_ticker: address_tx.fee.ticker.clone(),
+            result: address_tx.result.clone(),
+            price,
+            value,
+        }
+    }
+
+    /// Returns the row as a CSV line, in the order of `CSV_HEADER`.
+    pub fn to_csv_line(&self) -> String {
+        let fields = [
+            self.time.to_string(),
+            self.date.clone(),
+            self.height.to_string(),
+            self.hash.clone(),
+            self.r#type.clone(),
+            self.validator_address.clone().unwrap_or_default(),
+            self.amount.clone(),
+            self.ticker.clone(),
+            self.fee.clone(),
+            self.fee_ticker.clone(),
+            self.result.clone(),
+            self.price.map(|price| price.to_string()).unwrap_or_default(),
+            self.value.map(|value| value.to_string()).unwrap_or_default(),
+        ];
+
+        let mut line = fields.iter().map(|field| escape_csv_field(field)).collect::<Vec<String>>().join(",");
+        line.push('\n');
+        line
+    }
+
+    /// Returns the row as a line of newline delimited JSON.
+    pub fn to_ndjson_line(&self) -> Result<String, String> {
+        let mut line = serde_json::to_string(self).map_err(|e| format!("Cannot serialize the export row: {e}"))?;
+        line.push('\n');
+        Ok(line)
+    }
+}
+
+/// Quotes a CSV field if it has a separator, a quote or a line break in it.
+fn escape_csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::DAY_MS;
+
+    fn daily_price(timestamp: i64, value: f64) -> DailyTokenPriceForDb {
+        DailyTokenPriceForDb {
+            token: "axelar".to_string(),
+            timestamp,
+            value,
+        }
+    }
+
+    #[test]
+    fn escape_csv_field_keeps_plain_fields() {
+        assert_eq!(escape_csv_field(""), "");
+        assert_eq!(escape_csv_field("axelar1abc"), "axelar1abc");
+        assert_eq!(escape_csv_field("12.5"), "12.5");
+    }
+
+    #[test]
+    fn escape_csv_field_quotes_separators_and_line_breaks() {
+        assert_eq!(escape_csv_field("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv_field("a\nb"), "\"a\nb\"");
+        assert_eq!(escape_csv_field("a\r\nb"), "\"a\r\nb\"");
+    }
+
+    #[test]
+    fn escape_csv_field_doubles_quotes() {
+        assert_eq!(escape_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_csv_field("\""), "\"\"\"\"");
+    }
+
+    #[test]
+    fn get_price_at_returns_the_price_of_the_day() {
+        let day = 1_683_590_400_000;
+        let prices = [daily_price(day, 1.0), daily_price(day + DAY_MS, 2.0), daily_price(day + 3 * DAY_MS, 4.0)];
+
+        assert_eq!(get_price_at(&prices, day - 1), None);
+        assert_eq!(get_price_at(&prices, day), Some(1.0));
+        assert_eq!(get_price_at(&prices, day + DAY_MS - 1), Some(1.0));
+        assert_eq!(get_price_at(&prices, day + DAY_MS + 1), Some(2.0));
+        assert_eq!(get_price_at(&prices, day + 2 * DAY_MS + 1), None);
+        assert_eq!(get_price_at(&prices, day + 3 * DAY_MS + 1), Some(4.0));
+        assert_eq!(get_price_at(&prices, day + 4 * DAY_MS), None);
+    }
+}
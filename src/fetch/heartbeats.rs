@@ -1,5 +1,6 @@
 use mongodb::bson::doc;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::chain::Chain;
 use crate::database::{HeartbeatForDb, ListDbResult};
@@ -69,7 +70,7 @@ impl HeartbeatsQuery {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ToSchema)]
 pub enum HeartbeatStatus {
     Success,
     Fail,
@@ -1,7 +1,10 @@
 pub mod account;
+pub mod address_txs;
+pub mod all_chains;
 pub mod amount_util;
 pub mod apr;
 pub mod assets;
+pub mod batch;
 pub mod blocks;
 pub mod chain;
 pub mod chain_socket;
@@ -9,15 +12,19 @@ pub mod delegations;
 pub mod delegators;
 pub mod evm;
 pub mod evm_socket_handler;
+pub mod export;
+pub mod health;
 pub mod heartbeats;
 pub mod others;
 pub mod params;
 pub mod proposals;
 pub mod requests;
+pub mod search;
 pub mod socket;
 pub mod tokenomics;
 pub mod transactions;
 pub mod utils;
+pub mod validator_signing;
 pub mod validators;
 
 use crate::fetch::cosmos::base::query::v1beta1::PageResponse;
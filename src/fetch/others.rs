@@ -1,9 +1,13 @@
-use crate::{chain::Chain, routes::OutRestResponse};
+use crate::{
+    chain::Chain,
+    routes::{OutRestResponse, TNRAppError, TNRAppErrorType},
+};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 impl Chain {
     /// Returns staking pool information.
-    pub async fn get_staking_pool(&self) -> Result<OutRestResponse<InternalStakingPool>, String> {
+    pub async fn get_staking_pool(&self) -> Result<OutRestResponse<InternalStakingPool>, TNRAppError> {
         let resp = self.rest_api_request::<StakingPoolResp>("/cosmos/staking/v1beta1/pool", &[]).await?;
         let staking_pool = InternalStakingPool {
             unbonded: self.calc_amount_u128_to_u64(
@@ -24,7 +28,7 @@ impl Chain {
     }
 
     /// Returns the native coin amount in the community pool.
-    pub async fn get_community_pool(&self) -> Result<OutRestResponse<u64>, String> {
+    pub async fn get_community_pool(&self) -> Result<OutRestResponse<u64>, TNRAppError> {
         let resp = self
             .rest_api_request::<CommunityPoolResp>("/cosmos/distribution/v1beta1/community_pool", &[])
             .await?;
@@ -48,11 +52,14 @@ impl Chain {
         Ok(OutRestResponse::new(community_pool_amount, 0))
     }
 
-    pub async fn get_annual_provisions(&self) -> Result<OutRestResponse<f64>, String> {
+    pub async fn get_annual_provisions(&self) -> Result<OutRestResponse<f64>, TNRAppError> {
         match self.config.name.as_str() {
             "example_chain_name" => {
                 //TODO If Needed Fill this block scope with related chain
-                Err("Chain Mint Params Not Implemented Yet".to_string())
+                Err(TNRAppError::new(
+                    TNRAppErrorType::UnsupportedError,
+                    "Chain Mint Params Not Implemented Yet",
+                ))
             }
             _ => {
                 let annual_provisions = match self
@@ -61,7 +68,7 @@ impl Chain {
                 {
                     Ok(value) => match value.annual_provisions.parse::<f64>() {
                         Ok(res) => res,
-                        Err(_) => return Err("Parsing Error".to_string()),
+                        Err(_) => return Err("Parsing Error".to_string().into()),
                     },
                     Err(error) => return Err(error),
                 };
@@ -193,7 +200,7 @@ pub struct StakingPool {
     pub bonded_tokens: String,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, ToSchema)]
 pub struct InternalStakingPool {
     /// Tokens unbonded. Eg: `15241580330282`
     pub unbonded: u64,
@@ -298,19 +305,3 @@ impl PaginationConfig {
         }
     }
 }
-
-#[derive(Serialize, Debug)]
-#[serde(untagged)]
-pub enum Response<S> {
-    Success(S),
-    Error(String),
-}
-
-impl<T> From<Result<T, String>> for Response<T> {
-    fn from(res: Result<T, String>) -> Self {
-        match res {
-            Ok(value) => Response::Success(value),
-            Err(error) => Response::Error(error),
-        }
-    }
-}
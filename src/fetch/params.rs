@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use tokio::join;
-use tonic::transport::Endpoint;
+use utoipa::ToSchema;
 
 use crate::fetch::cosmos::distribution::v1beta1::Params as DistributionParams;
 use crate::fetch::cosmos::gov::v1beta1::TallyParams;
@@ -9,11 +9,14 @@ use crate::fetch::cosmos::slashing::v1beta1::Params as SlashingParams;
 use crate::fetch::cosmos::staking::v1beta1::Params as StakingParams;
 use crate::utils::bytes_to_dec;
 use crate::utils::str_to_dec;
-use crate::{chain::Chain, routes::OutRestResponse};
+use crate::{
+    chain::Chain,
+    routes::{OutRestResponse, TNRAppError},
+};
 
 impl Chain {
     /// Returns the all parameters of the chain.
-    pub async fn get_params_all(&self) -> Result<ChainParams, String> {
+    pub async fn get_params_all(&self) -> Result<ChainParams, TNRAppError> {
         let (tally, voting, deposit, distribution, staking, slashing) = join!(
             self.get_tally_params(),
             self.get_voting_params(),
@@ -66,19 +69,17 @@ impl Chain {
     }
 
     /// Returns the slashing parameters of the chain.
-    pub async fn get_slashing_params(&self) -> Result<OutRestResponse<InternalSlashingParams>, String> {
+    pub async fn get_slashing_params(&self) -> Result<OutRestResponse<InternalSlashingParams>, TNRAppError> {
         use crate::fetch::cosmos::slashing::v1beta1::{query_client::QueryClient, QueryParamsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.clone().grpc_url.unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryParamsRequest {};
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .params(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let slashing_params = resp.params.unwrap().try_into()?;
@@ -87,19 +88,17 @@ impl Chain {
     }
 
     /// Returns the staking parameters.
-    async fn get_staking_params(&self) -> Result<OutRestResponse<InternalStakingParams>, String> {
+    async fn get_staking_params(&self) -> Result<OutRestResponse<InternalStakingParams>, TNRAppError> {
         use crate::fetch::cosmos::staking::v1beta1::{query_client::QueryClient, QueryParamsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryParamsRequest {};
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .params(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let staking_params = resp.params.unwrap().try_into()?;
@@ -108,21 +107,19 @@ impl Chain {
     }
 
     /// Returns the voting parameters.
-    async fn get_voting_params(&self) -> Result<OutRestResponse<InternalVotingParams>, String> {
+    async fn get_voting_params(&self) -> Result<OutRestResponse<InternalVotingParams>, TNRAppError> {
         use crate::fetch::cosmos::gov::v1beta1::{query_client::QueryClient, QueryParamsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryParamsRequest {
             params_type: "voting".to_string(),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .params(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let voting_params = resp.voting_params.unwrap().try_into()?;
@@ -131,19 +128,17 @@ impl Chain {
     }
 
     /// Returns the distribution parameters.
-    async fn get_distribution_params(&self) -> Result<OutRestResponse<InternalDistributionParams>, String> {
+    async fn get_distribution_params(&self) -> Result<OutRestResponse<InternalDistributionParams>, TNRAppError> {
         use crate::fetch::cosmos::distribution::v1beta1::{query_client::QueryClient, QueryParamsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryParamsRequest {};
 
-        let resp: crate::fetch::cosmos::distribution::v1beta1::QueryParamsResponse = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp: crate::fetch::cosmos::distribution::v1beta1::QueryParamsResponse = QueryClient::new(channel)
             .params(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let distribution_params = resp.params.unwrap().try_into()?;
@@ -152,35 +147,33 @@ impl Chain {
     }
 
     /// Returns the deposit parameters.
-    async fn get_deposit_params(&self) -> Result<OutRestResponse<InternalDepositParams>, String> {
+    async fn get_deposit_params(&self) -> Result<OutRestResponse<InternalDepositParams>, TNRAppError> {
         use crate::fetch::cosmos::gov::v1beta1::{query_client::QueryClient, QueryParamsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryParamsRequest {
             params_type: "deposit".to_string(),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .params(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let val = resp.deposit_params.unwrap();
 
         let Some(max_deposit_period) = val.max_deposit_period.map(|d| d.seconds) else {
-            return Err("Cannot parse maximum deposit period".to_string());
+            return Err("Cannot parse maximum deposit period".to_string().into());
         };
 
         let Some(den) = val.min_deposit.get(0) else {
-            return Err("There is no min deposit amount.".to_string());
+            return Err("There is no min deposit amount.".to_string().into());
         };
 
         let Ok(amount) = den.amount.parse::<u128>() else {
-            return Err(format!("Cannor parse amount, '{}'.", den.amount))
+            return Err(format!("Cannor parse amount, '{}'.", den.amount).into())
         };
 
         let min_deposit = self.calc_amount_u128_to_f64(amount);
@@ -193,21 +186,19 @@ impl Chain {
     }
 
     /// Returns the tallying parameters.
-    async fn get_tally_params(&self) -> Result<OutRestResponse<InternalTallyParams>, String> {
+    async fn get_tally_params(&self) -> Result<OutRestResponse<InternalTallyParams>, TNRAppError> {
         use crate::fetch::cosmos::gov::v1beta1::{query_client::QueryClient, QueryParamsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryParamsRequest {
             params_type: "tallying".to_string(),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .params(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let tally_params = resp.tally_params.unwrap().try_into()?;
@@ -387,7 +378,7 @@ impl TryFrom<SlashingParams> for InternalSlashingParams {
     }
 }
 /// The chain params.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct ChainParams {
     pub staking: ChainParamsStaking,
     pub slashing: ChainParamsSlashing,
@@ -396,7 +387,7 @@ pub struct ChainParams {
 }
 
 /// The staking params.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ChainParamsStaking {
     pub unbonding_time: i64,
@@ -407,7 +398,7 @@ pub struct ChainParamsStaking {
 }
 
 /// The slashing params.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ChainParamsSlashing {
     pub signed_blocks_window: i64,
@@ -418,7 +409,7 @@ pub struct ChainParamsSlashing {
 }
 
 /// The governance params.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ChainParamsGov {
     pub quorum: f64,
@@ -429,7 +420,7 @@ pub struct ChainParamsGov {
 }
 
 /// The governance params.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ChainParamsDistribution {
     pub community_tax: f64,
@@ -1,7 +1,7 @@
 use futures::future::join_all;
 use serde::{Deserialize, Serialize};
 use std::str;
-use tonic::transport::Endpoint;
+use utoipa::ToSchema;
 
 use crate::{
     chain::Chain,
@@ -10,7 +10,9 @@ use crate::{
         PaginationData,
         ProposalStatus,
         ChainAmountItem,
-        PaginationDirection
+        PaginationDirection,
+        TNRAppError,
+        TNRAppErrorType
     },
     fetch::{
         amount_util::TnrDecimal,
@@ -50,11 +52,12 @@ use prost::Message;
 use crate::fetch::lavanet::lava::plans::PlansAddProposal;
 use crate::fetch::lavanet::lava::spec::SpecAddProposal;
 
-#[derive(Deserialize, Serialize, Clone, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, ToSchema)]
 pub struct ProposalInfo {
     pub title: String,
     pub description: String,
     pub type_url: String,
+    #[schema(value_type = Object)]
     pub content: serde_json::Value,
 }
 
@@ -200,6 +203,7 @@ impl Into<PageRequest> for PaginationData {
 
 impl From<PageResponse> for PaginationData {
     fn from(value: PageResponse) -> Self {
+        let total = grpc_total(&value);
         let cursor = if !value.next_key.is_empty() {
             Some(base64::encode(value.next_key))
         } else {
@@ -211,12 +215,15 @@ impl From<PageResponse> for PaginationData {
             offset: None,
             limit: None,
             direction: Some(PaginationDirection::Next),
+            prev_cursor: None,
+            total,
         }
     }
 }
 
 impl PaginationData {
     fn from_grpc_pagin_resp(value: PageResponse, limit: Option<u64>) -> Self {
+        let total = grpc_total(&value);
         let cursor = if !value.next_key.is_empty() {
             Some(base64::encode(value.next_key))
         } else {
@@ -227,15 +234,23 @@ impl PaginationData {
             offset: None,
             limit,
             direction: Some(PaginationDirection::Next),
+            prev_cursor: None,
+            total,
         }
     }
 }
 
+/// Returns the total of a node response.\
+/// The nodes only count the items for the first page, they return `0` for the pages read by a key.
+fn grpc_total(value: &PageResponse) -> Option<u64> {
+    (value.total > 0).then_some(value.total)
+}
+
 impl Chain {
-    async fn get_proposals_v1(&self, status: &str, config: PaginationData) -> Result<ListDbResult<ProposalItem>, String> {
+    async fn get_proposals_v1(&self, status: &str, config: PaginationData) -> Result<ListDbResult<ProposalItem>, TNRAppError> {
         use crate::fetch::cosmos::gov::v1::{query_client::QueryClient, Proposal, QueryProposalsRequest};
         let limit = config.limit;
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
         let pagination = PageRequest {
             reverse: true,
             ..config.into()
@@ -247,12 +262,10 @@ impl Chain {
             pagination: Some(pagination),
         };
 
-        let resp = QueryClient::connect(endpoint.clone())
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel.clone())
             .proposals(proposal_request)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
         let proposals = resp.into_inner();
 
         let mut items = Vec::with_capacity(proposals.proposals.len());
@@ -287,10 +300,10 @@ impl Chain {
                 .unwrap_or_default(),
         })
     }
-    async fn get_proposals_v1beta1(&self, status: &str, config: PaginationData) -> Result<ListDbResult<ProposalItem>, String> {
+    async fn get_proposals_v1beta1(&self, status: &str, config: PaginationData) -> Result<ListDbResult<ProposalItem>, TNRAppError> {
         use crate::fetch::cosmos::gov::v1beta1::{query_client::QueryClient, Proposal, QueryProposalsRequest};
         let limit = config.limit;
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
         let pagination = PageRequest {
             reverse: true,
             ..config.into()
@@ -302,12 +315,10 @@ impl Chain {
             pagination: Some(pagination),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .proposals(proposal_request)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
 
         let proposals = resp.into_inner();
 
@@ -349,7 +360,7 @@ impl Chain {
         })
     }
     /// Returns all the proposals in voting period.
-    pub async fn get_proposals_by_status(&self, status: ProposalStatus, config: PaginationData) -> Result<ListDbResult<ProposalItem>, String> {
+    pub async fn get_proposals_by_status(&self, status: ProposalStatus, config: PaginationData) -> Result<ListDbResult<ProposalItem>, TNRAppError> {
         let status_id = status.get_id().to_string();
         let items = if self.config.sdk_version.minor >= 46 {
             self.get_proposals_v1(&status_id, config.clone()).await.ok()
@@ -360,29 +371,25 @@ impl Chain {
         let items = if let Some(items) = items {
             items
         } else {
-            self.get_proposals_v1beta1(&status_id, config)
-                .await
-                .map_err(|e| format!("Upstream error: {}", e))?
+            self.get_proposals_v1beta1(&status_id, config).await?
         };
 
         Ok(items)
     }
 
-    async fn get_proposal_details_v1(&self, proposal_id: u64) -> Result<InternalProposal, String> {
+    async fn get_proposal_details_v1(&self, proposal_id: u64) -> Result<InternalProposal, TNRAppError> {
         use crate::fetch::cosmos::gov::v1::{query_client::QueryClient, QueryProposalRequest};
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let proposal_request = QueryProposalRequest { proposal_id };
 
-        let client = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let client = QueryClient::new(channel)
             .proposal(proposal_request)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
 
         let proposal_resp = client.into_inner();
-        let proposal = proposal_resp.proposal.ok_or_else(|| String::from("No proposal content"))?;
+        let proposal = proposal_resp.proposal.ok_or_else(|| TNRAppError::new(TNRAppErrorType::NotFoundError, "No proposal content"))?;
         let final_tally_result = self.get_proposal_tally(proposal_id).await.ok();
 
         let messages = proposal.messages.into_iter().map(|m| m.into()).collect();
@@ -408,20 +415,18 @@ impl Chain {
 
         Ok(internal_proposal)
     }
-    async fn get_proposal_details_v1beta1(&self, proposal_id: u64) -> Result<InternalProposal, String> {
+    async fn get_proposal_details_v1beta1(&self, proposal_id: u64) -> Result<InternalProposal, TNRAppError> {
         use crate::fetch::cosmos::gov::v1beta1::{query_client::QueryClient, QueryProposalRequest};
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
         let proposal_request = QueryProposalRequest { proposal_id };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .proposal(proposal_request)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
 
         let proposal_resp = resp.into_inner();
-        let proposal = proposal_resp.proposal.ok_or_else(|| String::from("No proposal content"))?;
+        let proposal = proposal_resp.proposal.ok_or_else(|| TNRAppError::new(TNRAppErrorType::NotFoundError, "No proposal content"))?;
 
         let prop_info: Option<ProposalInfo> = proposal.content.map(|c| c.into());
 
@@ -458,7 +463,7 @@ impl Chain {
         Ok(internal_proposal)
     }
     /// Returns the details of given proposal.
-    pub async fn get_proposal_details(&self, proposal_id: u64) -> Result<InternalProposal, String> {
+    pub async fn get_proposal_details(&self, proposal_id: u64) -> Result<InternalProposal, TNRAppError> {
         let items = if self.config.sdk_version.minor >= 46 {
             self.get_proposal_details_v1(proposal_id).await.ok()
         } else {
@@ -468,29 +473,25 @@ impl Chain {
         let items = if let Some(items) = items {
             items
         } else {
-            self.get_proposal_details_v1beta1(proposal_id)
-                .await
-                .map_err(|e| format!("Upstream error: {}", e))?
+            self.get_proposal_details_v1beta1(proposal_id).await?
         };
 
         Ok(items)
     }
 
-    async fn proposal_deposits_v1(&self, proposal_id: u64, config: PaginationData) -> Result<ListDbResult<InternalProposalDeposit>, String> {
+    async fn proposal_deposits_v1(&self, proposal_id: u64, config: PaginationData) -> Result<ListDbResult<InternalProposalDeposit>, TNRAppError> {
         use crate::fetch::cosmos::gov::v1::{query_client::QueryClient, QueryDepositsRequest};
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let deposits_request = QueryDepositsRequest {
             proposal_id,
             pagination: Some(config.into()),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .deposits(deposits_request)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
         let deposits = resp.into_inner();
 
         let internal_deposits = join_all(deposits.deposits.iter().map(|d| async move {
@@ -510,21 +511,23 @@ impl Chain {
         })
     }
 
-    async fn proposal_deposits_v1beta1(&self, proposal_id: u64, config: PaginationData) -> Result<ListDbResult<InternalProposalDeposit>, String> {
+    async fn proposal_deposits_v1beta1(
+        &self,
+        proposal_id: u64,
+        config: PaginationData,
+    ) -> Result<ListDbResult<InternalProposalDeposit>, TNRAppError> {
         use crate::fetch::cosmos::gov::v1beta1::{query_client::QueryClient, QueryDepositsRequest};
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let deposit_request = QueryDepositsRequest {
             proposal_id,
             pagination: Some(config.into()),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .deposits(deposit_request)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
 
         let deposits = resp.into_inner();
         let internal_deposits = join_all(deposits.deposits.iter().map(|d| async move {
@@ -544,7 +547,7 @@ impl Chain {
         })
     }
     /// Returns the deposits of given proposal.
-    pub async fn get_proposal_deposits(&self, proposal_id: u64, config: PaginationData) -> Result<ListDbResult<InternalProposalDeposit>, String> {
+    pub async fn get_proposal_deposits(&self, proposal_id: u64, config: PaginationData) -> Result<ListDbResult<InternalProposalDeposit>, TNRAppError> {
         let items = if self.config.sdk_version.minor >= 46 {
             self.proposal_deposits_v1(proposal_id, config.clone()).await.ok()
         } else {
@@ -554,32 +557,28 @@ impl Chain {
         let items = if let Some(items) = items {
             items
         } else {
-            self.proposal_deposits_v1beta1(proposal_id, config)
-                .await
-                .map_err(|e| format!("Upstream error: {}", e))?
+            self.proposal_deposits_v1beta1(proposal_id, config).await?
         };
 
         Ok(items)
     }
 
-    async fn proposal_deposit_v1(&self, proposal_id: u64, depositor: &str) -> Result<InternalProposalDeposit, String> {
+    async fn proposal_deposit_v1(&self, proposal_id: u64, depositor: &str) -> Result<InternalProposalDeposit, TNRAppError> {
         use crate::fetch::cosmos::gov::v1::{query_client::QueryClient, QueryDepositRequest};
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let deposit_request = QueryDepositRequest {
             proposal_id,
             depositor: depositor.to_string(),
         };
 
-        let client = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let client = QueryClient::new(channel)
             .deposit(deposit_request)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
 
         let deposit = client.into_inner();
-        let deposit = deposit.deposit.ok_or_else(|| String::from("Deposit not found"))?;
+        let deposit = deposit.deposit.ok_or_else(|| TNRAppError::new(TNRAppErrorType::NotFoundError, "Deposit not found"))?;
 
         let string_amount = deposit.amount.iter().map(|d| d.amount.clone()).collect();
         let amount = self.string_amount_parser(string_amount, None).await.unwrap_or_default();
@@ -593,22 +592,20 @@ impl Chain {
         Ok(internal_deposit)
     }
 
-    async fn proposal_deposit_v1beta1(&self, proposal_id: u64, depositor: &str) -> Result<InternalProposalDeposit, String> {
+    async fn proposal_deposit_v1beta1(&self, proposal_id: u64, depositor: &str) -> Result<InternalProposalDeposit, TNRAppError> {
         use crate::fetch::cosmos::gov::v1beta1::{query_client::QueryClient, QueryDepositRequest};
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
         let deposit_request = QueryDepositRequest {
             proposal_id,
             depositor: depositor.to_string(),
         };
-        let client = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let client = QueryClient::new(channel)
             .deposit(deposit_request)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
 
         let deposit = client.into_inner();
-        let deposit = deposit.deposit.ok_or_else(|| String::from("Deposit not found"))?;
+        let deposit = deposit.deposit.ok_or_else(|| TNRAppError::new(TNRAppErrorType::NotFoundError, "Deposit not found"))?;
 
         let string_amount = deposit.amount.iter().map(|d| d.amount.clone()).collect();
         let amount = self.string_amount_parser(string_amount, None).await.unwrap_or_default();
@@ -621,7 +618,7 @@ impl Chain {
         Ok(internal_deposit)
     }
     /// Returns the deposit of given proposal by given depositor.
-    pub async fn get_proposal_deposit_by_depositor(&self, proposal_id: u64, depositor: &str) -> Result<InternalProposalDeposit, String> {
+    pub async fn get_proposal_deposit_by_depositor(&self, proposal_id: u64, depositor: &str) -> Result<InternalProposalDeposit, TNRAppError> {
         let items = if self.config.sdk_version.minor >= 46 {
             self.proposal_deposit_v1(proposal_id, depositor).await.ok()
         } else {
@@ -631,46 +628,40 @@ impl Chain {
         let items = if let Some(items) = items {
             items
         } else {
-            self.proposal_deposit_v1beta1(proposal_id, depositor)
-                .await
-                .map_err(|e| format!("Upstream error: {}", e))?
+            self.proposal_deposit_v1beta1(proposal_id, depositor).await?
         };
 
         Ok(items)
     }
 
-    async fn proposal_tally_v1(&self, proposal_id: u64) -> Result<InternalProposalFinalTallyResult, String> {
+    async fn proposal_tally_v1(&self, proposal_id: u64) -> Result<InternalProposalFinalTallyResult, TNRAppError> {
         use crate::fetch::cosmos::gov::v1::{query_client::QueryClient, QueryTallyResultRequest};
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
         let tally_request = QueryTallyResultRequest { proposal_id };
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .tally_result(tally_request)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
 
         let tally_resp = resp.into_inner();
-        let tally = tally_resp.tally.ok_or_else(|| String::from("Tally not found"))?;
+        let tally = tally_resp.tally.ok_or_else(|| TNRAppError::new(TNRAppErrorType::NotFoundError, "Tally not found"))?;
 
         let internal_proposal_final_tally_result = InternalProposalFinalTallyResult::from_raw_tally_result(self, tally.into()).await;
 
         Ok(internal_proposal_final_tally_result)
     }
 
-    async fn proposal_tally_v1beta1(&self, proposal_id: u64) -> Result<InternalProposalFinalTallyResult, String> {
+    async fn proposal_tally_v1beta1(&self, proposal_id: u64) -> Result<InternalProposalFinalTallyResult, TNRAppError> {
         use crate::fetch::cosmos::gov::v1beta1::{query_client::QueryClient, QueryTallyResultRequest};
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
         let tally_request = QueryTallyResultRequest { proposal_id };
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .tally_result(tally_request)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
 
         let tally_resp = resp.into_inner();
-        let tally = tally_resp.tally.ok_or_else(|| String::from("Tally not found"))?;
+        let tally = tally_resp.tally.ok_or_else(|| TNRAppError::new(TNRAppErrorType::NotFoundError, "Tally not found"))?;
 
         let internal_proposal_final_tally_result = InternalProposalFinalTallyResult::from_raw_tally_result(self, tally.into()).await;
 
@@ -678,7 +669,7 @@ impl Chain {
     }
 
     /// Returns the tally of given proposal.
-    pub async fn get_proposal_tally(&self, proposal_id: u64) -> Result<InternalProposalFinalTallyResult, String> {
+    pub async fn get_proposal_tally(&self, proposal_id: u64) -> Result<InternalProposalFinalTallyResult, TNRAppError> {
         let items = if self.config.sdk_version.minor >= 46 {
             self.proposal_tally_v1(proposal_id).await.ok()
         } else {
@@ -688,27 +679,23 @@ impl Chain {
         let items = if let Some(items) = items {
             items
         } else {
-            self.proposal_tally_v1beta1(proposal_id)
-                .await
-                .map_err(|e| format!("Upstream error: {}", e))?
+            self.proposal_tally_v1beta1(proposal_id).await?
         };
 
         Ok(items)
     }
 
-    async fn proposal_votes_v1(&self, proposal_id: u64, config: PaginationData) -> Result<ListDbResult<InternalProposalVote>, String> {
+    async fn proposal_votes_v1(&self, proposal_id: u64, config: PaginationData) -> Result<ListDbResult<InternalProposalVote>, TNRAppError> {
         use crate::fetch::cosmos::gov::v1::{query_client::QueryClient, QueryVotesRequest};
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
         let votes_request = QueryVotesRequest {
             proposal_id,
             pagination: Some(config.into()),
         };
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .votes(votes_request)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
 
         let votes_resp = resp.into_inner();
         let votes = votes_resp.votes;
@@ -741,19 +728,17 @@ impl Chain {
         })
     }
 
-    async fn proposal_votes_v1beta1(&self, proposal_id: u64, config: PaginationData) -> Result<ListDbResult<InternalProposalVote>, String> {
+    async fn proposal_votes_v1beta1(&self, proposal_id: u64, config: PaginationData) -> Result<ListDbResult<InternalProposalVote>, TNRAppError> {
         use crate::fetch::cosmos::gov::v1beta1::{query_client::QueryClient, QueryVotesRequest};
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
         let votes_request = QueryVotesRequest {
             proposal_id,
             pagination: Some(config.into()),
         };
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .votes(votes_request)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
 
         let votes_resp = resp.into_inner();
         let votes = votes_resp.votes;
@@ -786,7 +771,7 @@ impl Chain {
         })
     }
     /// Returns the votes of given proposal.
-    pub async fn get_proposal_votes(&self, proposal_id: u64, config: PaginationData) -> Result<ListDbResult<InternalProposalVote>, String> {
+    pub async fn get_proposal_votes(&self, proposal_id: u64, config: PaginationData) -> Result<ListDbResult<InternalProposalVote>, TNRAppError> {
         let items = if self.config.sdk_version.minor >= 46 {
             self.proposal_votes_v1(proposal_id, config.clone()).await.ok()
         } else {
@@ -796,30 +781,26 @@ impl Chain {
         let items = if let Some(items) = items {
             items
         } else {
-            self.proposal_votes_v1beta1(proposal_id, config)
-                .await
-                .map_err(|e| format!("Upstream error: {}", e))?
+            self.proposal_votes_v1beta1(proposal_id, config).await?
         };
 
         Ok(items)
     }
 
-    async fn proposal_vote_by_voter_v1(&self, proposal_id: u64, voter: &str) -> Result<InternalProposalVote, String> {
+    async fn proposal_vote_by_voter_v1(&self, proposal_id: u64, voter: &str) -> Result<InternalProposalVote, TNRAppError> {
         use crate::fetch::cosmos::gov::v1::{query_client::QueryClient, QueryVoteRequest};
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
         let vote_request = QueryVoteRequest {
             proposal_id,
             voter: voter.to_string(),
         };
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .vote(vote_request)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
 
         let vote_resp = resp.into_inner();
-        let vote = vote_resp.vote.ok_or_else(|| String::from("Vote not found"))?;
+        let vote = vote_resp.vote.ok_or_else(|| TNRAppError::new(TNRAppErrorType::NotFoundError, "Vote not found"))?;
 
         let internal_proposal_vote = InternalProposalVote {
             proposal_id,
@@ -838,22 +819,20 @@ impl Chain {
 
         Ok(internal_proposal_vote)
     }
-    async fn proposal_vote_by_voter_v1beta1(&self, proposal_id: u64, voter: &str) -> Result<InternalProposalVote, String> {
+    async fn proposal_vote_by_voter_v1beta1(&self, proposal_id: u64, voter: &str) -> Result<InternalProposalVote, TNRAppError> {
         use crate::fetch::cosmos::gov::v1beta1::{query_client::QueryClient, QueryVoteRequest};
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
         let vote_request = QueryVoteRequest {
             proposal_id,
             voter: voter.to_string(),
         };
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .vote(vote_request)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
 
         let vote_resp = resp.into_inner();
-        let vote = vote_resp.vote.ok_or_else(|| String::from("Vote not found"))?;
+        let vote = vote_resp.vote.ok_or_else(|| TNRAppError::new(TNRAppErrorType::NotFoundError, "Vote not found"))?;
 
         let internal_proposal_vote = InternalProposalVote {
             proposal_id,
@@ -873,7 +852,7 @@ impl Chain {
         Ok(internal_proposal_vote)
     }
     /// Returns the vote of given proposal by given voter.
-    pub async fn get_proposal_vote_by_voter(&self, proposal_id: u64, voter: &str) -> Result<InternalProposalVote, String> {
+    pub async fn get_proposal_vote_by_voter(&self, proposal_id: u64, voter: &str) -> Result<InternalProposalVote, TNRAppError> {
         let items = if self.config.sdk_version.minor >= 46 {
             self.proposal_vote_by_voter_v1(proposal_id, voter).await.ok()
         } else {
@@ -883,16 +862,14 @@ impl Chain {
         let items = if let Some(items) = items {
             items
         } else {
-            self.proposal_vote_by_voter_v1beta1(proposal_id, voter)
-                .await
-                .map_err(|e| format!("Upstream error: {}", e))?
+            self.proposal_vote_by_voter_v1beta1(proposal_id, voter).await?
         };
 
         Ok(items)
     }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct InternalProposalVote {
     /// Proposal ID. Eg: `34`
     pub proposal_id: u64,
@@ -906,7 +883,7 @@ pub struct InternalProposalVote {
     pub metadata: Option<String>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ProposalOption {
     /// Proposal vote option. Eg: `"VOTE_OPTION_UNSPECIFIED"`
     pub option: i32,
@@ -914,7 +891,7 @@ pub struct ProposalOption {
     pub weight: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct InternalProposalDeposit {
     /// Proposal depositor. Eg: `""`
     pub depositor: String,
@@ -922,7 +899,7 @@ pub struct InternalProposalDeposit {
     pub amount: ChainAmountItem,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct InternalProposal {
     /// Proposal ID. Eg: `79`
     pub id: u64,
@@ -953,22 +930,26 @@ pub struct InternalProposal {
     pub expedited: Option<bool>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Default)]
+#[derive(Deserialize, Serialize, Debug, Default, ToSchema)]
 pub struct InternalProposalFinalTallyResult {
     /// Number of `yes` votes. Eg: `"50"`
     pub raw_yes_count: String,
+    #[schema(value_type = String)]
     pub yes_ratio: TnrDecimal,
     pub yes_amount: ChainAmountItem,
     /// Number of `abstain` votes. Eg: `"35"`
     pub raw_abstain_count: String,
+    #[schema(value_type = String)]
     pub abstain_ratio: TnrDecimal,
     pub abstain_amount: ChainAmountItem,
     /// Number of `no` votes. Eg: `"12"`
     pub raw_no_count: String,
+    #[schema(value_type = String)]
     pub no_ratio: TnrDecimal,
     pub no_amount: ChainAmountItem,
     /// Number of `no with veto` votes.  Eg: `"7"`
     pub raw_no_with_veto_count: String,
+    #[schema(value_type = String)]
     pub no_with_veto_ratio: TnrDecimal,
     pub no_with_veto_amount: ChainAmountItem,
 }
@@ -1045,7 +1026,7 @@ impl From<TallyResultV1> for RawProposalFinalTallyResult {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, ToSchema)]
 pub struct ProposalItem {
     /// Proposal ID.
     pub proposal_id: u64,
@@ -1059,5 +1040,6 @@ pub struct ProposalItem {
     pub status: i32,
     pub type_url: String,
     // Content.
+    #[schema(value_type = Object)]
     pub content: serde_json::Value,
 }
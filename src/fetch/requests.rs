@@ -1,79 +1,90 @@
-use reqwest::{Client, Method};
+use std::time::Instant;
+
+use reqwest::{Client, Method, RequestBuilder};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tonic::body::BoxBody;
+use tonic::codegen::{http, BoxFuture, Context, Poll, Service};
+use tonic::transport::{self, Body, Channel, Endpoint};
+use tonic::{Code, Status};
 
 use crate::chain::Chain;
+use crate::metrics::METRICS;
+use crate::routes::{TNRAppError, TNRAppErrorType};
 
 impl Chain {
     /// Makes a request to the RPC node.
-    pub(super) async fn rpc_request<T: DeserializeOwned>(&self, path: &str, query: &[(&'static str, String)]) -> Result<T, String> {
+    pub(super) async fn rpc_request<T: DeserializeOwned>(&self, path: &str, query: &[(&'static str, String)]) -> Result<T, TNRAppError> {
         // Create the URL request to.
         let url = format!("{}{}", self.config.rpc_url, path);
+        let started_at = Instant::now();
 
-        match self.client.get(&url).query(query).send().await {
+        let result = match self.client.get(&url).query(query).send().await {
             Ok(res) => match res.json::<RPCResponse<T>>().await {
                 Ok(res_json) => match res_json {
                     RPCResponse::Success(res) => Ok(res.result),
-                    RPCResponse::Error(res) => Err(res.error.data),
+                    RPCResponse::Error(res) => Err(rpc_error(res.error)),
                 },
-                Err(error) => Err(format!("Cannot parse JSON.\nURL requested: {url}\nError Message:\n{error}")),
+                Err(error) => Err(invalid_json_error(&url, error)),
             },
-            Err(_) => Err(format!("Cannot make a request to `{url}`.")),
-        }
+            Err(error) => Err(TNRAppError::from_request_error(&url, error)),
+        };
+
+        METRICS.observe_upstream_request(&self.config.name, "rpc", started_at, &result);
+
+        result
     }
 
     /// Makes a request to the REST API node.
-    pub(super) async fn rest_api_request<T: DeserializeOwned>(&self, path: &str, query: &[(&'static str, String)]) -> Result<T, String> {
+    pub(super) async fn rest_api_request<T: DeserializeOwned>(&self, path: &str, query: &[(&'static str, String)]) -> Result<T, TNRAppError> {
         // Create the URL request to.
         let url = format!("{}{}", self.config.rest_url, path);
+        let started_at = Instant::now();
 
-        match self.client.get(&url).query(query).send().await {
-            Ok(res) => match res.json::<RestResponse<T>>().await {
-                Ok(res_json) => match res_json {
-                    RestResponse::Success(res_json) => Ok(res_json),
-                    RestResponse::Error { message, details: _ } => Err(message),
-                },
-                Err(error) => Err(format!("Cannot parse JSON.\nURL requested: {url}\nError Message:\n{error}")),
-            },
-            Err(_) => Err(format!("Cannot make a request to `{url}`.")),
-        }
+        let result = rest_request(self.client.get(&url).query(query), &url).await;
+
+        METRICS.observe_upstream_request(&self.config.name, "rest", started_at, &result);
+
+        result
     }
 
     /// Makes a request to the ARCHIVE REST API node.
-    pub(super) async fn archive_api_request<T: DeserializeOwned>(&self, path: &str, query: &[(&'static str, String)]) -> Result<T, String> {
+    pub(super) async fn archive_api_request<T: DeserializeOwned>(&self, path: &str, query: &[(&'static str, String)]) -> Result<T, TNRAppError> {
         // Create the URL request to.
         let url = format!("{}{}", self.config.archive_url, path);
+        let started_at = Instant::now();
 
-        match self.client.get(&url).query(query).send().await {
-            Ok(res) => match res.json::<RestResponse<T>>().await {
-                Ok(res_json) => match res_json {
-                    RestResponse::Success(res_json) => Ok(res_json),
-                    RestResponse::Error { message, details: _ } => Err(message),
-                },
-                Err(error) => Err(format!("Cannot parse JSON.\nURL requested: {url}\nError Message:\n{error}")),
-            },
-            Err(_) => Err(format!("Cannot make a request to `{url}`.")),
-        }
+        let result = rest_request(self.client.get(&url).query(query), &url).await;
+
+        METRICS.observe_upstream_request(&self.config.name, "archive", started_at, &result);
+
+        result
     }
 
     /// Makes a post request to the JSON RPC node.
-    pub(super) async fn jsonrpc_request<T: DeserializeOwned>(&self, body: String) -> Result<T, String> {
+    pub(super) async fn jsonrpc_request<T: DeserializeOwned>(&self, body: String) -> Result<T, TNRAppError> {
         // Create the URL request to.
-        let url = self
-            .config
-            .jsonrpc_url
-            .clone()
-            .ok_or_else(|| format!("`jsonrpc` key for {} is empty in `Chains.yml` file.", self.config.name))?;
+        let url = self.config.jsonrpc_url.clone().ok_or_else(|| {
+            TNRAppError::new(
+                TNRAppErrorType::UnsupportedError,
+                format!("`jsonrpc` key for {} is empty in `Chains.yml` file.", self.config.name),
+            )
+        })?;
+        let started_at = Instant::now();
 
-        match self.client.post(&url).body(body).send().await {
+        let result = match self.client.post(&url).body(body).send().await {
             Ok(res) => match res.json::<JsonRpcResponse<T>>().await {
                 Ok(res_json) => match res_json {
                     JsonRpcResponse::Success(res) => Ok(res.result),
-                    JsonRpcResponse::Error(res) => Err(res.error.message),
+                    JsonRpcResponse::Error(res) => Err(TNRAppError::new(TNRAppErrorType::UpstreamUnavailableError, res.error.message)),
                 },
-                Err(error) => Err(format!("Cannot parse JSON.\nURL requested: {url}\nError Message:\n{error}")),
+                Err(error) => Err(invalid_json_error(&url, error)),
             },
-            Err(_) => Err(format!("Cannot make a request to `{url}`.")),
-        }
+            Err(error) => Err(TNRAppError::from_request_error(&url, error)),
+        };
+
+        METRICS.observe_upstream_request(&self.config.name, "jsonrpc", started_at, &result);
+
+        result
     }
 
     // Makes a request to the External Resource
@@ -83,28 +94,117 @@ impl Chain {
         method: Method,
         full_path: &str,
         query: &[(&'static str, String)],
-    ) -> Result<T, String> {
-        let request = client.request(method, full_path);
-
-        match request.query(query).send().await {
-            Ok(res) => match res.json::<RestResponse<T>>().await {
-                Ok(res_json) => match res_json {
-                    RestResponse::Success(res_json) => Ok(res_json),
-                    RestResponse::Error { message, details: _ } => Err(message),
-                },
-                Err(error) => Err(format!("Cannot parse JSON.\nURL requested: {full_path}\nError Message:\n{error}")),
-            },
-            Err(e) => Err(format!("Cannot make a request to `{full_path}`.{}", e)),
-        }
+    ) -> Result<T, TNRAppError> {
+        rest_request(client.request(method, full_path).query(query), full_path).await
     }
 
-    pub(super) async fn coingecko_rest_client<T: DeserializeOwned>(&self, url: String, query: &[(&'static str, String)]) -> Result<T, String> {
+    pub(super) async fn coingecko_rest_client<T: DeserializeOwned>(&self, url: String, query: &[(&'static str, String)]) -> Result<T, TNRAppError> {
         let client = Client::new();
         let method = Method::GET;
         let full_path = format!("https://api.coingecko.com/api/v3{url}");
 
         self.external_rest_api_req::<T>(&client, method, &full_path, query).await
     }
+
+    /// Returns a gRPC channel to the node of the chain. The calls made through it are recorded in the metrics.
+    pub(crate) async fn grpc_channel(&self) -> Result<MeteredChannel, TNRAppError> {
+        let url = self.config.grpc_url.clone().ok_or_else(|| {
+            TNRAppError::new(
+                TNRAppErrorType::UnsupportedError,
+                format!("`grpc` key for {} is empty in `Chains.yml` file.", self.config.name),
+            )
+        })?;
+
+        let endpoint = Endpoint::from_shared(url.clone()).map_err(|e| format!("Invalid gRPC URL `{url}`: {e}"))?;
+
+        let started_at = Instant::now();
+
+        match endpoint.connect().await {
+            Ok(channel) => Ok(MeteredChannel {
+                inner: channel,
+                chain: self.config.name.clone(),
+            }),
+            Err(_) => {
+                let error = TNRAppError::new(TNRAppErrorType::UpstreamUnavailableError, format!("Cannot connect to `{url}`."));
+                METRICS.observe_upstream_outcome(&self.config.name, "grpc", started_at, error.error_type.code());
+                Err(error)
+            }
+        }
+    }
+}
+
+/// A gRPC channel to the node of a chain, it records the calls in the metrics.
+#[derive(Clone)]
+pub(crate) struct MeteredChannel {
+    inner: Channel,
+    chain: String,
+}
+
+impl Service<http::Request<BoxBody>> for MeteredChannel {
+    type Response = http::Response<Body>;
+    type Error = transport::Error;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<BoxBody>) -> Self::Future {
+        let chain = self.chain.clone();
+        let started_at = Instant::now();
+        let response = self.inner.call(request);
+
+        Box::pin(async move {
+            let response = response.await;
+
+            // A failed unary call has no messages, so the nodes send its status in the headers instead of the trailers.
+            let outcome = match &response {
+                Ok(response) => match Status::from_header_map(response.headers()) {
+                    Some(status) if status.code() != Code::Ok => TNRAppError::from(status).error_type.code(),
+                    _ => "ok",
+                },
+                Err(_) => TNRAppErrorType::UpstreamUnavailableError.code(),
+            };
+
+            METRICS.observe_upstream_outcome(&chain, "grpc", started_at, outcome);
+
+            response
+        })
+    }
+}
+
+/// Sends a request to a REST API, the errors are typed by the HTTP status of the response.
+async fn rest_request<T: DeserializeOwned>(request: RequestBuilder, url: &str) -> Result<T, TNRAppError> {
+    let res = request.send().await.map_err(|error| TNRAppError::from_request_error(url, error))?;
+    let status = res.status();
+
+    match res.json::<RestResponse<T>>().await {
+        Ok(RestResponse::Success(res_json)) => Ok(res_json),
+        Ok(RestResponse::Error { message, details: _ }) => Err(TNRAppError::from_upstream_status(status, message)),
+        Err(_) if !status.is_success() => Err(TNRAppError::from_upstream_status(status, format!("`{url}` responded with {status}."))),
+        Err(error) => Err(invalid_json_error(url, error)),
+    }
+}
+
+/// Returns the error of an RPC node response, typed by its JSON-RPC error code.
+fn rpc_error(error: RPCErrorResponseError) -> TNRAppError {
+    let error_type = match error.code {
+        -32602 => TNRAppErrorType::BadRequestError,
+        -32601 => TNRAppErrorType::UnsupportedError,
+        // The blocks after the latest one are requested with an internal error.
+        _ if error.data.contains("must be less than or equal to the current blockchain height") => TNRAppErrorType::NotFoundError,
+        _ => TNRAppErrorType::UpstreamUnavailableError,
+    };
+
+    TNRAppError::new(error_type, error.data)
+}
+
+/// Returns the error of a node response that cannot be parsed.
+fn invalid_json_error(url: &str, error: reqwest::Error) -> TNRAppError {
+    TNRAppError::new(
+        TNRAppErrorType::UpstreamUnavailableError,
+        format!("Cannot parse JSON.\nURL requested: {url}\nError Message:\n{error}"),
+    )
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -0,0 +1,243 @@
+use bech32::ToBase32;
+use futures::future::join_all;
+use mongodb::bson::doc;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::chain::Chain;
+use crate::database::{BlockForDb, ValidatorForDb};
+use crate::fetch::proposals::InternalProposal;
+use crate::fetch::transactions::TransactionItem;
+use crate::routes::{TNRAppError, TNRAppErrorType};
+use crate::utils::{bech32_address_prefix, is_hex};
+
+/// The kind of a search query. A query might be classified as more than one kind, like a number is both a height and a proposal ID.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchKind {
+    /// Eg: `4611328`
+    Height,
+    /// Eg: `4611328`
+    ProposalId,
+    /// Eg: `"7BF4A6A4A07D1F1B38EB0A2B6F1C42F96C3B6A8A5D2D86E4B6E8C1F0E4A7D2C1"`
+    TxHash,
+    /// Eg: `"7BF4A6A4A07D1F1B38EB0A2B6F1C42F96C3B6A8A5D2D86E4B6E8C1F0E4A7D2C1"`
+    BlockHash,
+    /// Eg: `"0x7bf4a6a4a07d1f1b38eb0a2b6f1c42f96c3b6a8a5d2d86e4b6e8c1f0e4a7d2c1"`
+    EvmTxHash,
+    /// Eg: `"0x9a1cb8a2d5cc1e6ee1dfa7f8e7d2e8b6f6a3e5a1"`
+    EvmAddress,
+    /// Eg: `"cosmos1c4k24jzduc365kywrsvf5ujz4ya6mwymy8vq4q"`
+    Account,
+    /// Eg: `"cosmosvaloper1c4k24jzduc365kywrsvf5ujz4ya6mwympnc4en"`
+    Validator,
+    /// Eg: `"cosmosvalcons1c4k24jzduc365kywrsvf5ujz4ya6mwymq7nmta"`
+    ValidatorConsensus,
+    /// Eg: `"E42125451E65AC3931726936026F295677DB5D07"`
+    ValidatorHex,
+}
+
+impl SearchKind {
+    /// Returns the kinds the query might be, using given bech32 base prefix of the chain.
+    pub fn classify(query: &str, base_prefix: &str) -> Vec<SearchKind> {
+        let query = query.trim();
+
+        if query.is_empty() {
+            return vec![];
+        }
+
+        if query.parse::<u64>().is_ok() {
+            return vec![SearchKind::Height, SearchKind::ProposalId];
+        }
+
+        if let Some(hex) = query.strip_prefix("0x") {
+            return match (is_hex(hex), hex.len()) {
+                (true, 64) => vec![SearchKind::EvmTxHash],
+                (true, 40) => vec![SearchKind::EvmAddress],
+                _ => vec![],
+            };
+        }
+
+        if is_hex(query) {
+            return match query.len() {
+                64 => vec![SearchKind::TxHash, SearchKind::BlockHash],
+                40 => vec![SearchKind::ValidatorHex],
+                _ => vec![],
+            };
+        }
+
+        match bech32_address_prefix(query) {
+            Some(hrp) if hrp == base_prefix => vec![SearchKind::Account],
+            Some(hrp) if hrp == format!("{base_prefix}valoper") => vec![SearchKind::Validator],
+            Some(hrp) if hrp == format!("{base_prefix}valcons") => vec![SearchKind::ValidatorConsensus],
+            _ => vec![],
+        }
+    }
+}
+
+impl Chain {
+    /// Classifies the query and returns the items matching it.
+    /// The items are looked up in the database first, and requested from the node if they are not saved.
+    pub async fn search(&self, query: &str) -> Result<SearchResponse, TNRAppError> {
+        let query = query.trim();
+        let kinds = SearchKind::classify(query, &self.config.base_prefix);
+
+        let results = join_all(kinds.iter().map(|kind| self.search_by_kind(query, *kind)))
+            .await
+            .into_iter()
+            .filter_map(Result::ok)
+            .collect();
+
+        Ok(SearchResponse {
+            chain: self.config.name.clone(),
+            query: query.to_string(),
+            kinds,
+            results,
+        })
+    }
+
+    /// Returns the item matching the query of given kind.
+    async fn search_by_kind(&self, query: &str, kind: SearchKind) -> Result<SearchResultItem, TNRAppError> {
+        match kind {
+            SearchKind::Height => {
+                let height = query
+                    .parse::<u64>()
+                    .map_err(|e| TNRAppError::new(TNRAppErrorType::BadRequestError, format!("Invalid height {query}: {e}")))?;
+
+                let block = match self.database.find_block(doc! {"height": height as i64}).await {
+                    Ok(block) => block,
+                    Err(_) => self.get_block_for_db_by_height(height).await?,
+                };
+
+                Ok(SearchResultItem::Block(block))
+            }
+            SearchKind::BlockHash => {
+                let hash = query.to_uppercase();
+
+                let block = match self.database.find_block(doc! {"hash": &hash}).await {
+                    Ok(block) => block,
+                    Err(_) => {
+                        let height = self.get_block_by_hash(&hash).await?.value.height;
+                        self.get_block_for_db_by_height(height).await?
+                    }
+                };
+
+                Ok(SearchResultItem::Block(block))
+            }
+            SearchKind::TxHash | SearchKind::EvmTxHash => {
+                let tx = self.get_stored_tx_by_hash(query).await?.value;
+
+                Ok(SearchResultItem::Transaction(TransactionItem::from(tx)))
+            }
+            SearchKind::ProposalId => {
+                let proposal_id = query
+                    .parse::<u64>()
+                    .map_err(|e| TNRAppError::new(TNRAppErrorType::BadRequestError, format!("Invalid proposal ID {query}: {e}")))?;
+
+                Ok(SearchResultItem::Proposal(self.get_proposal_details(proposal_id).await?))
+            }
+            SearchKind::Validator => Ok(SearchResultItem::Validator(self.database.find_validator_by_operator_addr(query).await?)),
+            SearchKind::ValidatorConsensus => Ok(SearchResultItem::Validator(
+                self.database.find_validator(doc! {"consensus_address": query}).await?,
+            )),
+            SearchKind::ValidatorHex => Ok(SearchResultItem::Validator(self.database.find_validator_by_hex_addr(query).await?)),
+            SearchKind::Account => Ok(SearchResultItem::Account(SearchAccountItem {
+                address: query.to_string(),
+                evm_address: None,
+            })),
+            SearchKind::EvmAddress => {
+                // EVM addresses are the same bytes with the account addresses on the chains using Ethereum keys.
+                let bytes = hex::decode(&query[2..])
+                    .map_err(|e| TNRAppError::new(TNRAppErrorType::BadRequestError, format!("Invalid EVM address {query}: {e}")))?;
+                let address = bech32::encode(&self.config.base_prefix, bytes.to_base32(), bech32::Variant::Bech32)
+                    .map_err(|e| TNRAppError::new(TNRAppErrorType::BadRequestError, format!("Cannot convert EVM address {query}: {e}")))?;
+
+                Ok(SearchResultItem::Account(SearchAccountItem {
+                    address,
+                    evm_address: Some(query.to_lowercase()),
+                }))
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SearchResponse {
+    pub chain: String,
+    pub query: String,
+    /// The kinds the query is classified as.
+    pub kinds: Vec<SearchKind>,
+    /// The items found, at most one for each kind.
+    #[schema(value_type = Vec<Object>)]
+    pub results: Vec<SearchResultItem>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum SearchResultItem {
+    Block(BlockForDb),
+    Transaction(TransactionItem),
+    Account(SearchAccountItem),
+    Validator(ValidatorForDb),
+    Proposal(InternalProposal),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SearchAccountItem {
+    /// Bech32 encoded account address. Eg: `"evmos1nh5mzgqs0g9z34z6ggmm2rqsm0h9xgj8f7rwu3"`
+    pub address: String,
+    /// The searched EVM address, if the account is searched by it. Eg: `"0x9de9b12010a0a2a8d5a42237b50c10dbee532247"`
+    pub evm_address: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a bech32 address of 20 bytes with given prefix.
+    fn address(prefix: &str) -> String {
+        bech32::encode(prefix, [7u8; 20].to_base32(), bech32::Variant::Bech32).unwrap()
+    }
+
+    #[test]
+    fn classify_numbers_as_heights_and_proposal_ids() {
+        assert_eq!(SearchKind::classify("4611328", "axelar"), [SearchKind::Height, SearchKind::ProposalId]);
+        assert_eq!(SearchKind::classify(" 12 ", "axelar"), [SearchKind::Height, SearchKind::ProposalId]);
+        assert!(SearchKind::classify("", "axelar").is_empty());
+    }
+
+    #[test]
+    fn classify_hex_by_length() {
+        let hash = "7BF4A6A4A07D1F1B38EB0A2B6F1C42F96C3B6A8A5D2D86E4B6E8C1F0E4A7D2C1";
+
+        assert_eq!(SearchKind::classify(hash, "axelar"), [SearchKind::TxHash, SearchKind::BlockHash]);
+        assert_eq!(
+            SearchKind::classify(&hash.to_lowercase(), "axelar"),
+            [SearchKind::TxHash, SearchKind::BlockHash]
+        );
+        assert_eq!(SearchKind::classify(&hash[..40], "axelar"), [SearchKind::ValidatorHex]);
+        assert!(SearchKind::classify(&hash[..50], "axelar").is_empty());
+    }
+
+    #[test]
+    fn classify_0x_prefixed_hex_as_evm() {
+        let hash = "0x7bf4a6a4a07d1f1b38eb0a2b6f1c42f96c3b6a8a5d2d86e4b6e8c1f0e4a7d2c1";
+
+        assert_eq!(SearchKind::classify(hash, "evmos"), [SearchKind::EvmTxHash]);
+        assert_eq!(SearchKind::classify(&hash[..42], "evmos"), [SearchKind::EvmAddress]);
+        assert!(SearchKind::classify("0x7bf4zz", "evmos").is_empty());
+        assert!(SearchKind::classify("0x", "evmos").is_empty());
+    }
+
+    #[test]
+    fn classify_bech32_addresses_by_prefix() {
+        assert_eq!(SearchKind::classify(&address("axelar"), "axelar"), [SearchKind::Account]);
+        assert_eq!(SearchKind::classify(&address("axelarvaloper"), "axelar"), [SearchKind::Validator]);
+        assert_eq!(
+            SearchKind::classify(&address("axelarvalcons"), "axelar"),
+            [SearchKind::ValidatorConsensus]
+        );
+        assert!(SearchKind::classify(&address("cosmos"), "axelar").is_empty());
+        assert!(SearchKind::classify("axelar1invalid", "axelar").is_empty());
+    }
+}
@@ -1,37 +1,64 @@
-use crate::fetch::chain_socket::tx::{parse_transaction, ExtraTxEventData};
+use crate::fetch::chain_socket::tx::{parse_transaction, ExtraTxEventData, TXMap};
 use crate::fetch::chain_socket::EvmPollBlockInfo;
-use crate::utils::Base64Convert;
-use std::sync::Arc;
+use crate::utils::{convert_tx_bytes_to_hex, Base64Convert};
 
 use chrono::DateTime;
 use futures::stream::select;
 use futures::StreamExt;
-use mongodb::bson::doc;
 use serde::{Deserialize, Serialize};
 use tendermint::block::commit_sig::CommitSig;
+use tendermint::block::Height;
 use tendermint::{Block, Time};
 use tendermint_rpc::event::EventData;
 use tendermint_rpc::query::EventType;
-use tendermint_rpc::{SubscriptionClient, WebSocketClient};
-use tokio::sync::broadcast::Sender;
-use tokio::sync::Mutex;
+use tendermint_rpc::{Client, SubscriptionClient, WebSocketClient};
+use tokio::task::JoinHandle;
 
 use crate::chain::Chain;
-use crate::database::BlockForDb;
-use crate::events::WsEvent;
+use crate::database::{BlockEventForDb, BlockEventPhaseForDb, BlockForDb, CheckpointForDb};
 use crate::fetch::blocks::CosmosEvent;
+use crate::fetch::transactions::TransactionItem;
+use crate::ingest::IngestPipeline;
 
 use super::blocks::{BlockLastCommitSignatures, CosmosEventAttribute};
 use super::evm_socket_handler::EvmSocketHandler;
 
+/// The name of the checkpoint that keeps the last block height fully ingested by the socket.
+const SOCKET_CHECKPOINT: &str = "socket";
+
+/// The maximum count of blocks replayed after a reconnect. Older blocks are left to the blocks backfill cron job.
+const SOCKET_REPLAY_LIMIT: u64 = 1000;
+
+/// The state kept between the events of a single subscription.
+#[derive(Default)]
+struct SocketState {
+    /// A block is saved when the next one arrives, since its signatures are committed in the next block.
+    previous_block: Option<Block>,
+    /// The task saving the begin and end block events of the previous block, the block is only checkpointed once they are saved.
+    previous_block_events: Option<JoinHandle<Result<(), String>>>,
+    heartbeat_begin_height: u64,
+}
+
+/// Aborts the task when it is dropped.
+struct AbortOnDrop<T>(JoinHandle<T>);
+
+impl<T> Drop for AbortOnDrop<T> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
 impl Chain {
-    pub async fn subscribe_events(&self, tx: Sender<(String, WsEvent)>) -> Result<(), String> {
+    /// Subscribes to the new blocks and transactions of the chain, and passes them to the given ingestion pipeline.
+    pub async fn subscribe_events(&self, pipeline: IngestPipeline) -> Result<(), String> {
         let (client, driver) = WebSocketClient::new(self.config.wss_url.as_str())
             .await
             .map_err(|e| format!("Failed to connect to the websocket endpoint: {e}"))?;
 
-        tokio::spawn(async move { driver.run().await });
+        // The driver is stopped with the subscription, also when the task of the subscription is aborted.
+        let _driver = AbortOnDrop(tokio::spawn(async move { driver.run().await }));
 
+        // Subscriptions are made before the replay, so the events produced during the replay are not lost.
         let txs = client
             .subscribe(EventType::Tx.into())
             .await
@@ -44,17 +71,14 @@ impl Chain {
 
         let mut bundled = select(txs, blocks);
 
-        let previous_block_header_resp: Arc<Mutex<Option<Block>>> = Arc::new(Mutex::new(None));
+        let mut state = SocketState::default();
 
-        let mut heartbeat_begin_height: u64 = 0;
+        let replayed_height = self.replay_missed_blocks(&client, &mut state, &pipeline).await?;
 
         while let Some(ev) = bundled.next().await {
-            let Ok(ev) = ev else {
-                continue
-            };
+            let Ok(ev) = ev else { continue };
 
-            let events = ev.events.clone().unwrap();
-            let handler = EvmSocketHandler::new(self.clone(), tx.clone());
+            self.health.record_event();
 
             match ev.data {
                 EventData::NewBlock {
@@ -62,168 +86,314 @@ impl Chain {
                     result_begin_block,
                     result_end_block,
                 } => {
-                    let (Some(block), Some(_), Some(result_end_block)) = (block, result_begin_block, result_end_block) else {
-                        continue
+                    let (Some(block), Some(result_begin_block), Some(result_end_block)) = (block, result_begin_block, result_end_block) else {
+                        continue;
                     };
+
+                    if block.header.height.value() <= replayed_height {
+                        continue;
+                    }
+
                     tracing::info!("wss: new block on {}", self.config.name);
 
-                    if vec![String::from("axelar"), String::from("axelar-testnet")].contains(&self.config.name) {
-                        let is_hearbeat_begin = result_end_block.clone().events.iter().any(|e| e.kind == "heartbeat");
-                        let current_height = block.header.height.value();
-
-                        let handler_params = HeartbeatStateParams::from_ws_block(current_height, is_hearbeat_begin, &mut heartbeat_begin_height);
-
-                        handler.heartbeat_handler(handler_params).await;
-
-                        let evm_poll_block_info = EvmPollBlockInfo {
-                            events: result_end_block
-                                .events
-                                .into_iter()
-                                .map(|e| {
-                                    let attributes = e
-                                        .attributes
-                                        .into_iter()
-                                        .map(|a| {
-                                            let key = String::base64_to_string(&a.key);
-                                            let value = String::base64_to_string(&a.value);
-                                            let index = a.index;
-
-                                            CosmosEventAttribute { key, value, index }
-                                        })
-                                        .collect();
-
-                                    CosmosEvent { r#type: e.kind, attributes }
-                                })
-                                .collect::<Vec<CosmosEvent>>(),
-                        };
+                    let begin_block_events = to_cosmos_events(result_begin_block.events);
+                    let end_block_events = to_cosmos_events(result_end_block.events);
+
+                    self.handle_new_block(block, begin_block_events, end_block_events, &mut state, &pipeline)
+                        .await?;
+                }
+                EventData::Tx { .. } => {
+                    let Some(events) = ev.events else { continue };
 
-                        handler.new_evm_poll_from_block(evm_poll_block_info).await;
+                    let is_replayed = events
+                        .get("tx.height")
+                        .and_then(|heights| heights.first())
+                        .and_then(|height| height.parse::<u64>().ok())
+                        .map_or(false, |height| height <= replayed_height);
+
+                    if is_replayed {
+                        continue;
                     }
 
-                    let mut mutex_previous_resp = previous_block_header_resp.lock().await;
-                    match mutex_previous_resp.as_ref() {
-                        Some(previous_resp) => {
-                            let hex_res = hex::encode(previous_resp.header.proposer_address.as_bytes());
-
-                            let proposer_metadata = self
-                                .database
-                                .find_validator_by_hex_addr(&hex_res)
-                                .await
-                                .map_err(|e| format!("block+ error: {e}"))?;
-
-                            let prev_header = &previous_resp.header;
-                            let current_heder = &block.header;
-                            let signatures: Vec<BlockLastCommitSignatures> = block.last_commit().as_ref().map_or_else(Vec::new, |c| {
-                                c.signatures
-                                    .iter()
-                                    .map(|cs| {
-                                        let (block_id_flag, validator_address, timestamp, signature) = match cs {
-                                            CommitSig::BlockIdFlagAbsent => (0, String::from(""), Time::now().to_rfc3339(), None),
-                                            CommitSig::BlockIdFlagCommit {
-                                                validator_address,
-                                                timestamp,
-                                                signature,
-                                            } => (
-                                                1,
-                                                validator_address.to_string(),
-                                                timestamp.to_rfc3339(),
-                                                signature.as_ref().map(|s| base64::encode(s.as_bytes())),
-                                            ),
-                                            CommitSig::BlockIdFlagNil {
-                                                validator_address,
-                                                timestamp,
-                                                signature,
-                                            } => (
-                                                2,
-                                                validator_address.to_string(),
-                                                timestamp.to_rfc3339(),
-                                                signature.as_ref().map(|s| base64::encode(s.as_bytes())),
-                                            ),
-                                        };
-
-                                        BlockLastCommitSignatures {
-                                            block_id_flag,
-                                            validator_address,
-                                            timestamp,
-                                            signature,
-                                        }
-                                    })
-                                    .collect::<Vec<BlockLastCommitSignatures>>()
-                            });
-
-                            let block_item = BlockForDb {
-                                hash: current_heder.last_block_id.map(|id| id.hash.to_string()).unwrap_or_default(),
-                                height: prev_header.height.value(),
-                                timestamp: DateTime::parse_from_rfc3339(&prev_header.time.to_rfc3339())
-                                    .map(|dt| dt.timestamp_millis())
-                                    .unwrap_or_default(),
-                                tx_count: previous_resp.data.len() as u64,
-                                proposer_logo_url: proposer_metadata.logo_url,
-                                proposer_name: proposer_metadata.name,
-                                proposer_address: proposer_metadata.operator_address,
-                                signatures,
-                            };
-
-                            tx.send((self.config.name.clone(), WsEvent::NewBLock(block_item.clone()))).ok();
-
-                            let self_clone = self.clone();
-                            tokio::spawn(async move {
-                                if let Err(e) = self_clone.database.upsert_block(block_item).await {
-                                    tracing::error!("Error saving block to the database: {e} ")
-                                }
-                            });
-
-                            *mutex_previous_resp = Some(block);
-                        }
-                        None => *mutex_previous_resp = Some(block),
-                    };
+                    self.handle_new_tx(events, &pipeline);
                 }
-                EventData::Tx { .. } => {
-                    let Ok((base, extra)) = parse_transaction(events) else {
-                        continue
-                    };
-                    tracing::info!("wss: new tx on {}", self.config.name);
-
-                    //All Tx Flow
-                    let chain = self.clone();
-                    let tx_sender_clone = tx.clone();
-                    tokio::spawn(async move {
-                        if let Ok(tx_item) = base.clone().as_tx_item(&chain).await {
-                            tx_sender_clone.send((chain.config.name.clone(), WsEvent::NewTX(tx_item.clone()))).ok();
-                            let _ = chain.database.add_transaction(tx_item.into()).await;
+                EventData::GenericJsonEvent(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Replays the blocks and the transactions missed while the socket was disconnected, using the same handlers with the live events.
+    /// Returns the last replayed height, or `0` if nothing is replayed.
+    async fn replay_missed_blocks(&self, client: &WebSocketClient, state: &mut SocketState, pipeline: &IngestPipeline) -> Result<u64, String> {
+        let Some(checkpoint) = self.database.find_checkpoint(SOCKET_CHECKPOINT).await? else {
+            return Ok(0);
+        };
+
+        let latest_height = self.get_node_sync_info().await.map_err(|e| e.to_string())?.latest_block_height;
+
+        let from = checkpoint.height.max(latest_height.saturating_sub(SOCKET_REPLAY_LIMIT));
+
+        if from >= latest_height {
+            return Ok(0);
+        }
+
+        tracing::info!("wss: replaying blocks between {} and {latest_height} on {}", from + 1, self.config.name);
+
+        state.previous_block = Some(get_socket_block(client, from).await?);
+
+        for height in (from + 1)..=latest_height {
+            let block = get_socket_block(client, height).await?;
+            let block_result = self.get_block_result_by_height(Some(height)).await.map_err(|e| e.to_string())?.value;
+
+            let raw_txs = block.data.clone();
+
+            self.handle_new_block(block, block_result.begin_block_events, block_result.end_block_events, state, pipeline)
+                .await?;
+
+            // The same keys with the websocket tx events are built from the tx results.
+            for (raw_tx, tx_result) in raw_txs.iter().zip(block_result.txs_results) {
+                let mut events = TXMap::new();
+
+                events.insert("tx.hash".to_string(), vec![convert_tx_bytes_to_hex(raw_tx)]);
+                events.insert("tx.height".to_string(), vec![height.to_string()]);
+
+                for event in tx_result.events {
+                    for attribute in event.attributes {
+                        events
+                            .entry(format!("{}.{}", event.r#type, attribute.key))
+                            .or_default()
+                            .push(attribute.value);
+                    }
+                }
+
+                self.handle_new_tx(events, pipeline);
+            }
+        }
+
+        Ok(latest_height)
+    }
+
+    /// Handles a new block. The previous block is saved, as its signatures are committed in the new one.
+    /// The begin and end block events are saved for the new block, as they are final already.
+    async fn handle_new_block(
+        &self,
+        block: Block,
+        begin_block_events: Vec<CosmosEvent>,
+        end_block_events: Vec<CosmosEvent>,
+        state: &mut SocketState,
+        pipeline: &IngestPipeline,
+    ) -> Result<(), String> {
+        let current_height = block.header.height.value();
+
+        let mut block_events = BlockEventForDb::from_cosmos_events(current_height, BlockEventPhaseForDb::Begin, begin_block_events);
+        block_events.extend(BlockEventForDb::from_cosmos_events(
+            current_height,
+            BlockEventPhaseForDb::End,
+            end_block_events.clone(),
+        ));
+
+        let pipeline_clone = pipeline.clone();
+        let block_events_task = tokio::spawn(async move { pipeline_clone.on_block_events(current_height, &block_events).await });
+        let previous_block_events = state.previous_block_events.replace(block_events_task);
+
+        if vec![String::from("axelar"), String::from("axelar-testnet")].contains(&self.config.name) {
+            let handler = EvmSocketHandler::new(self.clone(), pipeline.clone());
+
+            let is_hearbeat_begin = end_block_events.iter().any(|e| e.r#type == "heartbeat");
+            let handler_params = HeartbeatStateParams::from_ws_block(current_height, is_hearbeat_begin, &mut state.heartbeat_begin_height);
+
+            handler.heartbeat_handler(handler_params).await;
+
+            let evm_poll_block_info = EvmPollBlockInfo { events: end_block_events };
+
+            handler.new_evm_poll_from_block(evm_poll_block_info).await;
+        }
+
+        if let Some(previous_resp) = state.previous_block.as_ref() {
+            let hex_res = hex::encode(previous_resp.header.proposer_address.as_bytes());
+
+            let proposer_metadata = self
+                .database
+                .find_validator_by_hex_addr(&hex_res)
+                .await
+                .map_err(|e| format!("block+ error: {e}"))?;
+
+            let prev_header = &previous_resp.header;
+            let current_heder = &block.header;
+            let signatures: Vec<BlockLastCommitSignatures> = block.last_commit().as_ref().map_or_else(Vec::new, |c| {
+                c.signatures
+                    .iter()
+                    .map(|cs| {
+                        let (block_id_flag, validator_address, timestamp, signature) = match cs {
+                            CommitSig::BlockIdFlagAbsent => (0, String::from(""), Time::now().to_rfc3339(), None),
+                            CommitSig::BlockIdFlagCommit {
+                                validator_address,
+                                timestamp,
+                                signature,
+                            } => (
+                                1,
+                                validator_address.to_string(),
+                                timestamp.to_rfc3339(),
+                                signature.as_ref().map(|s| base64::encode(s.as_bytes())),
+                            ),
+                            CommitSig::BlockIdFlagNil {
+                                validator_address,
+                                timestamp,
+                                signature,
+                            } => (
+                                2,
+                                validator_address.to_string(),
+                                timestamp.to_rfc3339(),
+                                signature.as_ref().map(|s| base64::encode(s.as_bytes())),
+                            ),
                         };
-                    });
-
-                    //Axelar tx flow
-                    if vec![String::from("axelar"), String::from("axelar-testnet")].contains(&self.config.name) {
-                        if let Some(extra_data) = extra {
-                            match extra_data {
-                                ExtraTxEventData::NewPoll(p) => {
-                                    tokio::spawn(async move {
-                                        handler.new_evm_poll_from_tx(p).await;
-                                    });
-                                }
-                                ExtraTxEventData::PollVote(v) => {
-                                    tokio::spawn(async move {
-                                        handler.evm_poll_status_handler(v).await;
-                                    });
-                                }
-                                ExtraTxEventData::NewProposalVote(np) => {
-                                    tokio::spawn(async move {
-                                        handler.new_proposal_vote(np).await;
-                                    });
-                                }
-                            }
+
+                        BlockLastCommitSignatures {
+                            block_id_flag,
+                            validator_address,
+                            timestamp,
+                            signature,
                         }
+                    })
+                    .collect::<Vec<BlockLastCommitSignatures>>()
+            });
+
+            let block_item = BlockForDb {
+                hash: current_heder.last_block_id.map(|id| id.hash.to_string()).unwrap_or_default(),
+                height: prev_header.height.value(),
+                timestamp: DateTime::parse_from_rfc3339(&prev_header.time.to_rfc3339())
+                    .map(|dt| dt.timestamp_millis())
+                    .unwrap_or_default(),
+                tx_count: previous_resp.data.len() as u64,
+                proposer_logo_url: proposer_metadata.logo_url,
+                proposer_name: proposer_metadata.name,
+                proposer_address: proposer_metadata.operator_address,
+                signatures,
+                validators_hash: prev_header.validators_hash.to_string(),
+            };
+
+            let self_clone = self.clone();
+            let pipeline = pipeline.clone();
+            tokio::spawn(async move {
+                let is_block_events_saved = match previous_block_events {
+                    Some(task) => matches!(task.await, Ok(Ok(()))),
+                    None => true,
+                };
+
+                // The checkpoint isn't moved if the block or its events aren't saved, so it is replayed after a reconnect.
+                if pipeline.on_block(&block_item).await.is_err() || !is_block_events_saved {
+                    return;
+                }
+
+                // All the txs of the previous block are received before the new block, so it is fully ingested.
+                let checkpoint = CheckpointForDb {
+                    name: SOCKET_CHECKPOINT.to_string(),
+                    height: block_item.height,
+                };
+
+                if let Err(e) = self_clone.database.upsert_checkpoint(checkpoint).await {
+                    tracing::error!("Error saving socket checkpoint to the database: {e} ")
+                }
+            });
+        }
+
+        state.previous_block = Some(block);
+
+        Ok(())
+    }
+
+    /// Handles a new transaction.
+    fn handle_new_tx(&self, events: TXMap, pipeline: &IngestPipeline) {
+        let Some(hash) = events.get("tx.hash").and_then(|hashes| hashes.first()).cloned() else {
+            return;
+        };
+
+        // Failed txs don't have message events, so they can't be parsed. They are only saved from the node response.
+        let parsed = parse_transaction(events).ok();
+        tracing::info!("wss: new tx on {}", self.config.name);
+
+        //All Tx Flow
+        let chain = self.clone();
+        let pipeline_clone = pipeline.clone();
+        let base = parsed.as_ref().map(|(base, _)| base.clone());
+        tokio::spawn(async move {
+            // The summary is built from the decoded transaction, and from the tx events only if the node doesn't return it.
+            let tx_item = match chain.save_tx_details(&hash).await {
+                Ok(tx_details) => Some(TransactionItem::from(tx_details)),
+                Err(e) => {
+                    tracing::error!("Error saving transaction details to the database: {e}");
+                    match base {
+                        Some(base) => base.as_tx_item(&chain).await.ok(),
+                        None => None,
+                    }
+                }
+            };
+
+            if let Some(tx_item) = tx_item {
+                let _ = pipeline_clone.on_tx(&tx_item).await;
+            };
+        });
+
+        //Axelar tx flow
+        if vec![String::from("axelar"), String::from("axelar-testnet")].contains(&self.config.name) {
+            if let Some((_, Some(extra_data))) = parsed {
+                let handler = EvmSocketHandler::new(self.clone(), pipeline.clone());
+                match extra_data {
+                    ExtraTxEventData::NewPoll(p) => {
+                        tokio::spawn(async move {
+                            handler.new_evm_poll_from_tx(p).await;
+                        });
+                    }
+                    ExtraTxEventData::PollVote(v) => {
+                        tokio::spawn(async move {
+                            handler.evm_poll_status_handler(v).await;
+                        });
+                    }
+                    ExtraTxEventData::NewProposalVote(np) => {
+                        tokio::spawn(async move {
+                            handler.new_proposal_vote(np).await;
+                        });
                     }
                 }
-                EventData::GenericJsonEvent(_) => {}
             }
         }
-        Ok(())
     }
 }
 
+/// Converts the ABCI events of a block to the format used by the block results.
+fn to_cosmos_events(events: Vec<tendermint::abci::Event>) -> Vec<CosmosEvent> {
+    events
+        .into_iter()
+        .map(|e| {
+            let attributes = e
+                .attributes
+                .into_iter()
+                .map(|a| {
+                    let key = String::base64_to_string(&a.key);
+                    let value = String::base64_to_string(&a.value);
+                    let index = a.index;
+
+                    CosmosEventAttribute { key, value, index }
+                })
+                .collect();
+
+            CosmosEvent { r#type: e.kind, attributes }
+        })
+        .collect()
+}
+
+/// Returns the block at given height from the websocket client.
+async fn get_socket_block(client: &WebSocketClient, height: u64) -> Result<Block, String> {
+    let height = Height::try_from(height).map_err(|e| format!("Invalid block height {height}: {e}"))?;
+
+    client
+        .block(height)
+        .await
+        .map(|resp| resp.block)
+        .map_err(|e| format!("Failed to fetch the block at {height}: {e}"))
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct HeartbeatStateParams {
     pub current_height: u64,
@@ -1,35 +1,28 @@
 use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 use tokio::join;
-use tonic::transport::Endpoint;
 
 use super::{
     amount_util::TnrDecimal,
     others::{DenomAmount, Pagination},
 };
 use crate::database::ListDbResult;
-use crate::routes::PaginationData;
+use crate::routes::{PaginationData, TNRAppError, TNRAppErrorType};
 use crate::utils::{bytes_to_dec, str_to_dec, val_address_to_bech32};
 use crate::{chain::Chain, routes::ChainAmountItem};
 
 impl Chain {
     /// Returns the total supply of all tokens.
-    pub async fn get_supply_of_all_tokens(&self, config: PaginationData) -> Result<ListDbResult<ChainAmountItem>, String> {
+    pub async fn get_supply_of_all_tokens(&self, config: PaginationData) -> Result<ListDbResult<ChainAmountItem>, TNRAppError> {
         use crate::fetch::cosmos::bank::v1beta1::{query_client::QueryClient, QueryTotalSupplyRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryTotalSupplyRequest {
             pagination: Some(config.into()),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
-            .total_supply(req)
-            .await
-            .map_err(|e| format!("{}", e))?
-            .into_inner();
+        let resp = QueryClient::new(channel).total_supply(req).await.map_err(TNRAppError::from)?.into_inner();
 
         let mut supplies = vec![];
 
@@ -44,12 +37,12 @@ impl Chain {
     }
 
     /// Returns the supply of given token.
-    pub async fn get_supply_by_denom(&self, denom: &str) -> Result<ChainAmountItem, String> {
+    pub async fn get_supply_by_denom(&self, denom: &str) -> Result<ChainAmountItem, TNRAppError> {
         use crate::fetch::cosmos::bank::v1beta1::{query_client::QueryClient, QuerySupplyOfRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
-        let mut client = QueryClient::connect(endpoint).await.unwrap();
+        let mut client = QueryClient::new(channel);
 
         let from_supply_list_chains = vec!["evmos", "umee", "quicksilver", "kyve", "c4e", "babylon-testnet"];
         if from_supply_list_chains.contains(&self.config.name.as_str()) {
@@ -60,7 +53,7 @@ impl Chain {
             let resp = client
                 .total_supply(req)
                 .await
-                .map_err(|e| format!("{}", e))?
+                .map_err(TNRAppError::from)?
                 .into_inner()
                 .supply
                 .iter()
@@ -73,12 +66,15 @@ impl Chain {
                 return Ok(supply);
             };
 
-            return Err("Token not found".to_string());
+            return Err(TNRAppError::new(
+                TNRAppErrorType::NotFoundError,
+                format!("No token is found with denom {denom}."),
+            ));
         };
 
         let req = QuerySupplyOfRequest { denom: denom.to_string() };
 
-        let resp = client.supply_of(req).await.map_err(|e| format!("{}", e))?.into_inner();
+        let resp = client.supply_of(req).await.map_err(TNRAppError::from)?.into_inner();
 
         let amount = resp.amount.unwrap();
         let supply = self.string_amount_parser(amount.amount, Some(amount.denom)).await?;
@@ -86,39 +82,35 @@ impl Chain {
         Ok(supply)
     }
 
-    pub async fn get_evm_supported_chains(&self) -> Result<Vec<String>, String> {
+    pub async fn get_evm_supported_chains(&self) -> Result<Vec<String>, TNRAppError> {
         use crate::fetch::axelar::evm::v1beta1::{query_service_client::QueryServiceClient, ChainsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = ChainsRequest { status: 0 };
 
-        let resp = QueryServiceClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryServiceClient::new(channel)
             .chains(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         Ok(resp.chains)
     }
 
-    pub async fn get_evm_chain_maintainers(&self, chain_name: &str) -> Result<Vec<String>, String> {
+    pub async fn get_evm_chain_maintainers(&self, chain_name: &str) -> Result<Vec<String>, TNRAppError> {
         use crate::fetch::axelar::nexus::v1beta1::{query_service_client::QueryServiceClient, ChainMaintainersRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = ChainMaintainersRequest {
             chain: chain_name.to_string(),
         };
 
-        let resp = QueryServiceClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryServiceClient::new(channel)
             .chain_maintainers(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let mut maintainers = vec![];
@@ -131,23 +123,21 @@ impl Chain {
         Ok(maintainers)
     }
     /// Returns the minting inflation rate of native coin of the chain.
-    pub async fn get_inflation_rate(&self) -> Result<f64, String> {
+    pub async fn get_inflation_rate(&self) -> Result<f64, TNRAppError> {
         let default_return_value = 0.0;
         let chain_name = self.config.name.as_str();
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let mut inflation = if ["evmos", "echelon"].contains(&chain_name) {
             use crate::fetch::evmos::inflation::v1::{query_client::QueryClient, QueryInflationRateRequest};
 
             let req = QueryInflationRateRequest {};
 
-            let resp = QueryClient::connect(endpoint.clone())
-                .await
-                .unwrap()
+            let resp = QueryClient::new(channel.clone())
                 .inflation_rate(req)
                 .await
-                .map_err(|e| format!("{}", e))?
+                .map_err(TNRAppError::from)?
                 .into_inner();
 
             let rate = str_to_dec(resp.inflation_rate.as_str());
@@ -173,12 +163,10 @@ impl Chain {
 
             let req = QueryInflationRequest {};
 
-            let resp = QueryClient::connect(endpoint.clone())
-                .await
-                .unwrap()
+            let resp = QueryClient::new(channel.clone())
                 .inflation(req)
                 .await
-                .map_err(|e| format!("{}", e))?
+                .map_err(TNRAppError::from)?
                 .into_inner();
 
             let rate = str_to_dec(resp.inflation.as_str());
@@ -188,12 +176,10 @@ impl Chain {
 
             let req = QueryInflationRequest {};
 
-            let resp = QueryClient::connect(endpoint.clone())
-                .await
-                .unwrap()
+            let resp = QueryClient::new(channel.clone())
                 .inflation(req)
                 .await
-                .map_err(|e| format!("{}", e))?
+                .map_err(TNRAppError::from)?
                 .into_inner();
 
             let rate = bytes_to_dec(resp.inflation);
@@ -209,12 +195,10 @@ impl Chain {
                 key: "ExternalChainVotingInflationRate".to_string(),
             };
 
-            let rate_string = QueryClient::connect(endpoint)
-                .await
-                .unwrap()
+            let rate_string = QueryClient::new(channel)
                 .params(req)
                 .await
-                .map_err(|e| format!("{}", e))?
+                .map_err(TNRAppError::from)?
                 .into_inner()
                 .param
                 .ok_or_else(|| "Missing param".to_string())?
@@ -236,11 +220,11 @@ impl Chain {
     }
 
     //Returns epoch provision
-    pub async fn get_epoch_provision(&self) -> Result<f64, String> {
+    pub async fn get_epoch_provision(&self) -> Result<f64, TNRAppError> {
         let default_return_value = 0.0;
         let chain_name = self.config.name.clone();
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let epoch_provision = match chain_name.as_str() {
             "evmos" => {
@@ -248,12 +232,10 @@ impl Chain {
 
                 let req = QueryEpochMintProvisionRequest {};
 
-                let resp = QueryClient::connect(endpoint)
-                    .await
-                    .unwrap()
+                let resp = QueryClient::new(channel)
                     .epoch_mint_provision(req)
                     .await
-                    .map_err(|e| format!("{}", e))?
+                    .map_err(TNRAppError::from)?
                     .into_inner();
 
                 let epoch_mint_provision_amount = resp.epoch_mint_provision.map_or_else(|| "0".to_string(), |e| e.amount);
@@ -274,7 +256,7 @@ impl Chain {
         Ok(epoch_provision)
     }
 
-    pub async fn get_mint_params(&self) -> Result<MintParamsResponse, String> {
+    pub async fn get_mint_params(&self) -> Result<MintParamsResponse, TNRAppError> {
         let chain_name = self.config.name.clone();
         let mint_params = self
             .rest_api_request::<MintParamsResponse>(&format!("/{chain_name}/mint/v1beta1/params"), &[])
@@ -283,7 +265,7 @@ impl Chain {
         Ok(mint_params)
     }
 
-    pub async fn get_distributor_param(&self) -> Result<DistributorParamResponse, String> {
+    pub async fn get_distributor_param(&self) -> Result<DistributorParamResponse, TNRAppError> {
         let chain_name = self.config.name.clone();
         let distributor_param = self
             .rest_api_request::<DistributorParamResponse>(&format!("/{chain_name}/distributor/v1beta1/params"), &[])
@@ -292,7 +274,7 @@ impl Chain {
         Ok(distributor_param)
     }
 
-    pub async fn get_share_param(&self) -> Result<TnrDecimal, String> {
+    pub async fn get_share_param(&self) -> Result<TnrDecimal, TNRAppError> {
         let distributor_param = self.get_distributor_param().await?;
         let mut share_param = TnrDecimal::from_f64(1.0).unwrap_or_default();
         if let Some(dp) = distributor_param
@@ -8,23 +8,27 @@ use futures::{
 use mongodb::bson::doc;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
+use utoipa::ToSchema;
 
 use crate::fetch::chain_socket::EvmPollVote;
 use crate::{
     chain::Chain,
-    routes::{calc_pages, OutRestResponse},
+    routes::{calc_pages, OutRestResponse, PaginationData, TNRAppError, TNRAppErrorType},
     utils::{get_msg_name, Base64Convert},
 };
-use crate::{database::TransactionForDb, routes::ChainAmountItem};
+use crate::{
+    database::{ListDbResult, TransactionForDb},
+    routes::ChainAmountItem,
+};
 
 use super::{
-    blocks::CosmosEvent,
+    blocks::{from_base64_events_opt, CosmosEvent},
     others::{DenomAmount, Pagination, PaginationConfig, PublicKey},
 };
 
 impl Chain {
     /// Returns transaction by given hash.
-    pub async fn get_tx_by_hash(&self, hash: &str) -> Result<OutRestResponse<InternalTransaction>, String> {
+    pub async fn get_tx_by_hash(&self, hash: &str) -> Result<OutRestResponse<InternalTransaction>, TNRAppError> {
         match self.config.name.as_str() {
             "evmos" => {
                 if hash.starts_with("0x") {
@@ -45,7 +49,7 @@ impl Chain {
                                 }
                             })
                         })
-                        .ok_or_else(|| format!("This transaction does not exist, {hash}."))?;
+                        .ok_or_else(|| TNRAppError::new(TNRAppErrorType::NotFoundError, format!("This transaction does not exist, {hash}.")))?;
 
                     Ok(OutRestResponse::new(tx, 0))
                 } else {
@@ -70,52 +74,120 @@ impl Chain {
         }
     }
 
-    /// Returns transactions with given sender.
-    pub async fn get_txs_by_sender(&self, sender_address: &str, config: PaginationConfig) -> Result<OutRestResponse<Vec<TransactionItem>>, String> {
-        let mut query = vec![];
+    /// Returns transaction by given hash from the database.
+    /// The node is requested if the transaction is not saved yet, and the returned transaction is saved for the next requests.
+    pub async fn get_stored_tx_by_hash(&self, hash: &str) -> Result<OutRestResponse<InternalTransaction>, TNRAppError> {
+        // EVM hashes are not saved, they are always resolved by the node.
+        if !hash.starts_with("0x") {
+            if let Ok(tx) = self.database.find_transaction_details_by_hash(&hash.to_uppercase()).await {
+                return Ok(OutRestResponse::new(tx, 0));
+            }
+        }
 
-        query.push(("events", format!("message.sender='{}'", sender_address)));
-        query.push(("pagination.reverse", format!("{}", config.is_reverse())));
-        query.push(("pagination.limit", format!("{}", config.get_limit())));
-        query.push(("pagination.count_total", "true".to_string()));
-        query.push(("pagination.offset", format!("{}", config.get_offset())));
+        let resp = self.get_tx_by_hash(hash).await?;
 
-        let resp = self.rest_api_request::<TxsResp>("/cosmos/tx/v1beta1/txs", &query).await?;
+        if let Err(e) = self.store_tx(&resp.value).await {
+            tracing::error!("Error saving transaction details to the database: {e}");
+        }
 
-        let mut txs = vec![];
+        Ok(resp)
+    }
 
-        for i in 0..resp.txs.len() {
-            let (tx, tx_response) = (
-                resp.txs
-                    .get(i)
-                    .ok_or_else(|| "The count of transactions and transaction responses aren't the same.".to_string())?,
-                resp.tx_responses
-                    .get(i)
-                    .ok_or_else(|| "The count of transactions and transaction responses aren't the same.".to_string())?,
-            );
+    /// Fetches the transaction with given hash from the node and saves it to the database.
+    /// The node might not index the transaction yet when its event is received, so the request is retried a few times.
+    pub async fn save_tx_details(&self, hash: &str) -> Result<InternalTransaction, TNRAppError> {
+        let max_retries = 3;
+        let mut retries = 0;
+
+        let tx = loop {
+            match self.get_tx_by_hash(hash).await {
+                Ok(resp) => break resp.value,
+                Err(_) if retries < max_retries => {
+                    retries += 1;
+                    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+                }
+                Err(e) => return Err(e),
+            }
+        };
 
-            txs.push(TransactionItem::new(tx, tx_response, self).await?)
-        }
+        self.store_tx(&tx).await?;
 
-        let pages = calc_pages(resp.pagination.unwrap_or(Pagination::default()), config)?;
+        Ok(tx)
+    }
 
-        Ok(OutRestResponse::new(txs, pages))
+    /// Saves the fully decoded transaction and indexes the addresses involved in it.
+    pub async fn store_tx(&self, tx: &InternalTransaction) -> Result<(), String> {
+        self.database.upsert_transaction_details(tx.clone()).await?;
+        self.database.upsert_address_txs(tx.to_address_txs()).await
     }
 
-    pub async fn get_internal_txs_by_sender_height(
+    /// Saves all the transactions at given height, including their summaries.
+    pub async fn store_txs_by_height(&self, height: u64) -> Result<(), TNRAppError> {
+        let limit = 100;
+        let mut page = 1;
+
+        loop {
+            let resp = self
+                .get_txs_by_height_detailed(Some(height), PaginationConfig::new().limit(limit).page(page))
+                .await?;
+
+            for tx in resp.value {
+                self.store_tx(&tx).await?;
+                let tx = TransactionForDb::from(TransactionItem::from(tx)).with_main_token(&self.config.main_symbol, &self.config.main_denom);
+                self.database.upsert_transaction(tx).await?;
+            }
+
+            if page >= resp.pages {
+                break;
+            }
+
+            page += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Returns transactions with given sender from the node, from the newest to the oldest.\
+    /// Only the transactions before `before_height` are returned if it is given, the newer ones are served from the address index.
+    pub async fn get_txs_by_sender(
         &self,
         sender_address: &str,
-        block_height: Option<u64>,
+        before_height: Option<u64>,
+        config: PaginationConfig,
+    ) -> Result<OutRestResponse<Vec<TransactionItem>>, TNRAppError> {
+        self.get_txs_by_event(format!("message.sender='{}'", sender_address), before_height, config)
+            .await
+    }
+
+    /// Returns transactions with given recipient from the node, from the newest to the oldest.\
+    /// Only the transactions before `before_height` are returned if it is given, the newer ones are served from the address index.
+    pub async fn get_txs_by_recipient(
+        &self,
+        recipient_address: &str,
+        before_height: Option<u64>,
         config: PaginationConfig,
-    ) -> Result<OutRestResponse<Vec<InternalTransaction>>, String> {
+    ) -> Result<OutRestResponse<Vec<TransactionItem>>, TNRAppError> {
+        self.get_txs_by_event(format!("transfer.recipient='{}'", recipient_address), before_height, config)
+            .await
+    }
+
+    /// Returns transactions matching given event from the node, from the newest to the oldest.
+    async fn get_txs_by_event(
+        &self,
+        event: String,
+        before_height: Option<u64>,
+        config: PaginationConfig,
+    ) -> Result<OutRestResponse<Vec<TransactionItem>>, TNRAppError> {
         let mut query = vec![];
 
-        if let Some(block_height) = block_height {
-            query.push(("events", format!("tx.height={}", block_height)));
-        };
+        query.push(("events", event));
 
-        query.push(("events", format!("message.sender='{}'", sender_address)));
-        query.push(("pagination.reverse", format!("{}", config.is_reverse())));
+        if let Some(before_height) = before_height {
+            // The node accepts the events with a single `=` only, so the range is written as inclusive.
+            query.push(("events", format!("tx.height<={}", before_height.saturating_sub(1))));
+        }
+
+        query.push(("order_by", "ORDER_BY_DESC".to_string()));
         query.push(("pagination.limit", format!("{}", config.get_limit())));
         query.push(("pagination.count_total", "true".to_string()));
         query.push(("pagination.offset", format!("{}", config.get_offset())));
@@ -128,15 +200,13 @@ impl Chain {
             let (tx, tx_response) = (
                 resp.txs
                     .get(i)
-                    .cloned()
                     .ok_or_else(|| "The count of transactions and transaction responses aren't the same.".to_string())?,
                 resp.tx_responses
                     .get(i)
-                    .cloned()
                     .ok_or_else(|| "The count of transactions and transaction responses aren't the same.".to_string())?,
             );
 
-            txs.push(InternalTransaction::new(tx, tx_response, self).await?)
+            txs.push(TransactionItem::new(tx, tx_response, self).await?)
         }
 
         let pages = calc_pages(resp.pagination.unwrap_or(Pagination::default()), config)?;
@@ -144,15 +214,19 @@ impl Chain {
         Ok(OutRestResponse::new(txs, pages))
     }
 
-    /// Returns transactions with given recipient.
-    pub async fn get_txs_by_recipient(
+    pub async fn get_internal_txs_by_sender_height(
         &self,
-        recipient_address: &str,
+        sender_address: &str,
+        block_height: Option<u64>,
         config: PaginationConfig,
-    ) -> Result<OutRestResponse<Vec<TransactionItem>>, String> {
+    ) -> Result<OutRestResponse<Vec<InternalTransaction>>, TNRAppError> {
         let mut query = vec![];
 
-        query.push(("events", format!("message.recipient='{}'", recipient_address)));
+        if let Some(block_height) = block_height {
+            query.push(("events", format!("tx.height={}", block_height)));
+        };
+
+        query.push(("events", format!("message.sender='{}'", sender_address)));
         query.push(("pagination.reverse", format!("{}", config.is_reverse())));
         query.push(("pagination.limit", format!("{}", config.get_limit())));
         query.push(("pagination.count_total", "true".to_string()));
@@ -166,13 +240,15 @@ impl Chain {
             let (tx, tx_response) = (
                 resp.txs
                     .get(i)
+                    .cloned()
                     .ok_or_else(|| "The count of transactions and transaction responses aren't the same.".to_string())?,
                 resp.tx_responses
                     .get(i)
+                    .cloned()
                     .ok_or_else(|| "The count of transactions and transaction responses aren't the same.".to_string())?,
             );
 
-            txs.push(TransactionItem::new(tx, tx_response, self).await?)
+            txs.push(InternalTransaction::new(tx, tx_response, self).await?)
         }
 
         let pages = calc_pages(resp.pagination.unwrap_or(Pagination::default()), config)?;
@@ -185,7 +261,7 @@ impl Chain {
         &self,
         block_height: Option<u64>,
         config: PaginationConfig,
-    ) -> Result<OutRestResponse<Vec<InternalTransaction>>, String> {
+    ) -> Result<OutRestResponse<Vec<InternalTransaction>>, TNRAppError> {
         let mut query = vec![];
 
         if let Some(block_height) = block_height {
@@ -220,21 +296,20 @@ impl Chain {
         Ok(OutRestResponse::new(txs, pages))
     }
 
-    /// Returns transactions at given height.
-    pub async fn get_txs_by_height(
-        &self,
-        block_height: Option<u64>,
-        config: PaginationConfig,
-    ) -> Result<OutRestResponse<Vec<TransactionItem>>, String> {
+    /// Returns transactions at given height, paginated by offset cursors.\
+    /// The transactions of the latest block are returned if the height is not specified.
+    pub async fn get_txs_by_height(&self, block_height: Option<u64>, config: PaginationData) -> Result<ListDbResult<TransactionItem>, TNRAppError> {
+        let (offset, limit) = config.to_offset_page(20)?;
+
         let mut query = vec![];
 
         if let Some(block_height) = block_height {
             query.push(("events", format!("tx.height={}", block_height)));
         };
-        query.push(("pagination.reverse", format!("{}", config.is_reverse())));
-        query.push(("pagination.limit", format!("{}", config.get_limit())));
+        query.push(("pagination.reverse", "false".to_string()));
+        query.push(("pagination.limit", format!("{}", limit)));
         query.push(("pagination.count_total", "true".to_string()));
-        query.push(("pagination.offset", format!("{}", config.get_offset())));
+        query.push(("pagination.offset", format!("{}", offset)));
 
         let resp = self.rest_api_request::<TxsResp>("/cosmos/tx/v1beta1/txs", &query).await?;
 
@@ -253,13 +328,16 @@ impl Chain {
             txs.push(TransactionItem::new(tx, tx_response, self).await?)
         }
 
-        let pages = calc_pages(resp.pagination.unwrap_or(Pagination::default()), config)?;
+        let total = resp.pagination.and_then(|pagination| pagination.total.parse::<u64>().ok());
 
-        Ok(OutRestResponse::new(txs, pages))
+        Ok(ListDbResult {
+            pagination: PaginationData::from_offset_page(offset, limit, txs.len() as u64, total),
+            data: txs,
+        })
     }
 
     /// Returns transactions from db.
-    pub async fn get_last_txs_from_db(&self, count: u16) -> Result<Vec<TransactionForDb>, String> {
+    pub async fn get_last_txs_from_db(&self, count: u16) -> Result<Vec<TransactionForDb>, TNRAppError> {
         let txs = self.database.find_last_count_transactions(None, count).await?;
 
         Ok(txs)
@@ -268,18 +346,20 @@ impl Chain {
     /// Returns the EVM TX response by given hash. Only works for Evmos chain.
     ///
     /// The hash must start with `"0x..."`.
-    async fn get_evm_tx_by_hash(&self, hash: &str) -> Result<InternalEvmTxResp, String> {
-        self.jsonrpc_request::<EvmTxResp>(format!(
-            r#"{{"method":"eth_getTransactionByHash","params":["{hash}"],"id":1,"jsonrpc":"2.0"}}"#
-        ))
-        .await?
-        .try_into()
+    async fn get_evm_tx_by_hash(&self, hash: &str) -> Result<InternalEvmTxResp, TNRAppError> {
+        let resp = self
+            .jsonrpc_request::<EvmTxResp>(format!(
+                r#"{{"method":"eth_getTransactionByHash","params":["{hash}"],"id":1,"jsonrpc":"2.0"}}"#
+            ))
+            .await?;
+
+        Ok(resp.try_into()?)
     }
     pub async fn get_axelar_sender_heartbeat_info(
         &self,
         val_voter_address: &String,
         block_height: u64,
-    ) -> Result<InternalAxelarHeartbeatInfo, String> {
+    ) -> Result<InternalAxelarHeartbeatInfo, TNRAppError> {
         match self
             .get_internal_txs_by_sender_height(val_voter_address, Some(block_height), PaginationConfig::new().limit(1).page(1))
             .await
@@ -291,7 +371,7 @@ impl Chain {
                     };
                 }
                 let message = String::from("This is not an heartbeat tx");
-                Err(message)
+                Err(message.into())
             }
             Err(e) => {
                 tracing::error!("Could not fetched txs by sender");
@@ -341,7 +421,7 @@ pub struct EvmTxResp {
     pub block_number: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct InternalTransaction {
     pub hash: String,
     pub r#type: String,
@@ -352,15 +432,22 @@ pub struct InternalTransaction {
     pub gas_wanted: u64,
     pub gas_used: u64,
     pub result: String,
+    pub code: u32,
+    pub codespace: String,
+    pub message_types: Vec<String>,
     pub memo: String,
     pub signatures: Vec<String>,
+    pub signers: Vec<String>,
+    /// The messages of the transaction, their fields depend on their types.
+    #[schema(value_type = Vec<Object>)]
     pub content: Vec<InternalTransactionContent>,
     pub logs: Vec<TxResponseLog>,
+    pub events: Vec<CosmosEvent>,
     pub raw: String,
 }
 
 impl InternalTransaction {
-    async fn new(tx: Tx, tx_response: TxResponse, chain: &Chain) -> Result<Self, String> {
+    async fn new(tx: Tx, tx_response: TxResponse, chain: &Chain) -> Result<Self, TNRAppError> {
         let mut jobs = vec![];
 
         let r#type = tx
@@ -409,11 +496,13 @@ impl InternalTransaction {
                 .await
             {
                 Ok(res) => res,
-                Err(err) => return Err(format!("Cannot parse transaction amount, '{}'.", err)),
+                Err(err) => return Err(format!("Cannot parse transaction amount, '{}'.", err).into()),
             },
             None => ChainAmountItem::default(),
         };
 
+        let message_types = tx.body.messages.iter().map(|msg| msg.get_type()).collect();
+
         for message in tx.body.messages {
             let logs = tx_response.logs.clone();
             jobs.push(async move { message.to_internal(chain, &Some(logs.clone())).await })
@@ -427,6 +516,8 @@ impl InternalTransaction {
             content.push(resp?)
         }
 
+        let signers = get_tx_signers(&tx_response);
+
         Ok(Self {
             hash: tx_response.txhash,
             height: tx_response
@@ -455,11 +546,14 @@ impl InternalTransaction {
                 .gas_used
                 .parse::<u64>()
                 .map_err(|_| format!("Cannot parse transaction gas used, '{}'.", tx_response.gas_used))?,
-            result: if tx_response.raw_log.starts_with('[') || tx_response.raw_log.starts_with('{') {
+            result: if tx_response.code == 0 {
                 "Success".to_string()
             } else {
                 "Failed".to_string()
             },
+            code: tx_response.code as u32,
+            codespace: tx_response.codespace,
+            message_types,
             signatures: match tx_response.tx {
                 TxsResponseTx::Tx { signatures, .. } => signatures,
             },
@@ -468,7 +562,9 @@ impl InternalTransaction {
             content,
             amount,
             r#type,
+            signers,
             logs: tx_response.logs,
+            events: tx_response.events.unwrap_or_default(),
         })
     }
     pub fn extract_axelar_heartbeat_info(&self) -> Option<InternalAxelarHeartbeatInfo> {
@@ -538,6 +634,37 @@ impl InternalTransaction {
     }
 }
 
+/// Returns the addresses that signed the transaction.
+/// `tx.acc_seq` event attributes hold the `address/sequence` pairs of the signers, `message.sender` attributes are used if they are missing.
+fn get_tx_signers(tx_response: &TxResponse) -> Vec<String> {
+    let mut signers: Vec<String> = tx_response
+        .events
+        .iter()
+        .flatten()
+        .filter(|event| event.r#type == "tx")
+        .flat_map(|event| event.attributes.iter())
+        .filter(|attribute| attribute.key == "acc_seq")
+        .filter_map(|attribute| attribute.value.split_once('/').map(|(address, _)| address.to_string()))
+        .collect();
+
+    if signers.is_empty() {
+        signers = tx_response
+            .logs
+            .iter()
+            .flat_map(|log| log.events.iter())
+            .filter(|event| event.r#type == "message")
+            .flat_map(|event| event.attributes.iter())
+            .filter(|attribute| attribute.key == "sender")
+            .map(|attribute| attribute.value.clone())
+            .collect();
+    }
+
+    signers.sort();
+    signers.dedup();
+
+    signers
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct InternalAxelarHeartbeatInfo {
     pub sender: String,
@@ -548,15 +675,23 @@ pub struct InternalAxelarHeartbeatInfo {
     pub timestamp: i64,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct TransactionItem {
     pub height: u64,
     #[serde(rename = "type")]
     pub tx_type: String,
+    /// The types of all the messages in the transaction.
+    pub message_types: Vec<String>,
     pub hash: String,
     pub amount: ChainAmountItem,
     pub fee: ChainAmountItem,
     pub result: String,
+    /// ABCI result code. It is `0` if the transaction succeeded.
+    pub code: u32,
+    /// ABCI result codespace. It is empty if the transaction succeeded.
+    pub codespace: String,
+    /// The raw log of the transaction, only if it failed.
+    pub raw_log: Option<String>,
     pub time: i64,
 }
 
@@ -566,14 +701,14 @@ pub struct KeyValue {
     pub value: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum InternalTransactionContent {
     Known(InternalTransactionContentKnowns),
     Unknown { r#type: String, keys_values: HashMap<String, String> },
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(tag = "type")]
 pub enum InternalTransactionContentKnowns {
     Exec {
@@ -742,17 +877,21 @@ impl From<InternalTransaction> for TransactionItem {
         Self {
             height: tx.height,
             tx_type: tx.r#type,
+            message_types: tx.message_types,
             hash: tx.hash,
             amount: tx.amount,
             fee: tx.fee,
             result: tx.result,
+            raw_log: if tx.code == 0 { None } else { Some(tx.raw) },
+            code: tx.code,
+            codespace: tx.codespace,
             time: tx.time,
         }
     }
 }
 
 impl TransactionItem {
-    async fn new(tx: &Tx, tx_response: &TxResponse, chain: &Chain) -> Result<Self, String> {
+    async fn new(tx: &Tx, tx_response: &TxResponse, chain: &Chain) -> Result<Self, TNRAppError> {
         let fee_amount = tx
             .auth_info
             .fee
@@ -801,7 +940,7 @@ impl TransactionItem {
                 .await
             {
                 Ok(res) => res,
-                Err(err) => return Err(format!("Cannot parse transaction amount, '{}'.", err)),
+                Err(err) => return Err(format!("Cannot parse transaction amount, '{}'.", err).into()),
             },
             None => ChainAmountItem::default(),
         };
@@ -817,14 +956,22 @@ impl TransactionItem {
                 .get(0)
                 .map(|msg| msg.get_type())
                 .ok_or_else(|| format!("There is no TX type, '{}'.", tx_response.txhash))?,
+            message_types: tx.body.messages.iter().map(|msg| msg.get_type()).collect(),
             hash: tx_response.txhash.to_string(),
             amount,
             fee,
-            result: if tx_response.raw_log.starts_with('[') || tx_response.raw_log.starts_with('{') {
+            result: if tx_response.code == 0 {
                 "Success".to_string()
             } else {
                 "Failed".to_string()
             },
+            code: tx_response.code as u32,
+            codespace: tx_response.codespace.clone(),
+            raw_log: if tx_response.code == 0 {
+                None
+            } else {
+                Some(tx_response.raw_log.clone())
+            },
             time: DateTime::parse_from_rfc3339(&tx_response.timestamp)
                 .map_err(|_| format!("Cannot parse transaction timestamp, '{}'.", tx_response.timestamp))?
                 .timestamp_millis(),
@@ -878,9 +1025,9 @@ impl TxsTransactionMessage {
         self,
         chain: &'a Chain,
         logs: &'a Option<Vec<TxResponseLog>>,
-    ) -> BoxFuture<'a, Result<InternalTransactionContent, String>> {
+    ) -> BoxFuture<'a, Result<InternalTransactionContent, TNRAppError>> {
         async move {
-            Ok::<_, String>(match self {
+            Ok::<_, TNRAppError>(match self {
                 TxsTransactionMessage::Known(message) => match message {
                     TxsTransactionMessageKnowns::Delegate {
                         delegator_address,
@@ -1327,38 +1474,45 @@ impl TxsTransactionMessage {
     /// Return the type of message.
     pub fn get_type(&self) -> String {
         match self {
-            TxsTransactionMessage::Known(msg) => match msg {
-                TxsTransactionMessageKnowns::Delegate { .. } => "Delegate",
-                TxsTransactionMessageKnowns::Redelegate { .. } => "Redelegate",
-                TxsTransactionMessageKnowns::Revoke { .. } => "Revoke",
-                TxsTransactionMessageKnowns::Send { .. } => "Send",
-                TxsTransactionMessageKnowns::Undelegate { .. } => "Undelegate",
-                TxsTransactionMessageKnowns::Vote { .. } => "Vote",
-                TxsTransactionMessageKnowns::WithdrawDelegatorReward { .. } => "Withdraw Delegator Rewards",
-                TxsTransactionMessageKnowns::WithdrawValidatorCommission { .. } => "Withdraw Validator Commission",
-                TxsTransactionMessageKnowns::EthereumTx { .. } => "Ethereum Tx",
-                TxsTransactionMessageKnowns::Grant { .. } => "Grant",
-                TxsTransactionMessageKnowns::Exec { .. } => "Exec",
-                TxsTransactionMessageKnowns::RegisterProxy { .. } => "RegisterProxy",
-                TxsTransactionMessageKnowns::IBCUpdateClient { .. } => "IBCUpdateClient",
-                TxsTransactionMessageKnowns::IBCReceived { .. } => "IBCReceived",
-                TxsTransactionMessageKnowns::IBCAcknowledgement { .. } => "IBCAcknowledgement",
-                TxsTransactionMessageKnowns::IBCTransfer { .. } => "IBCTransfer",
-                TxsTransactionMessageKnowns::SwapExactAmountIn { .. } => "SwapExactAmountIn",
-                TxsTransactionMessageKnowns::AxelarRegisterProxy { .. } => "RegisterProxy",
-                TxsTransactionMessageKnowns::AxelarRefundRequest { .. } => "AxelarRefundRequest",
-                TxsTransactionMessageKnowns::AxelarLinkRequest { .. } => "LinkRequest",
-                TxsTransactionMessageKnowns::AxelarConfirmDepositRequest { .. } => "ConfirmDepositRequest",
-                TxsTransactionMessageKnowns::AxelarCreatePendingTransfersRequest { .. } => "CreatePendingTransfersRequest",
-            }
-            .to_string(),
+            TxsTransactionMessage::Known(msg) => Self::get_type_by_type_url(msg.type_url()),
             TxsTransactionMessage::Unknown(keys_values) => keys_values
                 .get("@type")
-                .cloned()
-                .map(|r#type| get_msg_name(r#type.as_str().unwrap_or_else(|| "Unknown")))
+                .and_then(|r#type| r#type.as_str())
+                .map(Self::get_type_by_type_url)
                 .unwrap_or("Unknown".to_string()),
         }
     }
+
+    /// Returns the type of a message from its type URL, so the messages only known by their type URL have the same types.\
+    /// Eg: `"/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward"` is `"Withdraw Delegator Rewards"`
+    pub fn get_type_by_type_url(type_url: &str) -> String {
+        match type_url {
+            "/cosmos.staking.v1beta1.MsgDelegate" => "Delegate",
+            "/cosmos.staking.v1beta1.MsgBeginRedelegate" => "Redelegate",
+            "/cosmos.authz.v1beta1.MsgRevoke" => "Revoke",
+            "/cosmos.bank.v1beta1.MsgSend" => "Send",
+            "/cosmos.staking.v1beta1.MsgUndelegate" => "Undelegate",
+            "/cosmos.gov.v1beta1.MsgVote" => "Vote",
+            "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward" => "Withdraw Delegator Rewards",
+            "/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission" => "Withdraw Validator Commission",
+            "/ethermint.evm.v1.MsgEthereumTx" => "Ethereum Tx",
+            "/cosmos.authz.v1beta1.MsgGrant" => "Grant",
+            "/cosmos.authz.v1beta1.MsgExec" => "Exec",
+            "/snapshot.v1beta1.RegisterProxyRequest" => "RegisterProxy",
+            "/ibc.core.client.v1.MsgUpdateClient" => "IBCUpdateClient",
+            "/ibc.core.channel.v1.MsgRecvPacket" => "IBCReceived",
+            "/ibc.core.channel.v1.MsgAcknowledgement" => "IBCAcknowledgement",
+            "/ibc.applications.transfer.v1.MsgTransfer" => "IBCTransfer",
+            "/osmosis.gamm.v1beta1.MsgSwapExactAmountIn" => "SwapExactAmountIn",
+            "/axelar.snapshot.v1beta1.RegisterProxyRequest" => "RegisterProxy",
+            "/axelar.reward.v1beta1.RefundMsgRequest" => "AxelarRefundRequest",
+            "/axelar.axelarnet.v1beta1.LinkRequest" => "LinkRequest",
+            "/axelar.axelarnet.v1beta1.ConfirmDepositRequest" => "ConfirmDepositRequest",
+            "/axelar.evm.v1beta1.CreatePendingTransfersRequest" => "CreatePendingTransfersRequest",
+            type_url => return get_msg_name(type_url),
+        }
+        .to_string()
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -1527,6 +1681,36 @@ pub enum TxsTransactionMessageKnowns {
     AxelarCreatePendingTransfersRequest { chain: String, sender: String },
 }
 
+impl TxsTransactionMessageKnowns {
+    /// Returns the type URL of the message. Eg: `"/cosmos.bank.v1beta1.MsgSend"`
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            TxsTransactionMessageKnowns::Delegate { .. } => "/cosmos.staking.v1beta1.MsgDelegate",
+            TxsTransactionMessageKnowns::Redelegate { .. } => "/cosmos.staking.v1beta1.MsgBeginRedelegate",
+            TxsTransactionMessageKnowns::Revoke { .. } => "/cosmos.authz.v1beta1.MsgRevoke",
+            TxsTransactionMessageKnowns::Send { .. } => "/cosmos.bank.v1beta1.MsgSend",
+            TxsTransactionMessageKnowns::Undelegate { .. } => "/cosmos.staking.v1beta1.MsgUndelegate",
+            TxsTransactionMessageKnowns::Vote { .. } => "/cosmos.gov.v1beta1.MsgVote",
+            TxsTransactionMessageKnowns::WithdrawDelegatorReward { .. } => "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward",
+            TxsTransactionMessageKnowns::WithdrawValidatorCommission { .. } => "/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission",
+            TxsTransactionMessageKnowns::EthereumTx { .. } => "/ethermint.evm.v1.MsgEthereumTx",
+            TxsTransactionMessageKnowns::Grant { .. } => "/cosmos.authz.v1beta1.MsgGrant",
+            TxsTransactionMessageKnowns::Exec { .. } => "/cosmos.authz.v1beta1.MsgExec",
+            TxsTransactionMessageKnowns::RegisterProxy { .. } => "/snapshot.v1beta1.RegisterProxyRequest",
+            TxsTransactionMessageKnowns::IBCUpdateClient { .. } => "/ibc.core.client.v1.MsgUpdateClient",
+            TxsTransactionMessageKnowns::IBCReceived { .. } => "/ibc.core.channel.v1.MsgRecvPacket",
+            TxsTransactionMessageKnowns::IBCAcknowledgement { .. } => "/ibc.core.channel.v1.MsgAcknowledgement",
+            TxsTransactionMessageKnowns::IBCTransfer { .. } => "/ibc.applications.transfer.v1.MsgTransfer",
+            TxsTransactionMessageKnowns::SwapExactAmountIn { .. } => "/osmosis.gamm.v1beta1.MsgSwapExactAmountIn",
+            TxsTransactionMessageKnowns::AxelarRegisterProxy { .. } => "/axelar.snapshot.v1beta1.RegisterProxyRequest",
+            TxsTransactionMessageKnowns::AxelarRefundRequest { .. } => "/axelar.reward.v1beta1.RefundMsgRequest",
+            TxsTransactionMessageKnowns::AxelarLinkRequest { .. } => "/axelar.axelarnet.v1beta1.LinkRequest",
+            TxsTransactionMessageKnowns::AxelarConfirmDepositRequest { .. } => "/axelar.axelarnet.v1beta1.ConfirmDepositRequest",
+            TxsTransactionMessageKnowns::AxelarCreatePendingTransfersRequest { .. } => "/axelar.evm.v1beta1.CreatePendingTransfersRequest",
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum InnerMessage {
@@ -1643,10 +1827,11 @@ pub struct TxResponse {
     // Timestamp. Eg: `"2022-07-19T05:26:26Z"`
     pub timestamp: String,
     // Transaction events.
+    #[serde(default, deserialize_with = "from_base64_events_opt")]
     pub events: Option<Vec<CosmosEvent>>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct TxResponseLog {
     /// Array of events.
     pub log: String,
@@ -1654,7 +1839,7 @@ pub struct TxResponseLog {
     pub events: Vec<TxResponseEvent>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct TxResponseEvent {
     /// Event type. Eg: `"redelegate"`
     pub r#type: String,
@@ -1662,7 +1847,7 @@ pub struct TxResponseEvent {
     pub attributes: Vec<TxResponseEventAttribute>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct TxResponseEventAttribute {
     /// Event attribute key. Eg: `"completion_time"`
     pub key: String,
@@ -0,0 +1,342 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::chain::Chain;
+use crate::database::{BlockForDb, ValidatorSigningWindowForDb, SIGNING_WINDOW_SIZE};
+use crate::routes::{TNRAppError, TNRAppErrorType};
+
+/// The count of heights used when no range is given.
+const SIGNING_DEFAULT_RANGE: u64 = 10_000;
+
+/// The maximum count of heights the missed blocks can be listed for.
+const SIGNING_MISSED_BLOCKS_MAX_RANGE: u64 = 100_000;
+
+/// The maximum count of heights a heatmap can be requested for.
+const SIGNING_HEATMAP_MAX_RANGE: u64 = 1_000_000;
+
+/// The count of heatmap buckets used when no count is given.
+const SIGNING_HEATMAP_DEFAULT_BUCKETS: u64 = 100;
+
+/// The maximum count of heatmap buckets.
+const SIGNING_HEATMAP_MAX_BUCKETS: u64 = 1_000;
+
+/// The count of the validators requested at once from the RPC node, the maximum it allows.
+const VALIDATORS_PAGE_SIZE: u16 = 100;
+
+/// The count of the validator sets kept by [`ValidatorSetCache`]. The backfill reads older sets while the socket reads the latest one.
+const VALIDATOR_SET_CACHE_SIZE: usize = 16;
+
+impl Chain {
+    /// Saves whether the active validators signed the block to their signing bitmaps.
+    /// The active set at the height of the block is used, so the blocks saved by the backfill are recorded for the validators active then.
+    pub async fn store_block_signing(&self, block: &BlockForDb) -> Result<(), String> {
+        let active_addresses = self
+            .get_active_hex_addresses(block.height, &block.validators_hash)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // Only the signatures with the commit flag are counted as signed.
+        let signed_addresses: HashSet<String> = block
+            .signatures
+            .iter()
+            .filter(|signature| signature.block_id_flag == 1)
+            .map(|signature| signature.validator_address.to_ascii_uppercase())
+            .collect();
+
+        let validators = active_addresses
+            .iter()
+            .map(|hex_address| {
+                let missed = !signed_addresses.contains(hex_address);
+                (hex_address.clone(), missed)
+            })
+            .collect();
+
+        self.database.upsert_validator_signing(block.height, validators).await
+    }
+
+    /// Returns the uppercase hex addresses of the validators in the active set with given hash, at given height.\
+    /// The set is only requested from the RPC node when it changes, the blocks without a saved hash always request it.
+    async fn get_active_hex_addresses(&self, height: u64, validators_hash: &str) -> Result<Arc<Vec<String>>, TNRAppError> {
+        if let Some(hex_addresses) = self.validator_sets.get(validators_hash) {
+            return Ok(hex_addresses);
+        }
+
+        let hex_addresses = Arc::new(self.get_active_hex_addresses_at(height).await?);
+
+        if !validators_hash.is_empty() {
+            self.validator_sets.insert(validators_hash, hex_addresses.clone());
+        }
+
+        Ok(hex_addresses)
+    }
+
+    /// Returns the uppercase hex addresses of the validators in the active set at given height.
+    async fn get_active_hex_addresses_at(&self, height: u64) -> Result<Vec<String>, TNRAppError> {
+        let mut hex_addresses = vec![];
+        let mut page = 1;
+
+        loop {
+            let resp = self
+                .rpc_request::<ValidatorsResp>(
+                    "/validators",
+                    &[
+                        ("height", height.to_string()),
+                        ("page", page.to_string()),
+                        ("per_page", VALIDATORS_PAGE_SIZE.to_string()),
+                    ],
+                )
+                .await?;
+
+            let total: usize = resp
+                .total
+                .parse()
+                .map_err(|_| format!("Cannot parse validator count, '{}'.", resp.total))?;
+
+            let is_empty = resp.validators.is_empty();
+            hex_addresses.extend(resp.validators.into_iter().map(|validator| validator.address.to_ascii_uppercase()));
+
+            if is_empty || hex_addresses.len() >= total {
+                return Ok(hex_addresses);
+            }
+
+            page += 1;
+        }
+    }
+
+    /// Returns the missed blocks of a validator between given heights.
+    /// The last `10000` blocks are used if no range is given.
+    pub async fn get_validator_missed_blocks(
+        &self,
+        operator_address: &str,
+        from: Option<u64>,
+        to: Option<u64>,
+    ) -> Result<ValidatorMissedBlocks, TNRAppError> {
+        let (from, to) = self.get_signing_range(from, to, SIGNING_MISSED_BLOCKS_MAX_RANGE).await?;
+        let windows = self.get_signing_windows(operator_address, from, to).await?;
+
+        let (recorded_blocks, missed_blocks) = count_signing(&windows, from, to);
+
+        let missed_heights = windows
+            .iter()
+            .flat_map(|window| (window.window_start..window.window_start + SIGNING_WINDOW_SIZE).filter(|height| window.is_missed(*height)))
+            .filter(|height| (from..=to).contains(height))
+            .collect();
+
+        Ok(ValidatorMissedBlocks {
+            operator_address: operator_address.to_string(),
+            from_height: from,
+            to_height: to,
+            recorded_blocks,
+            missed_blocks,
+            missed_heights,
+        })
+    }
+
+    /// Returns the uptime of a validator over the last 24 hours, 7 days and 30 days.
+    /// A period is `None` if no saved block is that old.
+    pub async fn get_validator_uptime_periods(&self, operator_address: &str) -> Result<ValidatorUptimePeriods, TNRAppError> {
+        let (_, to) = self.get_signing_range(None, None, u64::MAX).await?;
+
+        Ok(ValidatorUptimePeriods {
+            last_24h: self.get_validator_uptime_period(operator_address, Duration::days(1), to).await?,
+            last_7d: self.get_validator_uptime_period(operator_address, Duration::days(7), to).await?,
+            last_30d: self.get_validator_uptime_period(operator_address, Duration::days(30), to).await?,
+        })
+    }
+
+    /// Returns the uptime of a validator over the given period that ends at given height.
+    async fn get_validator_uptime_period(
+        &self,
+        operator_address: &str,
+        period: Duration,
+        to: u64,
+    ) -> Result<Option<ValidatorUptimePeriod>, TNRAppError> {
+        let since = (Utc::now() - period).timestamp_millis();
+
+        let Some(from) = self.database.find_first_block_height_since(since).await?.filter(|from| *from <= to) else {
+            return Ok(None);
+        };
+
+        let windows = self.get_signing_windows(operator_address, from, to).await?;
+        let (recorded_blocks, missed_blocks) = count_signing(&windows, from, to);
+
+        Ok(Some(ValidatorUptimePeriod {
+            from_height: from,
+            to_height: to,
+            recorded_blocks,
+            missed_blocks,
+            uptime: uptime_of(recorded_blocks, missed_blocks),
+        }))
+    }
+
+    /// Returns the signing history of a validator between given heights, grouped into equal buckets.
+    /// The last `10000` blocks and `100` buckets are used if they are not given.
+    pub async fn get_validator_signing_heatmap(
+        &self,
+        operator_address: &str,
+        from: Option<u64>,
+        to: Option<u64>,
+        buckets: Option<u64>,
+    ) -> Result<Vec<ValidatorSigningBucket>, TNRAppError> {
+        let (from, to) = self.get_signing_range(from, to, SIGNING_HEATMAP_MAX_RANGE).await?;
+        let windows = self.get_signing_windows(operator_address, from, to).await?;
+
+        let range = to - from + 1;
+        let buckets = buckets
+            .unwrap_or(SIGNING_HEATMAP_DEFAULT_BUCKETS)
+            .clamp(1, SIGNING_HEATMAP_MAX_BUCKETS)
+            .min(range);
+        let bucket_size = (range + buckets - 1) / buckets;
+
+        let heatmap = (0..buckets)
+            .map(|index| from + index * bucket_size)
+            .take_while(|bucket_from| *bucket_from <= to)
+            .map(|bucket_from| {
+                let bucket_to = (bucket_from + bucket_size - 1).min(to);
+                let (recorded_blocks, missed_blocks) = count_signing(&windows, bucket_from, bucket_to);
+
+                ValidatorSigningBucket {
+                    from_height: bucket_from,
+                    to_height: bucket_to,
+                    recorded_blocks,
+                    missed_blocks,
+                }
+            })
+            .collect();
+
+        Ok(heatmap)
+    }
+
+    /// Returns the signing windows of the validator with given operator address.
+    async fn get_signing_windows(&self, operator_address: &str, from: u64, to: u64) -> Result<Vec<ValidatorSigningWindowForDb>, TNRAppError> {
+        let validator = self.database.find_validator_by_operator_addr(operator_address).await?;
+
+        self.database.find_validator_signing_windows(&validator.hex_address, from, to).await
+    }
+
+    /// Validates the given height range. The missing bounds are filled using the latest saved block.
+    async fn get_signing_range(&self, from: Option<u64>, to: Option<u64>, max_range: u64) -> Result<(u64, u64), TNRAppError> {
+        let to = match to {
+            Some(to) => to,
+            None => self
+                .database
+                .find_last_count_blocks(None, 1)
+                .await?
+                .first()
+                .map(|block| block.height)
+                .ok_or_else(|| TNRAppError::new(TNRAppErrorType::NotFoundError, "No block is found."))?,
+        };
+
+        let from = from.unwrap_or_else(|| to.saturating_sub(SIGNING_DEFAULT_RANGE - 1));
+
+        if from > to {
+            return Err(TNRAppError::new(
+                TNRAppErrorType::BadRequestError,
+                format!("Invalid height range, {from} is greater than {to}."),
+            ));
+        }
+
+        if to - from >= max_range {
+            return Err(TNRAppError::new(
+                TNRAppErrorType::BadRequestError,
+                format!("Height range can't be wider than {max_range} blocks."),
+            ));
+        }
+
+        Ok((from, to))
+    }
+}
+
+/// Returns the count of the recorded and the missed heights of the windows between given heights.
+fn count_signing(windows: &[ValidatorSigningWindowForDb], from: u64, to: u64) -> (u64, u64) {
+    windows.iter().fold((0, 0), |(recorded, missed), window| {
+        let (window_recorded, window_missed) = window.count_between(from, to);
+        (recorded + window_recorded, missed + window_missed)
+    })
+}
+
+/// Returns the ratio of the signed blocks to the recorded ones.
+fn uptime_of(recorded_blocks: u64, missed_blocks: u64) -> f64 {
+    match recorded_blocks {
+        0 => 0.0,
+        _ => (recorded_blocks - missed_blocks) as f64 / recorded_blocks as f64,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct ValidatorMissedBlocks {
+    pub operator_address: String,
+    pub from_height: u64,
+    pub to_height: u64,
+    /// The count of the blocks at which the validator is in the active set.
+    pub recorded_blocks: u64,
+    pub missed_blocks: u64,
+    pub missed_heights: Vec<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct ValidatorUptimePeriod {
+    pub from_height: u64,
+    pub to_height: u64,
+    pub recorded_blocks: u64,
+    pub missed_blocks: u64,
+    /// The ratio of the signed blocks to the recorded ones. Eg: `0.9985`
+    pub uptime: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct ValidatorUptimePeriods {
+    pub last_24h: Option<ValidatorUptimePeriod>,
+    pub last_7d: Option<ValidatorUptimePeriod>,
+    pub last_30d: Option<ValidatorUptimePeriod>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct ValidatorSigningBucket {
+    pub from_height: u64,
+    pub to_height: u64,
+    pub recorded_blocks: u64,
+    pub missed_blocks: u64,
+}
+
+/// The active validator sets requested by [`Chain::store_block_signing`], by their hashes.
+#[derive(Clone, Default)]
+pub struct ValidatorSetCache {
+    sets: Arc<Mutex<HashMap<String, Arc<Vec<String>>>>>,
+}
+
+impl ValidatorSetCache {
+    /// Returns the hex addresses of the validator set with given hash, if it is cached.
+    fn get(&self, validators_hash: &str) -> Option<Arc<Vec<String>>> {
+        self.sets.lock().ok()?.get(validators_hash).cloned()
+    }
+
+    /// Caches the hex addresses of the validator set with given hash. All the sets are dropped once the cache is full.
+    fn insert(&self, validators_hash: &str, hex_addresses: Arc<Vec<String>>) {
+        if let Ok(mut sets) = self.sets.lock() {
+            if sets.len() >= VALIDATOR_SET_CACHE_SIZE {
+                sets.clear();
+            }
+
+            sets.insert(validators_hash.to_string(), hex_addresses);
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+struct ValidatorsResp {
+    /// The validators in the page.
+    validators: Vec<ValidatorsRespValidator>,
+    /// The count of the validators in the set. Eg: `"75"`
+    total: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+struct ValidatorsRespValidator {
+    /// The hex encoded consensus address. Eg: `"2D5E36B3FFE7E1D31B4B0EE4E9AAE0E1ACA7C12C"`
+    address: String,
+}
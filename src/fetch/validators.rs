@@ -8,18 +8,19 @@ use prost::Message;
 use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 use tokio::join;
-use tonic::transport::Endpoint;
+use utoipa::ToSchema;
 
 use crate::database::{ListDbResult, PaginatedListResult, ValidatorForDb};
 use crate::fetch::cosmos::slashing::v1beta1::{QuerySigningInfoRequest, QuerySigningInfoResponse};
 use crate::fetch::cosmos::tx::v1beta1::OrderBy;
 use crate::fetch::transactions::TxsResp;
 use crate::routes::ChainAmountItem;
-use crate::routes::{PaginationData, TNRAppError};
+use crate::routes::{PaginationData, TNRAppError, TNRAppErrorType};
 use crate::utils::{convert_consensus_pubkey_to_consensus_address, get_key, str_to_dec};
 use crate::{chain::Chain, routes::OutRestResponse, utils};
 
 use crate::fetch::cosmos::base::abci::v1beta1::TxResponse as GrpcTxResponse;
+use crate::fetch::cosmos::base::query::v1beta1::PageRequest;
 use crate::fetch::cosmos::staking::v1beta1::{Commission, Description, Validator};
 use crate::fetch::cosmos::tx::v1beta1::Tx as GrpcTx;
 
@@ -29,21 +30,16 @@ use super::transactions::{InternalTransactionContent, InternalTransactionContent
 
 impl Chain {
     /// Returns the signing info by given cons address.
-    pub async fn get_validator_signing_info(&self, cons_addr: &str) -> Result<InternalSlashingSigningInfoItem, String> {
+    pub async fn get_validator_signing_info(&self, cons_addr: &str) -> Result<InternalSlashingSigningInfoItem, TNRAppError> {
         use crate::fetch::cosmos::slashing::v1beta1::query_client::QueryClient;
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QuerySigningInfoRequest {
             cons_address: cons_addr.to_string(),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
-            .signing_info(req)
-            .await
-            .map_err(|e| format!("{}", e))?;
+        let resp = QueryClient::new(channel).signing_info(req).await.map_err(TNRAppError::from)?;
 
         let signing_info = resp.into_inner();
 
@@ -53,9 +49,13 @@ impl Chain {
     }
 
     /// Returns the delegations to given validator address.
-    pub async fn get_validator_delegations(&self, validator_addr: &str, config: PaginationData) -> Result<ListDbResult<InternalDelegation>, String> {
+    pub async fn get_validator_delegations(
+        &self,
+        validator_addr: &str,
+        config: PaginationData,
+    ) -> Result<ListDbResult<InternalDelegation>, TNRAppError> {
         use crate::fetch::cosmos::staking::v1beta1::{query_client::QueryClient, QueryValidatorDelegationsRequest};
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let pagination = config.into();
 
@@ -64,12 +64,7 @@ impl Chain {
             pagination: Some(pagination),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
-            .validator_delegations(req)
-            .await
-            .map_err(|e| format!("{e}"))?;
+        let resp = QueryClient::new(channel).validator_delegations(req).await.map_err(TNRAppError::from)?;
 
         let delegations = resp.into_inner();
 
@@ -92,22 +87,24 @@ impl Chain {
     }
 
     /// Returns the unbonding delegations to given validator address.
-    pub async fn get_validator_unbondings(&self, validator_addr: &str, config: PaginationData) -> Result<ListDbResult<InternalUnbonding>, String> {
+    pub async fn get_validator_unbondings(
+        &self,
+        validator_addr: &str,
+        config: PaginationData,
+    ) -> Result<ListDbResult<InternalUnbonding>, TNRAppError> {
         use crate::fetch::cosmos::staking::v1beta1::{query_client::QueryClient, QueryValidatorUnbondingDelegationsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryValidatorUnbondingDelegationsRequest {
             validator_addr: validator_addr.to_string(),
             pagination: Some(config.into()),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .validator_unbonding_delegations(req)
             .await
-            .map_err(|e| format!("{}", e))?;
+            .map_err(TNRAppError::from)?;
 
         let resp_unboundings = resp.into_inner();
 
@@ -141,12 +138,14 @@ impl Chain {
         validator_addr: &str,
         config: PaginationData,
         query_config: ValidatorRedelegationQuery,
-    ) -> Result<ListDbResult<InternalRedelegation>, String> {
+    ) -> Result<ListDbResult<InternalRedelegation>, TNRAppError> {
         use crate::fetch::cosmos::tx::v1beta1::{service_client::ServiceClient, GetTxsEventRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
-        query_config.validate()?;
+        query_config
+            .validate()
+            .map_err(|e| TNRAppError::new(TNRAppErrorType::BadRequestError, e))?;
 
         let is_destination = query_config.destination.unwrap_or(false);
         let is_source = query_config.source.unwrap_or(false);
@@ -182,6 +181,7 @@ impl Chain {
             offset: config.offset,
             limit: Some(limit),
             direction: None,
+            ..Default::default()
         };
         let req = GetTxsEventRequest {
             events,
@@ -192,19 +192,14 @@ impl Chain {
             query: "".to_string(),
         };
 
-        let resp = ServiceClient::connect(endpoint)
-            .await
-            .unwrap()
-            .get_txs_event(req)
-            .await
-            .map_err(|e| format!("{}", e))?;
+        let resp = ServiceClient::new(channel).get_txs_event(req).await.map_err(TNRAppError::from)?;
 
         let txs = resp.into_inner();
 
         let mut redelegations = vec![];
 
         for (tx, tx_response) in txs.txs.iter().zip(txs.tx_responses.iter()) {
-            redelegations.push(InternalRedelegation::from_tx(tx, tx_response, &self).await.unwrap());
+            redelegations.push(InternalRedelegation::from_tx(tx, tx_response, &self).await?);
         }
 
         Ok(ListDbResult {
@@ -214,16 +209,16 @@ impl Chain {
     }
 
     /// Returns validator info by given validator address.
-    pub async fn get_validator_info(&self, validator_addr: &str) -> Result<InternalValidator, String> {
+    pub async fn get_validator_info(&self, validator_addr: &str) -> Result<InternalValidator, TNRAppError> {
         use crate::fetch::cosmos::staking::v1beta1::{query_client::QueryClient, QueryValidatorRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryValidatorRequest {
             validator_addr: validator_addr.to_string(),
         };
 
-        let mut client = QueryClient::connect(endpoint).await.unwrap();
+        let mut client = QueryClient::new(channel);
 
         let (resp, bonded_height, staking_pool_resp) = join!(
             client.validator(req),
@@ -233,7 +228,7 @@ impl Chain {
 
         let bonded_height = bonded_height.unwrap_or(0);
         let validator = resp
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner()
             .validator
             .ok_or(format!("No validator data on response: {}", validator_addr))?;
@@ -295,48 +290,52 @@ impl Chain {
         &self,
         delegator_addr: &str,
         pagination: PaginationData,
-    ) -> Result<ListDbResult<ValidatorListValidator>, String> {
+    ) -> Result<ListDbResult<ValidatorListValidator>, TNRAppError> {
         use crate::fetch::cosmos::staking::v1beta1::{query_client::QueryClient, QueryDelegatorValidatorsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
+
+        let (offset, limit) = pagination.to_offset_page(20)?;
 
         let req = QueryDelegatorValidatorsRequest {
             delegator_addr: delegator_addr.to_string(),
-            pagination: Some(pagination.into()),
+            pagination: Some(PageRequest {
+                offset,
+                limit,
+                count_total: true,
+                ..Default::default()
+            }),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .delegator_validators(req)
             .await
-            .map_err(|e| format!("{e}"))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
-        let validators = resp.validators.into_iter().map(|v| v.into()).collect();
+        let validators: Vec<ValidatorListValidator> = resp.validators.into_iter().map(|v| v.into()).collect();
+        let total = resp.pagination.map(|pagination| pagination.total);
 
         Ok(ListDbResult {
+            pagination: PaginationData::from_offset_page(offset, limit, validators.len() as u64, total),
             data: validators,
-            pagination: resp.pagination.unwrap_or_default().into(),
         })
     }
 
     /// Returns accumulated commission of given validator.
-    pub async fn get_validator_commission(&self, validator_addr: &str) -> Result<ValidatorCommisionResp, String> {
+    pub async fn get_validator_commission(&self, validator_addr: &str) -> Result<ValidatorCommisionResp, TNRAppError> {
         use crate::fetch::cosmos::distribution::v1beta1::{query_client::QueryClient, QueryValidatorCommissionRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryValidatorCommissionRequest {
             validator_address: validator_addr.to_string(),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .validator_commission(req)
             .await
-            .map_err(|e| format!("{e}"))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let commission = resp
@@ -357,21 +356,19 @@ impl Chain {
     }
 
     /// Returns rewards of given validator.
-    pub async fn get_validator_rewards(&self, validator_addr: &str) -> Result<ValidatorRewardsResp, String> {
+    pub async fn get_validator_rewards(&self, validator_addr: &str) -> Result<ValidatorRewardsResp, TNRAppError> {
         use crate::fetch::cosmos::distribution::v1beta1::{query_client::QueryClient, QueryValidatorOutstandingRewardsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryValidatorOutstandingRewardsRequest {
             validator_address: validator_addr.to_string(),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .validator_outstanding_rewards(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let resp = resp
@@ -395,23 +392,17 @@ impl Chain {
     }
 
     /// Returns the list of validators with unbonding status.
-    pub async fn get_validators_unbonding(&self, pagination_config: PaginationData) -> Result<ListDbResult<ValidatorListValidator>, String> {
+    pub async fn get_validators_unbonding(&self, pagination_config: PaginationData) -> Result<ListDbResult<ValidatorListValidator>, TNRAppError> {
         use crate::fetch::cosmos::staking::v1beta1::{query_client::QueryClient, QueryValidatorsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryValidatorsRequest {
             status: "BOND_STATUS_UNBONDING".to_string(),
             pagination: Some(pagination_config.into()),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
-            .validators(req)
-            .await
-            .map_err(|e| format!("{}", e))?
-            .into_inner();
+        let resp = QueryClient::new(channel).validators(req).await.map_err(TNRAppError::from)?.into_inner();
 
         let validators = resp.validators.into_iter().map(|v| v.into()).collect();
 
@@ -422,23 +413,17 @@ impl Chain {
     }
 
     /// Returns the list of validators with unspecified status.
-    pub async fn get_validators_unspecified(&self, pagination_config: PaginationData) -> Result<ListDbResult<ValidatorListValidator>, String> {
+    pub async fn get_validators_unspecified(&self, pagination_config: PaginationData) -> Result<ListDbResult<ValidatorListValidator>, TNRAppError> {
         use crate::fetch::cosmos::staking::v1beta1::{query_client::QueryClient, QueryValidatorsRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryValidatorsRequest {
             status: "".to_string(),
             pagination: Some(pagination_config.into()),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
-            .validators(req)
-            .await
-            .map_err(|e| format!("{}", e))?
-            .into_inner();
+        let resp = QueryClient::new(channel).validators(req).await.map_err(TNRAppError::from)?.into_inner();
 
         let validators = resp.validators.into_iter().map(|v| v.into()).collect();
 
@@ -449,22 +434,20 @@ impl Chain {
     }
 
     /// Returns validator information by given delegator validator pair.
-    pub async fn get_delegator_validator_pair_info(&self, delegator_addr: &str, validator_addr: &str) -> Result<ValidatorResp, String> {
+    pub async fn get_delegator_validator_pair_info(&self, delegator_addr: &str, validator_addr: &str) -> Result<ValidatorResp, TNRAppError> {
         use crate::fetch::cosmos::staking::v1beta1::{query_client::QueryClient, QueryDelegatorValidatorRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryDelegatorValidatorRequest {
             delegator_addr: delegator_addr.to_string(),
             validator_addr: validator_addr.to_string(),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = QueryClient::new(channel)
             .delegator_validator(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         Ok(ValidatorResp {
@@ -473,7 +456,7 @@ impl Chain {
     }
 
     /// Returns the latest validator set.
-    pub async fn get_validator_set(&self) -> Result<OutRestResponse<Vec<ValidatorSetValidator>>, String> {
+    pub async fn get_validator_set(&self) -> Result<OutRestResponse<Vec<ValidatorSetValidator>>, TNRAppError> {
         let limit = 100;
 
         let config = PaginationData {
@@ -481,6 +464,7 @@ impl Chain {
             offset: None,
             limit: Some(limit),
             direction: None,
+            ..Default::default()
         };
 
         let mut first_resp = self._get_validator_set(config).await?;
@@ -499,6 +483,7 @@ impl Chain {
                 offset: Some(page * limit),
                 limit: Some(limit),
                 direction: None,
+                ..Default::default()
             }))
         }
 
@@ -510,6 +495,7 @@ impl Chain {
                 offset: Some((pages_to_request + 1) * limit),
                 limit: Some(rem),
                 direction: None,
+                ..Default::default()
             }))
         }
 
@@ -525,21 +511,19 @@ impl Chain {
     }
 
     /// Returns the latest validator set.
-    async fn _get_validator_set(&self, page: PaginationData) -> Result<PaginatedListResult<ValidatorSetValidator>, String> {
+    async fn _get_validator_set(&self, page: PaginationData) -> Result<PaginatedListResult<ValidatorSetValidator>, TNRAppError> {
         use crate::fetch::cosmos::base::tendermint::v1beta1::{service_client::ServiceClient, GetLatestValidatorSetRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = GetLatestValidatorSetRequest {
             pagination: Some(page.into()),
         };
 
-        let resp = ServiceClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = ServiceClient::new(channel)
             .get_latest_validator_set(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let validators = resp
@@ -560,7 +544,7 @@ impl Chain {
     }
 
     /// Returns the validator set at given height.
-    pub async fn get_validator_set_by_height(&self, height: i64) -> Result<OutRestResponse<Vec<ValidatorSetValidator>>, String> {
+    pub async fn get_validator_set_by_height(&self, height: i64) -> Result<OutRestResponse<Vec<ValidatorSetValidator>>, TNRAppError> {
         let limit = 100;
 
         let config = PaginationData {
@@ -568,6 +552,7 @@ impl Chain {
             offset: None,
             limit: Some(limit),
             direction: None,
+            ..Default::default()
         };
 
         let mut first_resp = self._get_validator_set_by_height(height, config).await?;
@@ -590,6 +575,7 @@ impl Chain {
                     offset: Some(page * limit),
                     limit: Some(limit),
                     direction: None,
+                    ..Default::default()
                 },
             ))
         }
@@ -604,6 +590,7 @@ impl Chain {
                     offset: Some((pages_to_request + 1) * limit),
                     limit: Some(rem),
                     direction: None,
+                    ..Default::default()
                 },
             ))
         }
@@ -624,22 +611,20 @@ impl Chain {
         &self,
         height: i64,
         config: PaginationData,
-    ) -> Result<PaginatedListResult<ValidatorSetValidator>, String> {
+    ) -> Result<PaginatedListResult<ValidatorSetValidator>, TNRAppError> {
         use crate::fetch::cosmos::base::tendermint::v1beta1::{service_client::ServiceClient, GetValidatorSetByHeightRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = GetValidatorSetByHeightRequest {
             height,
             pagination: Some(config.into()),
         };
 
-        let resp = ServiceClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = ServiceClient::new(channel)
             .get_validator_set_by_height(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let validators = resp
@@ -660,10 +645,10 @@ impl Chain {
     }
 
     /// Returns the validator set at given height.
-    async fn get_validator_bonded_height(&self, valoper_addr: &str) -> Result<i64, String> {
+    async fn get_validator_bonded_height(&self, valoper_addr: &str) -> Result<i64, TNRAppError> {
         use crate::fetch::cosmos::tx::v1beta1::{service_client::ServiceClient, GetTxsEventRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = GetTxsEventRequest {
             events: vec![],
@@ -674,12 +659,10 @@ impl Chain {
             query: format!("create_validator.validator={}", valoper_addr),
         };
 
-        let resp = ServiceClient::connect(endpoint)
-            .await
-            .unwrap()
+        let resp = ServiceClient::new(channel)
             .get_txs_event(req)
             .await
-            .map_err(|e| format!("{}", e))?
+            .map_err(TNRAppError::from)?
             .into_inner();
 
         let default_bonded_height = 0;
@@ -692,7 +675,7 @@ impl Chain {
         Ok(bonded_height)
     }
 
-    pub async fn get_validator_uptime(&self, consensus_address: &str, val_status: Option<ValidatorStatus>) -> Result<f64, String> {
+    pub async fn get_validator_uptime(&self, consensus_address: &str, val_status: Option<ValidatorStatus>) -> Result<f64, TNRAppError> {
         let default_uptime_value = 0.0;
 
         if val_status.unwrap() != ValidatorStatus::Active {
@@ -708,7 +691,7 @@ impl Chain {
         Ok(1.0 - (val_signing_info.missed_blocks_counter as f64 / slashing_params.signed_blocks_window as f64))
     }
 
-    pub async fn get_validator_status(&self, validator: &Validator, consensus_address: &str) -> Result<ValidatorStatus, String> {
+    pub async fn get_validator_status(&self, validator: &Validator, consensus_address: &str) -> Result<ValidatorStatus, TNRAppError> {
         let signing_info = self.get_validator_signing_info(consensus_address).await?;
 
         let status = if validator.jailed {
@@ -729,7 +712,7 @@ impl Chain {
         validator_operator_address: &str,
         period: Duration,
         current_voting_power_percentage: f64,
-    ) -> Result<f64, String> {
+    ) -> Result<f64, TNRAppError> {
         let voting_powers_history = match self.database.find_historical_data_by_operator_address(validator_operator_address).await {
             Ok(voting_power_db) => voting_power_db.voting_power_data,
             Err(err) => return Err(err),
@@ -753,7 +736,7 @@ impl Chain {
                 let bonded_tokens = self.get_staking_pool().await?.value.bonded as f64;
                 Ok(((value / bonded_tokens) * 100.0) - current_voting_power_percentage)
             }
-            None => Err("Could not calculate voting power change".to_string()),
+            None => Err("Could not calculate voting power change".to_string().into()),
         }
     }
 
@@ -787,26 +770,20 @@ impl Chain {
     }
 
     //Self delegations of validator
-    pub async fn get_val_self_delegations(&self, operator_address: String) -> Result<InternalDelegation, String> {
+    pub async fn get_val_self_delegations(&self, operator_address: String) -> Result<InternalDelegation, TNRAppError> {
         let val = self.database.find_validator(doc! {"operator_address": operator_address.clone()}).await?;
         let self_delegate_address = val.self_delegate_address;
 
         use crate::fetch::cosmos::staking::v1beta1::{query_client::QueryClient, QueryDelegationRequest};
 
-        let endpoint = Endpoint::from_shared(self.config.grpc_url.clone().unwrap()).unwrap();
+        let channel = self.grpc_channel().await?;
 
         let req = QueryDelegationRequest {
             delegator_addr: operator_address,
             validator_addr: self_delegate_address.clone(),
         };
 
-        let resp = QueryClient::connect(endpoint)
-            .await
-            .unwrap()
-            .delegation(req)
-            .await
-            .map_err(|e| format!("{}", e))?
-            .into_inner();
+        let resp = QueryClient::new(channel).delegation(req).await.map_err(TNRAppError::from)?.into_inner();
 
         let amount = self
             .string_amount_parser(resp.delegation_response.unwrap().delegation.unwrap().shares, None)
@@ -819,7 +796,7 @@ impl Chain {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ValidatorSetValidator {
     /// Validator address. Eg: `"cosmosvalcons14sk4vptumprktehmuvvf0yynarjy4gv08t64t4""`
     pub address: String,
@@ -827,20 +804,22 @@ pub struct ValidatorSetValidator {
     pub pub_key: ValidatorSetPubKey,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ValidatorSetPubKey {
     /// Validator pubkey. Eg: `"LtiHVLCcE+oFII0vpIl9mfkGDmk9BpPg1eUkvKnO4xw=""`
     pub key: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+#[schema(as = ValidatorInternalUnbonding)]
 pub struct InternalUnbonding {
     pub address: String,
     pub balance: ChainAmountItem,
     pub completion_time: i32,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+#[schema(as = ValidatorInternalDelegation)]
 pub struct InternalDelegation {
     pub address: String,
     pub amount: ChainAmountItem,
@@ -872,7 +851,7 @@ pub struct ValidatorDelegationDelegation {
     shares: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ValidatorResp {
     /// Validator.
     pub validator: ValidatorListValidator,
@@ -886,19 +865,19 @@ pub struct ValidatorsResp {
     pub pagination: Pagination,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ValidatorCommisionResp {
     /// Validator commission.
     pub commission: ValidatorCommision,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ValidatorCommision {
     /// Array of amounts and demons.
     pub commission: Vec<DenomAmount>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ValidatorRewardsResp {
     /// Validator rewards.
     pub rewards: ValidatorCommision,
@@ -926,10 +905,10 @@ pub struct ValidatorListDbResp {
     pub pagination: PaginationData,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ValidatorListResp(Vec<ValidatorListElement>);
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ValidatorListElement {
     pub rank: u64,
     pub moniker: String,
@@ -980,7 +959,7 @@ impl ValidatorListResp {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ValidatorListElementValidatorCommission {
     pub commission_rates: ValidatorListElementValidatorCommissionRates,
     /// Validator commission update time. Eg: `"2022-03-02T19:00:00Z"`
@@ -996,7 +975,7 @@ impl ValidatorListElementValidatorCommission {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ValidatorListElementValidatorCommissionRates {
     pub rate: f64,
     pub max_rate: f64,
@@ -1017,7 +996,7 @@ impl ValidatorListElementValidatorCommissionRates {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ConsensusPubkey {
     /// Type of public key. Eg: `"/cosmos.crypto.secp256k1.PubKey"`
     #[serde(rename = "@type")]
@@ -1026,7 +1005,7 @@ pub struct ConsensusPubkey {
     pub key: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ValidatorListValidator {
     /// Operator address. Eg: `"evmosvaloper1qq95x6dhrdnrfunlth5uh24tkrfphzl9crd3xr"`
     pub operator_address: String,
@@ -1112,7 +1091,7 @@ impl From<Validator> for ValidatorListValidator {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct InternalValidator {
     logo_url: String,
     commission: f64,
@@ -1132,7 +1111,7 @@ pub struct InternalValidator {
     status: String,
 }
 
-#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone, ToSchema)]
 pub struct ValidatorListValidatorCommission {
     /// Validator commission rates.
     pub commission_rates: ValidatorListValidatorCommissionRates,
@@ -1140,7 +1119,7 @@ pub struct ValidatorListValidatorCommission {
     pub update_time: String,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, ToSchema)]
 pub struct ValidatorListValidatorCommissionRates {
     /// Validator commission rate. Eg: `"0.050000000000000000"`
     pub rate: String,
@@ -1150,7 +1129,7 @@ pub struct ValidatorListValidatorCommissionRates {
     pub max_change_rate: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ValidatorListValidatorDescription {
     /// Validator moniker. Eg: `"heisenbug"`
     pub moniker: String,
@@ -1164,7 +1143,8 @@ pub struct ValidatorListValidatorDescription {
     pub details: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+#[schema(as = ValidatorInternalRedelegation)]
 pub struct InternalRedelegation {
     pub amount: ChainAmountItem,
     pub completion_time: i64,
@@ -1175,7 +1155,7 @@ pub struct InternalRedelegation {
 }
 
 impl InternalRedelegation {
-    pub async fn from_tx(tx: &GrpcTx, tx_response: &GrpcTxResponse, chain: &Chain) -> Result<Self, String> {
+    pub async fn from_tx(tx: &GrpcTx, tx_response: &GrpcTxResponse, chain: &Chain) -> Result<Self, TNRAppError> {
         let (delegator_address, validator_dst_address, amount) = match tx.body.clone().map(|b| b.messages.get(0).cloned()).flatten() {
             Some(message) => {
                 if message.type_url == "/cosmos.staking.v1beta1.MsgBeginRedelegate" {
@@ -1186,10 +1166,10 @@ impl InternalRedelegation {
 
                     (redelegate.delegator_address, redelegate.validator_dst_address, redelegate.amount)
                 } else {
-                    return Err(format!("Message is not the correct type, {}", tx_response.txhash));
+                    return Err(format!("Message is not the correct type, {}", tx_response.txhash).into());
                 }
             }
-            None => return Err(format!("Tx doesn't have a redelegation message, {}.", tx_response.txhash)),
+            None => return Err(format!("Tx doesn't have a redelegation message, {}.", tx_response.txhash).into()),
         };
 
         let validator = chain.database.find_validator_by_operator_addr(&validator_dst_address).await?;
@@ -1198,18 +1178,19 @@ impl InternalRedelegation {
 
         // let completion_time = match txhashsh_response.logs.get(0) {
         let Some(logs) = tx_response.logs.get(0) else {
-            return Err(format!("Tx doesn't have a log, {}.", tx_response.txhash));
+            return Err(format!("Tx doesn't have a log, {}.", tx_response.txhash).into());
         };
 
         let Some(event) = logs.events.iter().find(|event| event.r#type == "redelegate") else {
             return Err(format!(
                 "Tx redelagate event log doesn't have `completion_time` attribute, {}.",
                 tx_response.txhash
-            ));
+            )
+            .into());
         };
 
         let Some(attr) = event.attributes.iter().find(|attr| attr.key == "completion_time") else {
-            return Err(format!("Tx doesn't have a redelagate event log, {}.", tx_response.txhash));
+            return Err(format!("Tx doesn't have a redelagate event log, {}.", tx_response.txhash).into());
         };
 
         let completion_time = match DateTime::parse_from_rfc3339(&attr.value) {
@@ -1221,7 +1202,7 @@ impl InternalRedelegation {
                     ts
                 }
             }
-            Err(e) => return Err(format!("Cannot parse datetetime {}: {}", attr.value, e)),
+            Err(e) => return Err(format!("Cannot parse datetetime {}: {}", attr.value, e).into()),
         };
 
         Ok(Self {
@@ -1235,7 +1216,7 @@ impl InternalRedelegation {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct InternalSlashingSigningInfoItem {
     /// Validator address. Eg: `"evmosvalcons1qx4hehfny66jfzymzn6d5t38m0ely3cvw6zn06"`
     pub address: String,
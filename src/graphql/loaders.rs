@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use actix_web::web::Data;
+use async_graphql::dataloader::Loader;
+use async_trait::async_trait;
+use mongodb::bson::doc;
+
+use crate::database::{TransactionForDb, ValidatorForDb};
+use crate::state::State;
+
+/// The key to load a validator by its operator address.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ValidatorByOperatorAddress {
+    pub chain: String,
+    /// Eg: `"cosmosvaloper1c4k24jzduc365kywrsvf5ujz4ya6mwympnc4en"`
+    pub operator_address: String,
+}
+
+/// The key to load the transactions of a block.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TxsByHeight {
+    pub chain: String,
+    pub height: u64,
+}
+
+/// The loader that batches the keys requested while resolving a query into a single database request for each chain.
+pub struct ChainLoader {
+    state: Data<State>,
+}
+
+impl ChainLoader {
+    /// Creates a new loader over the chains of the state.
+    pub fn new(state: Data<State>) -> Self {
+        Self { state }
+    }
+}
+
+#[async_trait]
+impl Loader<ValidatorByOperatorAddress> for ChainLoader {
+    type Value = ValidatorForDb;
+    type Error = String;
+
+    async fn load(&self, keys: &[ValidatorByOperatorAddress]) -> Result<HashMap<ValidatorByOperatorAddress, ValidatorForDb>, String> {
+        let mut validators = HashMap::new();
+
+        for (chain_name, operator_addresses) in group_by_chain(keys, |key| (key.chain.clone(), key.operator_address.clone())) {
+            let chain = self.state.get(&chain_name)?;
+
+            let found = chain
+                .database
+                .find_validators(Some(doc! {"$match": {"operator_address": {"$in": operator_addresses}}}))
+                .await
+                .map_err(|e| e.to_string())?;
+
+            for validator in found {
+                let key = ValidatorByOperatorAddress {
+                    chain: chain_name.clone(),
+                    operator_address: validator.operator_address.clone(),
+                };
+
+                validators.insert(key, validator);
+            }
+        }
+
+        Ok(validators)
+    }
+}
+
+#[async_trait]
+impl Loader<TxsByHeight> for ChainLoader {
+    type Value = Vec<TransactionForDb>;
+    type Error = String;
+
+    async fn load(&self, keys: &[TxsByHeight]) -> Result<HashMap<TxsByHeight, Vec<TransactionForDb>>, String> {
+        let mut txs: HashMap<TxsByHeight, Vec<TransactionForDb>> = HashMap::new();
+
+        for (chain_name, heights) in group_by_chain(keys, |key| (key.chain.clone(), key.height)) {
+            let chain = self.state.get(&chain_name)?;
+
+            // The blocks without any transactions are still returned, as empty lists.
+            for height in &heights {
+                txs.insert(
+                    TxsByHeight {
+                        chain: chain_name.clone(),
+                        height: *height,
+                    },
+                    vec![],
+                );
+            }
+
+            let heights_txs = chain.database.find_transactions_by_heights(heights).await.map_err(|e| e.to_string())?;
+
+            for tx in heights_txs {
+                let key = TxsByHeight {
+                    chain: chain_name.clone(),
+                    height: tx.height,
+                };
+
+                txs.entry(key).or_default().push(tx);
+            }
+        }
+
+        Ok(txs)
+    }
+}
+
+/// Groups the values of the keys by their chain names.
+fn group_by_chain<K, V>(keys: &[K], split: impl Fn(&K) -> (String, V)) -> HashMap<String, Vec<V>> {
+    let mut groups: HashMap<String, Vec<V>> = HashMap::new();
+
+    for key in keys {
+        let (chain, value) = split(key);
+        groups.entry(chain).or_default().push(value);
+    }
+
+    groups
+}
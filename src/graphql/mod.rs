@@ -0,0 +1,68 @@
+mod loaders;
+mod objects;
+
+use actix_web::http::header::ContentType;
+use actix_web::web::Data;
+use actix_web::{get, post, HttpResponse};
+use async_graphql::dataloader::DataLoader;
+use async_graphql::http::GraphiQLSource;
+use async_graphql::{Context, EmptyMutation, EmptySubscription, Object, Result, Schema};
+use async_graphql_actix_web::{GraphQLRequest, GraphQLResponse};
+
+use crate::state::State;
+
+use loaders::ChainLoader;
+use objects::ChainObject;
+
+/// The maximum nesting depth of a query, like `chain { blocks { txs { ... } } }`.
+const MAX_QUERY_DEPTH: usize = 8;
+
+/// The maximum complexity of a query, every field in it counts as 1.
+const MAX_QUERY_COMPLEXITY: usize = 500;
+
+/// The GraphQL schema served at `/graphql`.
+pub type ExplorerSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+/// Builds the GraphQL schema over the chains of the state.\
+/// The validators and the transactions requested while resolving a query are loaded in batches, see `loaders.rs`.\
+/// The queries deeper or more complex than the limits are rejected before they are resolved.
+pub fn build_schema(state: Data<State>) -> ExplorerSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_depth(MAX_QUERY_DEPTH)
+        .limit_complexity(MAX_QUERY_COMPLEXITY)
+        .data(DataLoader::new(ChainLoader::new(state.clone()), tokio::spawn))
+        .data(state)
+        .finish()
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Returns the chain with given name. Eg: `"axelar"`
+    async fn chain(&self, ctx: &Context<'_>, name: String) -> Result<ChainObject> {
+        let state = ctx.data::<Data<State>>()?;
+
+        Ok(ChainObject(state.get(&name)?))
+    }
+
+    /// Returns all the supported chains.
+    async fn chains(&self, ctx: &Context<'_>) -> Result<Vec<ChainObject>> {
+        let state = ctx.data::<Data<State>>()?;
+
+        Ok(state.get_chains().values().cloned().map(ChainObject).collect())
+    }
+}
+
+#[post("graphql")]
+pub async fn graphql(schema: Data<ExplorerSchema>, request: GraphQLRequest) -> GraphQLResponse {
+    schema.execute(request.into_inner()).await.into()
+}
+
+/// Serves the GraphiQL page to explore the schema.
+#[get("graphql")]
+pub async fn graphiql() -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(ContentType::html())
+        .body(GraphiQLSource::build().endpoint("/graphql").finish())
+}
@@ -0,0 +1,412 @@
+use async_graphql::dataloader::DataLoader;
+use async_graphql::{Context, Json, Object, Result};
+use mongodb::bson::doc;
+
+use crate::chain::Chain;
+use crate::database::{BlockForDb, TransactionForDb, ValidatorForDb};
+use crate::fetch::heartbeats::HeartbeatsQuery;
+use crate::fetch::proposals::InternalProposal;
+use crate::fetch::validators::InternalDelegation;
+use crate::routes::{ChainAmountItem, PaginationData};
+
+use super::loaders::{ChainLoader, TxsByHeight, ValidatorByOperatorAddress};
+
+/// The maximum count of the items a list field returns.
+const GRAPHQL_MAX_LIMIT: u64 = 100;
+
+/// Returns the pagination config of a list field.
+fn pagination(offset: Option<u64>, limit: Option<u64>) -> PaginationData {
+    let default = PaginationData::default();
+
+    PaginationData {
+        offset,
+        limit: limit.or(default.limit).map(|limit| limit.min(GRAPHQL_MAX_LIMIT)),
+        ..default
+    }
+}
+
+pub struct ChainObject(pub Chain);
+
+#[Object(name = "Chain")]
+impl ChainObject {
+    /// Chain name. Eg: `"axelar"`
+    async fn name(&self) -> &str {
+        &self.0.config.name
+    }
+
+    /// Bech32 prefix of the account addresses. Eg: `"axelar"`
+    async fn base_prefix(&self) -> &str {
+        &self.0.config.base_prefix
+    }
+
+    /// Returns the validator with given operator address.
+    async fn validator(&self, operator_address: String) -> Result<ValidatorObject> {
+        let validator = self.0.database.find_validator_by_operator_addr(&operator_address).await?;
+
+        Ok(ValidatorObject::new(&self.0, validator))
+    }
+
+    /// Returns the saved validators, only the active ones by default.
+    async fn validators(&self, #[graphql(default = true)] active_only: bool) -> Result<Vec<ValidatorObject>> {
+        let filter = active_only.then(|| doc! {"$match": {"is_active": true}});
+        let validators = self.0.database.find_validators(filter).await?;
+
+        Ok(validators.into_iter().map(|validator| ValidatorObject::new(&self.0, validator)).collect())
+    }
+
+    /// Returns the block at given height, or the latest saved block if no height is given.
+    async fn block(&self, height: Option<u64>) -> Result<BlockObject> {
+        let block = match height {
+            Some(height) => match self.0.database.find_block(doc! {"height": height as i64}).await {
+                Ok(block) => block,
+                Err(_) => self.0.get_block_for_db_by_height(height).await?,
+            },
+            None => self
+                .0
+                .get_last_blocks_from_db(1)
+                .await?
+                .pop()
+                .ok_or_else(|| "No block is found.".to_string())?,
+        };
+
+        Ok(BlockObject::new(&self.0, block))
+    }
+
+    /// Returns the last saved blocks.
+    async fn blocks(&self, #[graphql(default = 10)] limit: u64) -> Result<Vec<BlockObject>> {
+        let blocks = self.0.get_last_blocks_from_db(limit.min(GRAPHQL_MAX_LIMIT) as u16).await?;
+
+        Ok(blocks.into_iter().map(|block| BlockObject::new(&self.0, block)).collect())
+    }
+
+    /// Returns the transaction with given hash.
+    async fn tx(&self, hash: String) -> Result<Json<serde_json::Value>> {
+        let tx = self.0.get_stored_tx_by_hash(&hash).await?.value;
+
+        Ok(Json(serde_json::to_value(tx)?))
+    }
+
+    /// Returns the proposal with given ID.
+    async fn proposal(&self, id: u64) -> Result<ProposalObject> {
+        let proposal = self.0.get_proposal_details(id).await?;
+
+        Ok(ProposalObject {
+            chain: self.0.clone(),
+            proposal,
+        })
+    }
+}
+
+pub struct ValidatorObject {
+    chain: Chain,
+    validator: ValidatorForDb,
+}
+
+impl ValidatorObject {
+    pub fn new(chain: &Chain, validator: ValidatorForDb) -> Self {
+        Self {
+            chain: chain.clone(),
+            validator,
+        }
+    }
+}
+
+#[Object(name = "Validator")]
+impl ValidatorObject {
+    /// Validator operator address. Eg: `"axelarvaloper1qy9uq03rkpqkzwsa4fz7xxetkxttdcj6tf09pg"`
+    async fn operator_address(&self) -> &str {
+        &self.validator.operator_address
+    }
+
+    /// Validator consensus address. Eg: `"axelarvalcons1qy9uq03rkpqkzwsa4fz7xxetkxttdcj6tf09pg"`
+    async fn consensus_address(&self) -> &str {
+        &self.validator.consensus_address
+    }
+
+    /// HEX encoded address of the validator. Eg: `"E42125451E65AC3931726936026F295677DB5D07"`
+    async fn hex_address(&self) -> &str {
+        &self.validator.hex_address
+    }
+
+    /// Validator name. Eg: `"Testnetrun"`
+    async fn name(&self) -> &str {
+        &self.validator.name
+    }
+
+    async fn logo_url(&self) -> &str {
+        &self.validator.logo_url
+    }
+
+    async fn rank(&self) -> u64 {
+        self.validator.rank
+    }
+
+    async fn is_active(&self) -> bool {
+        self.validator.is_active
+    }
+
+    async fn voting_power(&self) -> u64 {
+        self.validator.voting_power
+    }
+
+    async fn voting_power_ratio(&self) -> f64 {
+        self.validator.voting_power_ratio
+    }
+
+    async fn tokens(&self) -> f64 {
+        self.validator.tokens
+    }
+
+    async fn uptime(&self) -> f64 {
+        self.validator.uptime
+    }
+
+    async fn self_delegate_address(&self) -> &str {
+        &self.validator.self_delegate_address
+    }
+
+    /// Returns the delegations to the validator.
+    async fn delegations(&self, offset: Option<u64>, limit: Option<u64>) -> Result<Vec<DelegationObject>> {
+        let delegations = self
+            .chain
+            .get_validator_delegations(&self.validator.operator_address, pagination(offset, limit))
+            .await?;
+
+        Ok(delegations.data.into_iter().map(DelegationObject).collect())
+    }
+
+    /// Returns the commission of the validator.
+    async fn commission(&self) -> Result<Json<serde_json::Value>> {
+        let commission = self.chain.get_validator_commission(&self.validator.operator_address).await?;
+
+        Ok(Json(serde_json::to_value(commission)?))
+    }
+
+    /// Returns the signing info of the validator.
+    async fn signing_info(&self) -> Result<Json<serde_json::Value>> {
+        let signing_info = self.chain.get_validator_signing_info(&self.validator.consensus_address).await?;
+
+        Ok(Json(serde_json::to_value(signing_info)?))
+    }
+
+    /// Returns the uptime of the validator over the last 24 hours, 7 days and 30 days.
+    async fn uptime_periods(&self) -> Result<Json<serde_json::Value>> {
+        let uptime = self.chain.get_validator_uptime_periods(&self.validator.operator_address).await?;
+
+        Ok(Json(serde_json::to_value(uptime)?))
+    }
+
+    /// Returns the heartbeats of the validator. Only supported on Axelar.
+    async fn heartbeats(&self, offset: Option<u64>, limit: Option<u64>) -> Result<Json<serde_json::Value>> {
+        if !vec![String::from("axelar"), String::from("axelar-testnet")].contains(&self.chain.config.name) {
+            return Err(format!("Heartbeats not supported for {}", self.chain.config.name).into());
+        }
+
+        let heartbeats = self
+            .chain
+            .get_val_heartbeats(
+                self.validator.operator_address.clone(),
+                HeartbeatsQuery::new(None, None)?,
+                pagination(offset, limit),
+            )
+            .await?;
+
+        Ok(Json(serde_json::to_value(heartbeats.data)?))
+    }
+}
+
+pub struct DelegationObject(InternalDelegation);
+
+#[Object(name = "Delegation")]
+impl DelegationObject {
+    /// Delegator address. Eg: `"axelar1qy9uq03rkpqkzwsa4fz7xxetkxttdcj6vzfuyj"`
+    async fn delegator_address(&self) -> &str {
+        &self.0.address
+    }
+
+    async fn amount(&self) -> Json<&ChainAmountItem> {
+        Json(&self.0.amount)
+    }
+}
+
+pub struct BlockObject {
+    chain: Chain,
+    block: BlockForDb,
+}
+
+impl BlockObject {
+    pub fn new(chain: &Chain, block: BlockForDb) -> Self {
+        Self { chain: chain.clone(), block }
+    }
+}
+
+#[Object(name = "Block")]
+impl BlockObject {
+    /// Block height. Eg: `4611328`
+    async fn height(&self) -> u64 {
+        self.block.height
+    }
+
+    /// Block hash. Eg: `"7BF4A6A4A07D1F1B38EB0A2B6F1C42F96C3B6A8A5D2D86E4B6E8C1F0E4A7D2C1"`
+    async fn hash(&self) -> &str {
+        &self.block.hash
+    }
+
+    /// Block timestamp in milliseconds.
+    async fn timestamp(&self) -> i64 {
+        self.block.timestamp
+    }
+
+    async fn tx_count(&self) -> u64 {
+        self.block.tx_count
+    }
+
+    /// Operator address of the proposer, or its HEX encoded address if it wasn't a saved validator. Eg: `"cosmosvaloper1c4k24jzduc365kywrsvf5ujz4ya6mwympnc4en"`
+    async fn proposer_address(&self) -> &str {
+        &self.block.proposer_address
+    }
+
+    /// Returns the proposer of the block, if it is a saved validator.
+    async fn proposer(&self, ctx: &Context<'_>) -> Result<Option<ValidatorObject>> {
+        let key = ValidatorByOperatorAddress {
+            chain: self.chain.config.name.clone(),
+            operator_address: self.block.proposer_address.clone(),
+        };
+
+        let validator = ctx.data::<DataLoader<ChainLoader>>()?.load_one(key).await?;
+
+        Ok(validator.map(|validator| ValidatorObject::new(&self.chain, validator)))
+    }
+
+    /// Returns the saved transactions of the block.
+    async fn txs(&self, ctx: &Context<'_>) -> Result<Vec<TransactionObject>> {
+        let key = TxsByHeight {
+            chain: self.chain.config.name.clone(),
+            height: self.block.height,
+        };
+
+        let txs = ctx.data::<DataLoader<ChainLoader>>()?.load_one(key).await?.unwrap_or_default();
+
+        Ok(txs.into_iter().map(|tx| TransactionObject::new(&self.chain, tx)).collect())
+    }
+}
+
+pub struct TransactionObject {
+    chain: Chain,
+    tx: TransactionForDb,
+}
+
+impl TransactionObject {
+    pub fn new(chain: &Chain, tx: TransactionForDb) -> Self {
+        Self { chain: chain.clone(), tx }
+    }
+}
+
+#[Object(name = "Transaction")]
+impl TransactionObject {
+    /// Transaction hash. Eg: `"7BF4A6A4A07D1F1B38EB0A2B6F1C42F96C3B6A8A5D2D86E4B6E8C1F0E4A7D2C1"`
+    async fn hash(&self) -> &str {
+        &self.tx.hash
+    }
+
+    async fn height(&self) -> u64 {
+        self.tx.height
+    }
+
+    /// The type of the first message. Eg: `"Delegate"`
+    #[graphql(name = "type")]
+    async fn tx_type(&self) -> &str {
+        &self.tx.tx_type
+    }
+
+    async fn message_types(&self) -> &[String] {
+        &self.tx.message_types
+    }
+
+    /// Eg: `"Success"`
+    async fn result(&self) -> &str {
+        &self.tx.result
+    }
+
+    async fn code(&self) -> u32 {
+        self.tx.code
+    }
+
+    async fn amount(&self) -> Json<&ChainAmountItem> {
+        Json(&self.tx.amount)
+    }
+
+    async fn fee(&self) -> Json<&ChainAmountItem> {
+        Json(&self.tx.fee)
+    }
+
+    /// Transaction timestamp in milliseconds.
+    async fn time(&self) -> i64 {
+        self.tx.time
+    }
+
+    /// Returns the fully decoded transaction.
+    async fn details(&self) -> Result<Json<serde_json::Value>> {
+        let tx = self.chain.get_stored_tx_by_hash(&self.tx.hash).await?.value;
+
+        Ok(Json(serde_json::to_value(tx)?))
+    }
+}
+
+pub struct ProposalObject {
+    chain: Chain,
+    proposal: InternalProposal,
+}
+
+#[Object(name = "Proposal")]
+impl ProposalObject {
+    /// Proposal ID. Eg: `79`
+    async fn id(&self) -> u64 {
+        self.proposal.id
+    }
+
+    async fn title(&self) -> &str {
+        &self.proposal.title
+    }
+
+    async fn summary(&self) -> &str {
+        &self.proposal.summary
+    }
+
+    /// Eg: `"voting_period"`
+    async fn status(&self) -> Result<Json<serde_json::Value>> {
+        Ok(Json(serde_json::to_value(&self.proposal.status)?))
+    }
+
+    async fn proposer(&self) -> Option<&str> {
+        self.proposal.proposer.as_deref()
+    }
+
+    /// Proposal submit timestamp in milliseconds.
+    async fn submit_time(&self) -> Option<i64> {
+        self.proposal.submit_time
+    }
+
+    /// Proposal voting end timestamp in milliseconds.
+    async fn voting_end_time(&self) -> Option<i64> {
+        self.proposal.voting_end_time
+    }
+
+    async fn total_deposit(&self) -> Json<&ChainAmountItem> {
+        Json(&self.proposal.total_deposit)
+    }
+
+    /// Returns the current tally of the proposal.
+    async fn tally(&self) -> Result<Json<serde_json::Value>> {
+        let tally = self.chain.get_proposal_tally(self.proposal.id).await?;
+
+        Ok(Json(serde_json::to_value(tally)?))
+    }
+
+    /// Returns the votes of the proposal.
+    async fn votes(&self, offset: Option<u64>, limit: Option<u64>) -> Result<Json<serde_json::Value>> {
+        let votes = self.chain.get_proposal_votes(self.proposal.id, pagination(offset, limit)).await?;
+
+        Ok(Json(serde_json::to_value(votes.data)?))
+    }
+}
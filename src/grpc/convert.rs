@@ -0,0 +1,108 @@
+use crate::database::{BlockForDb, EvmPollForDb, TransactionForDb, ValidatorForDb};
+use crate::fetch::proposals::InternalProposal;
+use crate::fetch::transactions::TransactionItem;
+use crate::routes::ChainAmountItem;
+
+use super::explorer::v1::{Amount, Block, Poll, Proposal, Tx, Validator};
+
+impl From<ChainAmountItem> for Amount {
+    fn from(value: ChainAmountItem) -> Self {
+        Self {
+            amount: value.amount.to_string(),
+            ticker: value.ticker,
+        }
+    }
+}
+
+impl From<BlockForDb> for Block {
+    fn from(value: BlockForDb) -> Self {
+        Self {
+            height: value.height,
+            hash: value.hash,
+            proposer_address: value.proposer_address,
+            proposer_name: value.proposer_name,
+            proposer_logo_url: value.proposer_logo_url,
+            tx_count: value.tx_count,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+impl From<TransactionForDb> for Tx {
+    fn from(value: TransactionForDb) -> Self {
+        Self {
+            hash: value.hash,
+            height: value.height,
+            r#type: value.tx_type,
+            message_types: value.message_types,
+            amount: Some(value.amount.into()),
+            fee: Some(value.fee.into()),
+            result: value.result,
+            code: value.code,
+            codespace: value.codespace,
+            time: value.time,
+        }
+    }
+}
+
+impl From<TransactionItem> for Tx {
+    fn from(value: TransactionItem) -> Self {
+        TransactionForDb::from(value).into()
+    }
+}
+
+impl From<ValidatorForDb> for Validator {
+    fn from(value: ValidatorForDb) -> Self {
+        Self {
+            operator_address: value.operator_address,
+            consensus_address: value.consensus_address,
+            hex_address: value.hex_address,
+            name: value.name,
+            logo_url: value.logo_url,
+            rank: value.rank,
+            is_active: value.is_active,
+            voting_power: value.voting_power,
+            voting_power_ratio: value.voting_power_ratio,
+            tokens: value.tokens,
+            uptime: value.uptime,
+            self_delegate_address: value.self_delegate_address,
+        }
+    }
+}
+
+impl From<InternalProposal> for Proposal {
+    fn from(value: InternalProposal) -> Self {
+        // The status is named the same as it is in the REST API, like `"voting_period"`.
+        let status = serde_json::to_value(&value.status)
+            .ok()
+            .and_then(|status| status.as_str().map(|status| status.to_string()))
+            .unwrap_or_default();
+
+        Self {
+            id: value.id,
+            title: value.title,
+            summary: value.summary,
+            status,
+            proposer: value.proposer.unwrap_or_default(),
+            submit_time: value.submit_time.unwrap_or_default(),
+            voting_start_time: value.voting_start_time.unwrap_or_default(),
+            voting_end_time: value.voting_end_time.unwrap_or_default(),
+            total_deposit: Some(value.total_deposit.into()),
+        }
+    }
+}
+
+impl From<EvmPollForDb> for Poll {
+    fn from(value: EvmPollForDb) -> Self {
+        Self {
+            poll_id: value.poll_id,
+            chain_name: value.chain_name,
+            status: value.status,
+            action: value.action,
+            evm_tx_id: value.evm_tx_id,
+            evm_deposit_address: value.evm_deposit_address,
+            tx_height: value.tx_height,
+            timestamp: value.timestamp,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+mod convert;
+mod service;
+
+use std::net::SocketAddr;
+
+use actix_web::web::Data;
+use tokio::sync::broadcast::Sender;
+use tonic::transport::Server;
+
+use crate::events::SequencedWsEvent;
+use crate::state::State;
+
+use explorer::v1::explorer_server::ExplorerServer;
+use service::ExplorerService;
+
+pub mod explorer {
+    pub mod v1 {
+        tonic::include_proto!("explorer.v1");
+    }
+}
+
+/// Runs the gRPC server of the explorer, defined at `proto/explorer/v1/explorer.proto`.\
+/// The streaming RPCs read the same socket events sent to the websocket clients, through the given channel.
+pub async fn run_grpc(state: Data<State>, tx: Sender<(String, SequencedWsEvent)>, address: SocketAddr) -> Result<(), String> {
+    Server::builder()
+        .add_service(ExplorerServer::new(ExplorerService::new(state, tx)))
+        .serve(address)
+        .await
+        .map_err(|e| format!("Error serving gRPC: {e}"))
+}
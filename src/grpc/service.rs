@@ -0,0 +1,268 @@
+use std::pin::Pin;
+
+use actix_web::web::Data;
+use futures::channel::mpsc;
+use futures::{SinkExt, Stream};
+use mongodb::bson::doc;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::broadcast::Sender;
+use tonic::{Request, Response, Status};
+
+use crate::chain::Chain;
+use crate::events::{replay_events, SequencedWsEvent, WsEvent};
+use crate::routes::PaginationData;
+use crate::state::State;
+
+use super::explorer::v1::explorer_server::Explorer;
+use super::explorer::v1::{
+    Block, BlockEvent, GetBlockRequest, GetPollRequest, GetProposalRequest, GetTxRequest, GetValidatorRequest, ListBlocksRequest, ListBlocksResponse,
+    ListPollsRequest, ListPollsResponse, ListTxsRequest, ListTxsResponse, ListValidatorsRequest, ListValidatorsResponse, Poll, Proposal,
+    StreamRequest, Tx, TxEvent, Validator,
+};
+
+/// The count of the items a list RPC returns if no limit is given.
+const GRPC_DEFAULT_LIMIT: u32 = 10;
+
+/// The maximum count of the items a list RPC returns.
+const GRPC_MAX_LIMIT: u32 = 100;
+
+/// The count of the events kept for a stream while the client is not reading them.
+const GRPC_STREAM_BUFFER_SIZE: usize = 100;
+
+/// The stream of the events sent to a client.
+type EventStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send>>;
+
+pub struct ExplorerService {
+    state: Data<State>,
+    /// The sender of the channel the broadcast sink sends the socket events to.
+    tx: Sender<(String, SequencedWsEvent)>,
+}
+
+impl ExplorerService {
+    /// Creates a new service over the chains of the state.
+    pub fn new(state: Data<State>, tx: Sender<(String, SequencedWsEvent)>) -> Self {
+        Self { state, tx }
+    }
+
+    /// Returns the chain with given name.
+    fn chain(&self, name: &str) -> Result<Chain, Status> {
+        self.state.get(name).map_err(Status::not_found)
+    }
+
+    /// Streams the events of the chain selected by `select`.\
+    /// The events after `since` are read from the event log first, the same as the websocket clients resume.
+    fn stream_events<T: Send + 'static>(&self, request: StreamRequest, select: fn(SequencedWsEvent) -> Option<T>) -> Result<EventStream<T>, Status> {
+        let chain = self.chain(&request.chain)?;
+
+        // The channel is subscribed before the replay, so the events produced during the replay are not lost.
+        let mut rx = self.tx.subscribe();
+        let (mut sender, receiver) = mpsc::channel(GRPC_STREAM_BUFFER_SIZE);
+
+        tokio::spawn(async move {
+            // The sequence number of the last event sent to the client.
+            let mut last_seq = request.since;
+            let mut pending = match request.since {
+                Some(since) => replay_events(&chain.database, since).await,
+                None => Ok(vec![]),
+            };
+
+            loop {
+                let events = match pending {
+                    Ok(events) => events,
+                    Err(e) => {
+                        let _ = sender.send(Err(Status::internal(e))).await;
+                        return;
+                    }
+                };
+
+                for event in events {
+                    last_seq = Some(event.seq);
+
+                    if let Some(item) = select(event) {
+                        // The client is gone if the receiver is dropped.
+                        if sender.send(Ok(item)).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+
+                pending = match rx.recv().await {
+                    Ok((chain_name, event)) => {
+                        if chain_name != chain.config.name || last_seq.map_or(false, |seq| event.seq <= seq) {
+                            Ok(vec![])
+                        } else {
+                            Ok(vec![event])
+                        }
+                    }
+                    // The skipped events are read from the event log, if any event is sent to the client before.
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!("gRPC stream of {} lagged behind {skipped} events", chain.config.name);
+
+                        match last_seq {
+                            Some(seq) => replay_events(&chain.database, seq).await,
+                            None => Ok(vec![]),
+                        }
+                    }
+                    Err(RecvError::Closed) => return,
+                };
+            }
+        });
+
+        Ok(Box::pin(receiver))
+    }
+}
+
+/// Returns the limit of a list RPC.
+fn limit_of(limit: u32) -> u32 {
+    match limit {
+        0 => GRPC_DEFAULT_LIMIT,
+        limit => limit.min(GRPC_MAX_LIMIT),
+    }
+}
+
+/// Returns `true` if the chain supports EVM polls.
+fn supports_polls(chain: &Chain) -> bool {
+    vec![String::from("axelar"), String::from("axelar-testnet")].contains(&chain.config.name)
+}
+
+#[tonic::async_trait]
+impl Explorer for ExplorerService {
+    async fn get_block(&self, request: Request<GetBlockRequest>) -> Result<Response<Block>, Status> {
+        let request = request.into_inner();
+        let chain = self.chain(&request.chain)?;
+
+        let block = match chain.database.find_block(doc! {"height": request.height as i64}).await {
+            Ok(block) => block,
+            Err(_) => chain.get_block_for_db_by_height(request.height).await?,
+        };
+
+        Ok(Response::new(block.into()))
+    }
+
+    async fn list_blocks(&self, request: Request<ListBlocksRequest>) -> Result<Response<ListBlocksResponse>, Status> {
+        let request = request.into_inner();
+        let chain = self.chain(&request.chain)?;
+
+        let blocks = chain.get_last_blocks_from_db(limit_of(request.limit) as u16).await?;
+
+        Ok(Response::new(ListBlocksResponse {
+            blocks: blocks.into_iter().map(Block::from).collect(),
+        }))
+    }
+
+    async fn get_tx(&self, request: Request<GetTxRequest>) -> Result<Response<Tx>, Status> {
+        let request = request.into_inner();
+        let chain = self.chain(&request.chain)?;
+
+        let tx = chain.database.find_transaction(doc! {"hash": request.hash}).await?;
+
+        Ok(Response::new(tx.into()))
+    }
+
+    async fn list_txs(&self, request: Request<ListTxsRequest>) -> Result<Response<ListTxsResponse>, Status> {
+        let request = request.into_inner();
+        let chain = self.chain(&request.chain)?;
+
+        let txs = chain.get_last_txs_from_db(limit_of(request.limit) as u16).await?;
+
+        Ok(Response::new(ListTxsResponse {
+            txs: txs.into_iter().map(Tx::from).collect(),
+        }))
+    }
+
+    async fn get_validator(&self, request: Request<GetValidatorRequest>) -> Result<Response<Validator>, Status> {
+        let request = request.into_inner();
+        let chain = self.chain(&request.chain)?;
+
+        let validator = chain.database.find_validator_by_operator_addr(&request.operator_address).await?;
+
+        Ok(Response::new(validator.into()))
+    }
+
+    async fn list_validators(&self, request: Request<ListValidatorsRequest>) -> Result<Response<ListValidatorsResponse>, Status> {
+        let request = request.into_inner();
+        let chain = self.chain(&request.chain)?;
+
+        let filter = request.active_only.then(|| doc! {"$match": {"is_active": true}});
+        let validators = chain.database.find_validators(filter).await?;
+
+        Ok(Response::new(ListValidatorsResponse {
+            validators: validators.into_iter().map(Validator::from).collect(),
+        }))
+    }
+
+    async fn get_proposal(&self, request: Request<GetProposalRequest>) -> Result<Response<Proposal>, Status> {
+        let request = request.into_inner();
+        let chain = self.chain(&request.chain)?;
+
+        let proposal = chain.get_proposal_details(request.id).await?;
+
+        Ok(Response::new(proposal.into()))
+    }
+
+    async fn get_poll(&self, request: Request<GetPollRequest>) -> Result<Response<Poll>, Status> {
+        let request = request.into_inner();
+        let chain = self.chain(&request.chain)?;
+
+        if !supports_polls(&chain) {
+            return Err(Status::unimplemented(format!("EVM polls not supported for {}", chain.config.name)));
+        }
+
+        let poll = chain.database.find_evm_poll(doc! {"poll_id": request.poll_id}).await?;
+
+        Ok(Response::new(poll.into()))
+    }
+
+    async fn list_polls(&self, request: Request<ListPollsRequest>) -> Result<Response<ListPollsResponse>, Status> {
+        let request = request.into_inner();
+        let chain = self.chain(&request.chain)?;
+
+        if !supports_polls(&chain) {
+            return Err(Status::unimplemented(format!("EVM polls not supported for {}", chain.config.name)));
+        }
+
+        let config = PaginationData {
+            cursor: Some(request.cursor).filter(|cursor| !cursor.is_empty()),
+            limit: Some(match request.limit {
+                0 => PaginationData::default().limit.unwrap_or_default(),
+                limit => limit.min(GRPC_MAX_LIMIT) as u64,
+            }),
+            ..Default::default()
+        };
+
+        let polls = chain.database.find_paginated_evm_polls(None, config).await?;
+
+        Ok(Response::new(ListPollsResponse {
+            polls: polls.data.into_iter().map(Poll::from).collect(),
+            next_cursor: polls.pagination.cursor.unwrap_or_default(),
+        }))
+    }
+
+    type StreamBlocksStream = EventStream<BlockEvent>;
+
+    async fn stream_blocks(&self, request: Request<StreamRequest>) -> Result<Response<Self::StreamBlocksStream>, Status> {
+        let stream = self.stream_events(request.into_inner(), |event| match event.event {
+            WsEvent::NewBLock(block) => Some(BlockEvent {
+                seq: event.seq,
+                block: Some(block.into()),
+            }),
+            _ => None,
+        })?;
+
+        Ok(Response::new(stream))
+    }
+
+    type StreamTxsStream = EventStream<TxEvent>;
+
+    async fn stream_txs(&self, request: Request<StreamRequest>) -> Result<Response<Self::StreamTxsStream>, Status> {
+        let stream = self.stream_events(request.into_inner(), |event| match event.event {
+            WsEvent::NewTX(tx) => Some(TxEvent {
+                seq: event.seq,
+                tx: Some(tx.into()),
+            }),
+            _ => None,
+        })?;
+
+        Ok(Response::new(stream))
+    }
+}
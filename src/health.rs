@@ -0,0 +1,98 @@
+use std::fmt::Display;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+
+use chrono::Utc;
+use dashmap::DashMap;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// The count of the missed intervals after which a cron job is reported as stale.
+const STALE_CRON_JOB_INTERVALS: i64 = 3;
+
+/// The records of the background work of a chain, read by the health routes.\
+/// The clones share the same records, so they are updated from the cron jobs and the event subscription.
+#[derive(Clone, Default)]
+pub struct HealthTracker {
+    cron_jobs: Arc<DashMap<&'static str, CronJobRun>>,
+    /// The time of the last event received from the websocket of the node, in milliseconds. It is 0 before the first event.
+    last_event_at: Arc<AtomicI64>,
+}
+
+/// The runs of a cron job.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CronJobRun {
+    /// The seconds between the runs.
+    pub interval_secs: u64,
+    /// Milliseconds since the Unix epoch. The job is expected to run since then.
+    #[serde(skip)]
+    registered_at: i64,
+    /// Milliseconds since the Unix epoch.
+    pub last_run_at: Option<i64>,
+    /// Milliseconds since the Unix epoch.
+    pub last_success_at: Option<i64>,
+    /// The error of the last run, if it failed.
+    pub last_error: Option<String>,
+}
+
+impl CronJobRun {
+    /// Returns `true` if the job hasn't succeeded for a few intervals.
+    pub fn is_stale(&self, now: i64) -> bool {
+        let since = self.last_success_at.unwrap_or(self.registered_at);
+
+        now - since > STALE_CRON_JOB_INTERVALS * self.interval_secs as i64 * 1000
+    }
+}
+
+impl HealthTracker {
+    /// Registers a cron job, before its first run.
+    pub fn register_cron_job(&self, job: &'static str, interval_secs: u64) {
+        self.cron_jobs.insert(
+            job,
+            CronJobRun {
+                interval_secs,
+                registered_at: Utc::now().timestamp_millis(),
+                last_run_at: None,
+                last_success_at: None,
+                last_error: None,
+            },
+        );
+    }
+
+    /// Records a run of a registered cron job.
+    pub fn record_cron_job<T, E: Display>(&self, job: &'static str, result: &Result<T, E>) {
+        let now = Utc::now().timestamp_millis();
+
+        if let Some(mut run) = self.cron_jobs.get_mut(job) {
+            run.last_run_at = Some(now);
+
+            match result {
+                Ok(_) => {
+                    run.last_success_at = Some(now);
+                    run.last_error = None;
+                }
+                Err(e) => run.last_error = Some(e.to_string()),
+            }
+        }
+    }
+
+    /// Records an event received from the websocket of the node.
+    pub fn record_event(&self) {
+        self.last_event_at.store(Utc::now().timestamp_millis(), Ordering::Relaxed);
+    }
+
+    /// Returns the registered cron jobs, sorted by name.
+    pub fn cron_jobs(&self) -> Vec<(&'static str, CronJobRun)> {
+        let mut jobs: Vec<(&'static str, CronJobRun)> = self.cron_jobs.iter().map(|run| (*run.key(), run.value().clone())).collect();
+        jobs.sort_by_key(|(job, _)| *job);
+        jobs
+    }
+
+    /// Returns the time of the last event received from the websocket of the node, in milliseconds.
+    pub fn last_event_at(&self) -> Option<i64> {
+        match self.last_event_at.load(Ordering::Relaxed) {
+            0 => None,
+            time => Some(time),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use futures::future::BoxFuture;
+use tokio::sync::broadcast::Sender;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio::sync::Mutex;
+
+use crate::database::{BlockForDb, DatabaseTR, EventLogEntryForDb, EvmPollForDb, EvmPollParticipantForDb};
+use crate::events::{SequencedWsEvent, WsEvent};
+use crate::fetch::transactions::TransactionItem;
+
+use super::IngestSink;
+
+/// The name of the counter that keeps the last sequence number reserved for the event log.
+const EVENT_LOG_COUNTER: &str = "event_log";
+
+/// The count of the sequence numbers reserved from the database at once.\
+/// The numbers not used before a restart are skipped, the clients only rely on the order of them.
+const EVENT_LOG_SEQUENCE_BATCH: u64 = 1000;
+
+/// The sink that writes the ingested data to the event log of the chain, and broadcasts it to the websocket clients.
+pub struct BroadcastSink {
+    database: DatabaseTR,
+    /// The sequence numbers reserved for the events. It is held while an event is numbered and queued, so the clients receive the events in order.
+    sequences: Mutex<SequenceRange>,
+    /// The events to write to the event log and broadcast, in the order of their sequence numbers.
+    log_tx: UnboundedSender<EventLogEntryForDb>,
+}
+
+/// The reserved sequence numbers left.
+#[derive(Default)]
+struct SequenceRange {
+    next: u64,
+    last: u64,
+}
+
+impl BroadcastSink {
+    /// Creates a new sink for the given chain, and starts writing its events to the event log.
+    pub fn new(chain_name: String, database: DatabaseTR, ws_tx: Sender<(String, SequencedWsEvent)>) -> Self {
+        let (log_tx, log_rx) = unbounded_channel();
+
+        tokio::spawn(write_event_log(chain_name, database.clone(), ws_tx, log_rx));
+
+        Self {
+            database,
+            sequences: Mutex::new(SequenceRange::default()),
+            log_tx,
+        }
+    }
+
+    /// Numbers the event, and queues it to be written to the event log and sent to the websocket clients.\
+    /// The database is only waited for when the reserved sequence numbers run out.
+    fn send(&self, event: WsEvent) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move {
+            let mut sequences = self.sequences.lock().await;
+
+            if sequences.next == 0 || sequences.next > sequences.last {
+                let last = self.database.reserve_sequences(EVENT_LOG_COUNTER, EVENT_LOG_SEQUENCE_BATCH).await?;
+
+                *sequences = SequenceRange {
+                    next: last - EVENT_LOG_SEQUENCE_BATCH + 1,
+                    last,
+                };
+            }
+
+            let seq = sequences.next;
+            sequences.next += 1;
+
+            self.log_tx
+                .send(EventLogEntryForDb { seq, event })
+                .map_err(|_| "The event log writer is stopped.".to_string())
+        })
+    }
+}
+
+/// Writes the queued events to the event log one by one, so the log has no gaps a replay could skip.\
+/// An event is sent to the websocket clients after it is written, so a lagged client replaying the log gets every event it skipped.
+/// Having no clients connected is not an error, so the result of the send is ignored.
+async fn write_event_log(
+    chain_name: String,
+    database: DatabaseTR,
+    ws_tx: Sender<(String, SequencedWsEvent)>,
+    mut log_rx: UnboundedReceiver<EventLogEntryForDb>,
+) {
+    while let Some(entry) = log_rx.recv().await {
+        let event = SequencedWsEvent {
+            seq: entry.seq,
+            event: entry.event.clone(),
+        };
+
+        if let Err(e) = database.add_event_log_entry(entry).await {
+            tracing::error!("{e}");
+        }
+
+        ws_tx.send((chain_name.clone(), event)).ok();
+    }
+}
+
+impl IngestSink for BroadcastSink {
+    fn name(&self) -> &'static str {
+        "broadcast"
+    }
+
+    fn on_block<'a>(&'a self, block: &'a BlockForDb) -> BoxFuture<'a, Result<(), String>> {
+        self.send(WsEvent::NewBLock(block.clone()))
+    }
+
+    fn on_tx<'a>(&'a self, tx: &'a TransactionItem) -> BoxFuture<'a, Result<(), String>> {
+        self.send(WsEvent::NewTX(tx.clone()))
+    }
+
+    fn on_poll<'a>(&'a self, poll: &'a EvmPollForDb, _participants: &'a [EvmPollParticipantForDb]) -> BoxFuture<'a, Result<(), String>> {
+        self.send(WsEvent::NewEvmPoll(poll.clone()))
+    }
+
+    fn on_poll_participant<'a>(&'a self, participant: &'a EvmPollParticipantForDb) -> BoxFuture<'a, Result<(), String>> {
+        self.send(WsEvent::UpdateEvmPollParticipant((participant.poll_id.clone(), participant.clone())))
+    }
+}
@@ -0,0 +1,35 @@
+use futures::future::BoxFuture;
+
+use crate::database::BlockForDb;
+use crate::fetch::transactions::TransactionItem;
+use crate::metrics::METRICS;
+
+use super::IngestSink;
+
+/// The sink that counts the ingested blocks and transactions of the chain in the metrics.
+pub struct MetricsSink {
+    chain_name: String,
+}
+
+impl MetricsSink {
+    /// Creates a new sink for the given chain.
+    pub fn new(chain_name: String) -> Self {
+        Self { chain_name }
+    }
+}
+
+impl IngestSink for MetricsSink {
+    fn name(&self) -> &'static str {
+        "metrics"
+    }
+
+    fn on_block<'a>(&'a self, _block: &'a BlockForDb) -> BoxFuture<'a, Result<(), String>> {
+        METRICS.inc_ingested_blocks(&self.chain_name);
+        Box::pin(async { Ok(()) })
+    }
+
+    fn on_tx<'a>(&'a self, _tx: &'a TransactionItem) -> BoxFuture<'a, Result<(), String>> {
+        METRICS.inc_ingested_txs(&self.chain_name);
+        Box::pin(async { Ok(()) })
+    }
+}
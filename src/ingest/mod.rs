@@ -0,0 +1,191 @@
+mod broadcast;
+mod metrics;
+mod mongo;
+mod webhook;
+
+use std::sync::Arc;
+
+use futures::future::{join_all, BoxFuture};
+use tokio::sync::broadcast::Sender;
+
+use crate::chain::Chain;
+use crate::database::{BlockEventForDb, BlockForDb, EvmPollForDb, EvmPollParticipantForDb, HeartbeatForDb, ProposalVoteForDb};
+use crate::events::SequencedWsEvent;
+use crate::fetch::evm::PollStatus;
+use crate::fetch::transactions::TransactionItem;
+
+pub use broadcast::BroadcastSink;
+pub use metrics::MetricsSink;
+pub use mongo::MongoSink;
+pub use webhook::WebhookSink;
+
+/// A consumer of the data ingested from the chain sockets.
+///
+/// Every method has a no-op default, so a sink only implements the events it is interested in.
+pub trait IngestSink: Send + Sync {
+    /// Returns the name of the sink, used in the logs.
+    fn name(&self) -> &'static str;
+
+    /// Returns whether an event is failed to be ingested when the sink fails.\
+    /// Only the required sinks hold back the socket checkpoint, so the event is replayed after a reconnect.
+    fn is_required(&self) -> bool {
+        false
+    }
+
+    /// Called when a block is finalized, with its signatures committed.
+    fn on_block<'a>(&'a self, _block: &'a BlockForDb) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Called when a block is committed, with its begin and end block events.
+    fn on_block_events<'a>(&'a self, _height: u64, _events: &'a [BlockEventForDb]) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Called when a transaction is included in a block.
+    fn on_tx<'a>(&'a self, _tx: &'a TransactionItem) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Called when a new EVM poll is started, with its initial participants.
+    fn on_poll<'a>(&'a self, _poll: &'a EvmPollForDb, _participants: &'a [EvmPollParticipantForDb]) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Called when the status of an EVM poll is changed.
+    fn on_poll_status<'a>(&'a self, _poll_id: &'a String, _status: &'a PollStatus) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Called when a participant of an EVM poll votes.
+    fn on_poll_participant<'a>(&'a self, _participant: &'a EvmPollParticipantForDb) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Called when a heartbeat period begins, with the heartbeats of all the validators marked as failed.
+    fn on_period_heartbeats<'a>(&'a self, _heartbeats: &'a [HeartbeatForDb]) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Called when a validator sends a heartbeat.
+    fn on_heartbeat<'a>(&'a self, _heartbeat: &'a HeartbeatForDb) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Called when a proposal is voted.
+    fn on_proposal_vote<'a>(&'a self, _vote: &'a ProposalVoteForDb) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// The sinks registered for a chain. Each event is passed to all the sinks concurrently.
+#[derive(Clone, Default)]
+pub struct IngestPipeline {
+    sinks: Vec<Arc<dyn IngestSink>>,
+}
+
+impl IngestPipeline {
+    /// Creates a new pipeline without any sinks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the pipeline with the given sink added.
+    pub fn with_sink(mut self, sink: impl IngestSink + 'static) -> Self {
+        self.sinks.push(Arc::new(sink));
+        self
+    }
+
+    /// Creates the default pipeline of a chain.
+    /// The data is saved to the database, and written to the event log and broadcasted to the websocket clients, and also posted to the webhooks if the chain has any.\
+    /// The blocks and the transactions are counted in the metrics.
+    pub fn for_chain(chain: &Chain, ws_tx: Sender<(String, SequencedWsEvent)>) -> Self {
+        let pipeline = Self::new()
+            .with_sink(MongoSink::new(chain.clone()))
+            .with_sink(BroadcastSink::new(chain.config.name.clone(), chain.database.clone(), ws_tx))
+            .with_sink(MetricsSink::new(chain.config.name.clone()));
+
+        if chain.config.webhooks.is_empty() {
+            return pipeline;
+        }
+
+        pipeline.with_sink(WebhookSink::new(
+            chain.config.name.clone(),
+            chain.client.clone(),
+            chain.database.clone(),
+            chain.config.webhooks.clone(),
+        ))
+    }
+
+    /// Passes a block to all the sinks.
+    pub async fn on_block<'a>(&'a self, block: &'a BlockForDb) -> Result<(), String> {
+        self.dispatch("block", |sink| sink.on_block(block)).await
+    }
+
+    /// Passes the begin and end block events of a block to all the sinks.
+    pub async fn on_block_events<'a>(&'a self, height: u64, events: &'a [BlockEventForDb]) -> Result<(), String> {
+        self.dispatch("block events", |sink| sink.on_block_events(height, events)).await
+    }
+
+    /// Passes a transaction to all the sinks.
+    pub async fn on_tx<'a>(&'a self, tx: &'a TransactionItem) -> Result<(), String> {
+        self.dispatch("tx", |sink| sink.on_tx(tx)).await
+    }
+
+    /// Passes a new EVM poll to all the sinks.
+    pub async fn on_poll<'a>(&'a self, poll: &'a EvmPollForDb, participants: &'a [EvmPollParticipantForDb]) -> Result<(), String> {
+        self.dispatch("poll", |sink| sink.on_poll(poll, participants)).await
+    }
+
+    /// Passes an EVM poll status change to all the sinks.
+    pub async fn on_poll_status<'a>(&'a self, poll_id: &'a String, status: &'a PollStatus) -> Result<(), String> {
+        self.dispatch("poll status", |sink| sink.on_poll_status(poll_id, status)).await
+    }
+
+    /// Passes an EVM poll vote to all the sinks.
+    pub async fn on_poll_participant<'a>(&'a self, participant: &'a EvmPollParticipantForDb) -> Result<(), String> {
+        self.dispatch("poll participant", |sink| sink.on_poll_participant(participant)).await
+    }
+
+    /// Passes the initial heartbeats of a period to all the sinks.
+    pub async fn on_period_heartbeats<'a>(&'a self, heartbeats: &'a [HeartbeatForDb]) -> Result<(), String> {
+        self.dispatch("period heartbeats", |sink| sink.on_period_heartbeats(heartbeats)).await
+    }
+
+    /// Passes a heartbeat to all the sinks.
+    pub async fn on_heartbeat<'a>(&'a self, heartbeat: &'a HeartbeatForDb) -> Result<(), String> {
+        self.dispatch("heartbeat", |sink| sink.on_heartbeat(heartbeat)).await
+    }
+
+    /// Passes a proposal vote to all the sinks.
+    pub async fn on_proposal_vote<'a>(&'a self, vote: &'a ProposalVoteForDb) -> Result<(), String> {
+        self.dispatch("proposal vote", |sink| sink.on_proposal_vote(vote)).await
+    }
+
+    /// Runs the given event handler on all the sinks. The errors are logged per sink, and the ones of the required sinks are returned together.
+    async fn dispatch<'a, F>(&'a self, event: &str, handler: F) -> Result<(), String>
+    where
+        F: Fn(&'a dyn IngestSink) -> BoxFuture<'a, Result<(), String>>,
+    {
+        let jobs = self.sinks.iter().map(|sink| {
+            let sink = sink.as_ref();
+            let job = handler(sink);
+            async move { job.await.map_err(|e| (sink.is_required(), format!("{}: {e}", sink.name()))) }
+        });
+
+        let mut errors = vec![];
+
+        for (is_required, error) in join_all(jobs).await.into_iter().filter_map(Result::err) {
+            tracing::error!("Error ingesting {event}, {error}");
+
+            if is_required {
+                errors.push(error);
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors.join(", ")),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use futures::future::{join_all, BoxFuture};
+
+use crate::cache::CacheRoute;
+use crate::chain::Chain;
+use crate::database::{BlockEventForDb, BlockForDb, EvmPollForDb, EvmPollParticipantForDb, HeartbeatForDb, ProposalVoteForDb, TransactionForDb};
+use crate::fetch::evm::PollStatus;
+use crate::fetch::transactions::TransactionItem;
+
+use super::IngestSink;
+
+/// The sink that saves the ingested data to the database of the chain.
+pub struct MongoSink {
+    chain: Chain,
+}
+
+impl MongoSink {
+    /// Creates a new sink for the given chain.
+    pub fn new(chain: Chain) -> Self {
+        Self { chain }
+    }
+}
+
+impl IngestSink for MongoSink {
+    fn name(&self) -> &'static str {
+        "mongo"
+    }
+
+    fn is_required(&self) -> bool {
+        true
+    }
+
+    fn on_block<'a>(&'a self, block: &'a BlockForDb) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async move {
+            self.chain.database.upsert_block(block.clone()).await?;
+            self.chain.cache.invalidate(&[CacheRoute::Stats]);
+
+            self.chain.update_ingestion_checkpoint(block.height).await?;
+
+            self.chain.store_block_signing(block).await
+        })
+    }
+
+    fn on_block_events<'a>(&'a self, height: u64, events: &'a [BlockEventForDb]) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(self.chain.database.upsert_block_events(height, events.to_vec()))
+    }
+
+    fn on_tx<'a>(&'a self, tx: &'a TransactionItem) -> BoxFuture<'a, Result<(), String>> {
+        let tx = TransactionForDb::from(tx.clone()).with_main_token(&self.chain.config.main_symbol, &self.chain.config.main_denom);
+
+        Box::pin(self.chain.database.upsert_transaction(tx))
+    }
+
+    fn on_poll<'a>(&'a self, poll: &'a EvmPollForDb, participants: &'a [EvmPollParticipantForDb]) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async move {
+            let participant_jobs = participants
+                .iter()
+                .map(|participant| self.chain.database.upsert_evm_poll_participant(participant.clone()));
+
+            join_all(participant_jobs).await.into_iter().collect::<Result<(), String>>()?;
+
+            self.chain.database.upsert_evm_poll(poll.clone()).await
+        })
+    }
+
+    fn on_poll_status<'a>(&'a self, poll_id: &'a String, status: &'a PollStatus) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(self.chain.database.update_evm_poll_status(poll_id, status))
+    }
+
+    fn on_poll_participant<'a>(&'a self, participant: &'a EvmPollParticipantForDb) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(self.chain.database.upsert_evm_poll_participant(participant.clone()))
+    }
+
+    fn on_period_heartbeats<'a>(&'a self, heartbeats: &'a [HeartbeatForDb]) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(self.chain.database.add_heartbeat_many(heartbeats.to_vec()))
+    }
+
+    fn on_heartbeat<'a>(&'a self, heartbeat: &'a HeartbeatForDb) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(self.chain.database.upsert_heartbeat(heartbeat.clone()))
+    }
+
+    fn on_proposal_vote<'a>(&'a self, vote: &'a ProposalVoteForDb) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(self.chain.database.add_propsal_vote(vote.clone()))
+    }
+}
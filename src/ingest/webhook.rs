@@ -0,0 +1,200 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use futures::future::BoxFuture;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::database::{
+    BlockEventForDb, BlockForDb, DatabaseTR, EvmPollForDb, EvmPollParticipantForDb, HeartbeatForDb, ProposalVoteForDb, WebhookDeadLetterForDb,
+};
+use crate::fetch::evm::PollStatus;
+use crate::fetch::transactions::TransactionItem;
+
+use super::IngestSink;
+
+/// The time limit of a single webhook request.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The count of the attempts made to post an event to a webhook, before it is saved as a dead letter.
+const WEBHOOK_MAX_ATTEMPTS: u32 = 5;
+
+/// The wait before the second attempt, doubled for each next attempt.
+const WEBHOOK_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// The sink that posts the ingested data as JSON to the webhook URLs of the chain.\
+/// The events are posted in the background and retried, so a slow or failing webhook doesn't hold back the ingestion.
+/// The events that can't be posted are saved to the webhook dead letters collection.
+pub struct WebhookSink {
+    chain_name: String,
+    client: reqwest::Client,
+    database: DatabaseTR,
+    urls: Vec<String>,
+}
+
+/// The body of a webhook request.
+#[derive(Serialize)]
+struct WebhookPayload<'a, T: Serialize> {
+    chain: &'a str,
+    event: &'a str,
+    data: T,
+}
+
+impl WebhookSink {
+    /// Creates a new sink for the given chain.
+    pub fn new(chain_name: String, client: reqwest::Client, database: DatabaseTR, urls: Vec<String>) -> Self {
+        Self {
+            chain_name,
+            client,
+            database,
+            urls,
+        }
+    }
+
+    /// Starts posting the event to all the webhook URLs.
+    fn post<'a, T: Serialize + Send + Sync + 'a>(&'a self, event: &'a str, data: T) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async move {
+            let payload = serde_json::to_value(WebhookPayload {
+                chain: &self.chain_name,
+                event,
+                data,
+            })
+            .map_err(|e| format!("Cannot serialize {event}: {e}"))?;
+
+            for url in &self.urls {
+                let delivery = WebhookDelivery {
+                    client: self.client.clone(),
+                    database: self.database.clone(),
+                    url: url.clone(),
+                    event: event.to_string(),
+                    payload: payload.clone(),
+                };
+
+                tokio::spawn(delivery.run());
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// An event posted to a webhook URL.
+struct WebhookDelivery {
+    client: reqwest::Client,
+    database: DatabaseTR,
+    url: String,
+    event: String,
+    payload: Value,
+}
+
+impl WebhookDelivery {
+    /// Posts the event until it succeeds, and saves it as a dead letter if all the attempts fail.
+    async fn run(self) {
+        let mut delay = WEBHOOK_RETRY_DELAY;
+        let mut attempt = 1;
+
+        let error = loop {
+            let error = match self.send().await {
+                Ok(()) => return,
+                Err(e) => e,
+            };
+
+            if attempt == WEBHOOK_MAX_ATTEMPTS {
+                break error;
+            }
+
+            tracing::warn!("{error}, retrying in {}s", delay.as_secs());
+            tokio::time::sleep(delay).await;
+
+            delay *= 2;
+            attempt += 1;
+        };
+
+        tracing::error!("{error}, giving up after {attempt} attempts");
+
+        let dead_letter = WebhookDeadLetterForDb {
+            url: self.url,
+            event: self.event,
+            payload: self.payload,
+            error,
+            attempts: attempt,
+            time: Utc::now().timestamp_millis(),
+        };
+
+        if let Err(e) = self.database.add_webhook_dead_letter(dead_letter).await {
+            tracing::error!("{e}");
+        }
+    }
+
+    /// Makes a single attempt to post the event.
+    async fn send(&self) -> Result<(), String> {
+        let resp = self
+            .client
+            .post(&self.url)
+            .timeout(WEBHOOK_TIMEOUT)
+            .json(&self.payload)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to post {} to {}: {e}", self.event, self.url))?;
+
+        if !resp.status().is_success() {
+            return Err(format!("Webhook {} responded {} for {}", self.url, resp.status(), self.event));
+        }
+
+        Ok(())
+    }
+}
+
+impl IngestSink for WebhookSink {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    fn on_block<'a>(&'a self, block: &'a BlockForDb) -> BoxFuture<'a, Result<(), String>> {
+        self.post("block", block)
+    }
+
+    fn on_block_events<'a>(&'a self, _height: u64, events: &'a [BlockEventForDb]) -> BoxFuture<'a, Result<(), String>> {
+        self.post("block_events", events)
+    }
+
+    fn on_tx<'a>(&'a self, tx: &'a TransactionItem) -> BoxFuture<'a, Result<(), String>> {
+        self.post("tx", tx)
+    }
+
+    fn on_poll<'a>(&'a self, poll: &'a EvmPollForDb, participants: &'a [EvmPollParticipantForDb]) -> BoxFuture<'a, Result<(), String>> {
+        #[derive(Serialize)]
+        struct NewPoll<'a> {
+            poll: &'a EvmPollForDb,
+            participants: &'a [EvmPollParticipantForDb],
+        }
+
+        self.post("poll", NewPoll { poll, participants })
+    }
+
+    fn on_poll_status<'a>(&'a self, poll_id: &'a String, status: &'a PollStatus) -> BoxFuture<'a, Result<(), String>> {
+        #[derive(Serialize)]
+        struct PollStatusChange<'a> {
+            poll_id: &'a String,
+            status: &'a PollStatus,
+        }
+
+        self.post("poll_status", PollStatusChange { poll_id, status })
+    }
+
+    fn on_poll_participant<'a>(&'a self, participant: &'a EvmPollParticipantForDb) -> BoxFuture<'a, Result<(), String>> {
+        self.post("poll_participant", participant)
+    }
+
+    fn on_period_heartbeats<'a>(&'a self, heartbeats: &'a [HeartbeatForDb]) -> BoxFuture<'a, Result<(), String>> {
+        self.post("period_heartbeats", heartbeats)
+    }
+
+    fn on_heartbeat<'a>(&'a self, heartbeat: &'a HeartbeatForDb) -> BoxFuture<'a, Result<(), String>> {
+        self.post("heartbeat", heartbeat)
+    }
+
+    fn on_proposal_vote<'a>(&'a self, vote: &'a ProposalVoteForDb) -> BoxFuture<'a, Result<(), String>> {
+        self.post("proposal_vote", vote)
+    }
+}
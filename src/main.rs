@@ -1,13 +1,22 @@
+use config::ServerConfig;
 use dotenvy::dotenv;
 use server::start_web_server;
 
+mod cache;
 mod chain;
+mod config;
 mod cron_jobs;
 mod database;
 mod encoding;
 mod events;
 mod fetch;
+mod graphql;
+mod grpc;
+mod health;
+mod ingest;
 mod macros;
+mod metrics;
+mod rate_limit;
 mod routes;
 mod server;
 mod state;
@@ -18,7 +27,15 @@ async fn main() {
     let _ = dotenv();
     tracing_subscriber::fmt::init();
 
+    let config = match ServerConfig::load() {
+        Ok(config) => config,
+        Err(e) => {
+            tracing::error!("Invalid server config:\n{e}");
+            std::process::exit(1);
+        }
+    };
+
     tracing::info!("Starting...");
-    start_web_server().await.unwrap();
+    start_web_server(config).await.unwrap();
     tracing::info!("Stopped!");
 }
@@ -0,0 +1,256 @@
+use std::future::{ready, Ready};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Instant;
+
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::Error;
+use futures::future::LocalBoxFuture;
+use mongodb::event::command::{CommandEventHandler, CommandFailedEvent, CommandSucceededEvent};
+use once_cell::sync::Lazy;
+use prometheus::{Encoder, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry, TextEncoder};
+
+use crate::routes::TNRAppError;
+
+/// The metrics of the server, served at `/metrics` in the Prometheus text format.
+pub static METRICS: Lazy<Metrics> = Lazy::new(Metrics::new);
+
+/// The buckets of the latencies in seconds, from 5 milliseconds to 30 seconds.
+const LATENCY_BUCKETS: [f64; 13] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 15.0, 30.0];
+
+/// The buckets of the cron job durations in seconds, the backfill of a big gap takes minutes.
+const CRON_JOB_BUCKETS: [f64; 10] = [0.1, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 300.0, 900.0];
+
+/// The label of the requests not matching any route, so the unknown paths don't create new series.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+pub struct Metrics {
+    registry: Registry,
+    /// The latencies of the REST routes, by method, route pattern and response status.
+    http_request_duration: HistogramVec,
+    /// The requests to the nodes, by chain, kind and outcome.
+    upstream_requests: IntCounterVec,
+    /// The latencies of the requests to the nodes, by chain and kind.
+    upstream_request_duration: HistogramVec,
+    /// The blocks passed to the ingestion sinks, by chain.
+    ingested_blocks: IntCounterVec,
+    /// The transactions passed to the ingestion sinks, by chain.
+    ingested_txs: IntCounterVec,
+    /// The events skipped by the lagging websocket connections, by chain.
+    ws_lagged_events: IntCounterVec,
+    /// The durations of the cron job runs, by chain, job and outcome.
+    cron_job_duration: HistogramVec,
+    /// The durations of the MongoDB commands, by command and outcome.
+    db_operation_duration: HistogramVec,
+}
+
+impl Metrics {
+    /// Creates the metrics and registers them.
+    fn new() -> Self {
+        let registry = Registry::new();
+
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "The latencies of the REST routes.").buckets(LATENCY_BUCKETS.to_vec()),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let upstream_requests = IntCounterVec::new(
+            Opts::new("upstream_requests_total", "The requests to the nodes of the chains."),
+            &["chain", "kind", "outcome"],
+        )
+        .unwrap();
+        let upstream_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "upstream_request_duration_seconds",
+                "The latencies of the requests to the nodes of the chains.",
+            )
+            .buckets(LATENCY_BUCKETS.to_vec()),
+            &["chain", "kind"],
+        )
+        .unwrap();
+        let ingested_blocks = IntCounterVec::new(Opts::new("ingested_blocks_total", "The blocks ingested from the chains."), &["chain"]).unwrap();
+        let ingested_txs = IntCounterVec::new(Opts::new("ingested_txs_total", "The transactions ingested from the chains."), &["chain"]).unwrap();
+        let ws_lagged_events = IntCounterVec::new(
+            Opts::new(
+                "ws_lagged_events_total",
+                "The events the websocket connections lagged behind the broadcast channel for.",
+            ),
+            &["chain"],
+        )
+        .unwrap();
+        let cron_job_duration = HistogramVec::new(
+            HistogramOpts::new("cron_job_duration_seconds", "The durations of the cron job runs.").buckets(CRON_JOB_BUCKETS.to_vec()),
+            &["chain", "job", "outcome"],
+        )
+        .unwrap();
+        let db_operation_duration = HistogramVec::new(
+            HistogramOpts::new("db_operation_duration_seconds", "The durations of the MongoDB commands.").buckets(LATENCY_BUCKETS.to_vec()),
+            &["command", "outcome"],
+        )
+        .unwrap();
+
+        registry.register(Box::new(http_request_duration.clone())).unwrap();
+        registry.register(Box::new(upstream_requests.clone())).unwrap();
+        registry.register(Box::new(upstream_request_duration.clone())).unwrap();
+        registry.register(Box::new(ingested_blocks.clone())).unwrap();
+        registry.register(Box::new(ingested_txs.clone())).unwrap();
+        registry.register(Box::new(ws_lagged_events.clone())).unwrap();
+        registry.register(Box::new(cron_job_duration.clone())).unwrap();
+        registry.register(Box::new(db_operation_duration.clone())).unwrap();
+
+        Self {
+            registry,
+            http_request_duration,
+            upstream_requests,
+            upstream_request_duration,
+            ingested_blocks,
+            ingested_txs,
+            ws_lagged_events,
+            cron_job_duration,
+            db_operation_duration,
+        }
+    }
+
+    /// Records a request to a REST route. `route` is the pattern of the matched route. Eg: `"/{chain}/block/{height}"`
+    pub fn observe_http_request(&self, method: &str, route: Option<&str>, status: u16, started_at: Instant) {
+        self.http_request_duration
+            .with_label_values(&[method, route.unwrap_or(UNMATCHED_ROUTE), &status.to_string()])
+            .observe(started_at.elapsed().as_secs_f64());
+    }
+
+    /// Records a request to a node of a chain. `kind` is the type of the node. Eg: `"rpc"`\
+    /// The outcome is `"ok"`, or the code of the error. Eg: `"upstream_timeout"`
+    pub fn observe_upstream_request<T>(&self, chain: &str, kind: &str, started_at: Instant, result: &Result<T, TNRAppError>) {
+        let outcome = match result {
+            Ok(_) => "ok",
+            Err(e) => e.error_type.code(),
+        };
+
+        self.observe_upstream_outcome(chain, kind, started_at, outcome);
+    }
+
+    /// Records a request to a node of a chain with given outcome.
+    pub fn observe_upstream_outcome(&self, chain: &str, kind: &str, started_at: Instant, outcome: &str) {
+        self.upstream_requests.with_label_values(&[chain, kind, outcome]).inc();
+        self.upstream_request_duration
+            .with_label_values(&[chain, kind])
+            .observe(started_at.elapsed().as_secs_f64());
+    }
+
+    /// Counts a block ingested from a chain.
+    pub fn inc_ingested_blocks(&self, chain: &str) {
+        self.ingested_blocks.with_label_values(&[chain]).inc();
+    }
+
+    /// Counts a transaction ingested from a chain.
+    pub fn inc_ingested_txs(&self, chain: &str) {
+        self.ingested_txs.with_label_values(&[chain]).inc();
+    }
+
+    /// Counts the events a websocket connection skipped, since it lagged behind the broadcast channel.
+    pub fn inc_ws_lagged_events(&self, chain: &str, skipped: u64) {
+        self.ws_lagged_events.with_label_values(&[chain]).inc_by(skipped);
+    }
+
+    /// Records a run of a cron job of a chain.
+    pub fn observe_cron_job(&self, chain: &str, job: &str, started_at: Instant, ok: bool) {
+        let outcome = match ok {
+            true => "ok",
+            false => "error",
+        };
+
+        self.cron_job_duration
+            .with_label_values(&[chain, job, outcome])
+            .observe(started_at.elapsed().as_secs_f64());
+    }
+
+    /// Returns all the metrics in the Prometheus text format.
+    pub fn encode(&self) -> Result<String, String> {
+        let mut buffer = vec![];
+
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .map_err(|e| format!("Cannot encode the metrics: {e}"))?;
+
+        String::from_utf8(buffer).map_err(|e| format!("Cannot encode the metrics: {e}"))
+    }
+}
+
+/// The handler of the MongoDB command events, it records the durations of the commands sent by `DatabaseTR`.
+pub struct DbCommandMetrics;
+
+impl DbCommandMetrics {
+    /// Returns the handler to be set to the options of the MongoDB client.
+    pub fn handler() -> Arc<dyn CommandEventHandler> {
+        Arc::new(Self)
+    }
+}
+
+impl CommandEventHandler for DbCommandMetrics {
+    fn handle_command_succeeded_event(&self, event: CommandSucceededEvent) {
+        METRICS
+            .db_operation_duration
+            .with_label_values(&[&event.command_name, "ok"])
+            .observe(event.duration.as_secs_f64());
+    }
+
+    fn handle_command_failed_event(&self, event: CommandFailedEvent) {
+        METRICS
+            .db_operation_duration
+            .with_label_values(&[&event.command_name, "error"])
+            .observe(event.duration.as_secs_f64());
+    }
+}
+
+/// The middleware that records the latencies of the REST routes.
+pub struct RequestMetrics;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestMetrics
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = RequestMetricsMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestMetricsMiddleware { service: Rc::new(service) }))
+    }
+}
+
+pub struct RequestMetricsMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestMetricsMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+
+        Box::pin(async move {
+            let started_at = Instant::now();
+            let method = req.method().to_string();
+
+            let result = service.call(req).await;
+
+            match &result {
+                Ok(res) => METRICS.observe_http_request(&method, res.request().match_pattern().as_deref(), res.status().as_u16(), started_at),
+                Err(e) => METRICS.observe_http_request(&method, None, e.as_response_error().status_code().as_u16(), started_at),
+            }
+
+            result
+        })
+    }
+}
@@ -0,0 +1,501 @@
+use std::future::{ready, Ready};
+use std::net::IpAddr;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use actix_web::body::EitherBody;
+use actix_web::dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header::RETRY_AFTER;
+use actix_web::http::Method;
+use actix_web::web::Bytes;
+use actix_web::{Error, HttpResponse};
+use dashmap::DashMap;
+use futures::future::LocalBoxFuture;
+use sha2::{Digest, Sha256};
+
+use crate::config::ServerConfig;
+use crate::database::{ApiKeyForDb, DatabaseTR};
+use crate::fetch::batch::BatchItem;
+use crate::routes::{TNRAppError, TNRAppErrorResponse, TNRAppErrorType};
+
+/// The header the clients send their API keys in.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// The time an API key is kept in memory before it is read from the database again.
+const API_KEY_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// The count of the API keys kept in memory, the unknown keys included.
+const MAX_CACHED_API_KEYS: usize = 10_000;
+
+/// The count of the buckets kept before the idle ones are dropped.
+const MAX_BUCKETS: usize = 100_000;
+
+/// The costs of the routes that make requests to the nodes or read a lot from the database, by their first path segment after the chain name.\
+/// The cross-chain routes and the top-level routes are matched by their first path segment. The other routes cost 1.
+const ROUTE_COSTS: [(&str, u32); 25] = [
+    // Free routes.
+    ("docs", 0),
+    ("openapi.json", 0),
+    ("ready", 0),
+    ("metrics", 0),
+    // Cross-chain routes.
+    ("all", 10),
+    ("search", 3),
+    ("graphql", 5),
+    ("health", 5),
+    // Upstream-heavy chain routes.
+    ("account", 3),
+    ("balances", 3),
+    ("block-headers", 5),
+    ("delegations", 3),
+    ("delegator-rewards", 3),
+    ("proposal-deposits", 3),
+    ("proposal-votes", 3),
+    ("redelegations", 3),
+    ("supplies", 3),
+    ("unbonding-delegations", 3),
+    ("validator-delegations", 5),
+    ("validator-delegator-pair", 3),
+    ("validator-redelegations", 5),
+    ("validator-set", 3),
+    ("validator-unbondings", 5),
+    ("validators-of", 3),
+    ("vesting", 3),
+];
+
+/// The cost of the exports, they read the whole history of an account.
+const EXPORT_COST: u32 = 20;
+
+/// The path of the batches, they cost as much as their items.
+const BATCH_PATH: &str = "/batch";
+
+/// Returns the cost of a request by its path.
+fn route_cost(path: &str) -> u32 {
+    if path.ends_with("/export") {
+        return EXPORT_COST;
+    }
+
+    let mut segments = path.trim_start_matches('/').split('/');
+    let first = segments.next().unwrap_or_default();
+    let second = segments.next().unwrap_or_default();
+
+    ROUTE_COSTS
+        .iter()
+        .find(|(segment, _)| *segment == first)
+        .or_else(|| ROUTE_COSTS.iter().find(|(segment, _)| *segment == second))
+        .map(|(_, cost)| *cost)
+        .unwrap_or(1)
+}
+
+/// Returns the cost of a batch by its body, the sum of the costs of its items like they are requested one by one.\
+/// A body that isn't a valid batch costs 1, it is rejected by the route.
+fn batch_cost(body: &[u8]) -> u32 {
+    match serde_json::from_slice::<Vec<BatchItem>>(body) {
+        Ok(items) => items.iter().map(|item| route_cost(&format!("/{}", item.route))).sum::<u32>().max(1),
+        Err(_) => 1,
+    }
+}
+
+/// Returns the hex encoded SHA-256 hash of an API key, the keys are saved by their hashes.
+pub fn hash_api_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+/// A token bucket, refilled continuously up to the requests per minute of its client.
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    /// Refills the bucket up to given capacity, and takes given cost from it.
+    fn take(&mut self, capacity: u32, cost: u32, now: Instant) -> Result<(), Rejection> {
+        let refill_per_sec = capacity as f64 / 60.0;
+
+        // Even a full bucket doesn't have the tokens, waiting never lets the request through.
+        if capacity > 0 && cost > capacity {
+            return Err(Rejection::OverCapacity { cost, capacity });
+        }
+
+        self.tokens = (self.tokens + now.duration_since(self.updated_at).as_secs_f64() * refill_per_sec).min(capacity as f64);
+        self.updated_at = now;
+
+        if self.tokens >= cost as f64 {
+            self.tokens -= cost as f64;
+            return Ok(());
+        }
+
+        match refill_per_sec > 0.0 {
+            true => Err(Rejection::RetryAfter(Duration::from_secs_f64(
+                (cost as f64 - self.tokens) / refill_per_sec,
+            ))),
+            false => Err(Rejection::RetryAfter(Duration::from_secs(60))),
+        }
+    }
+}
+
+/// The reason a request is not let through by the bucket of its client.
+#[derive(Debug, PartialEq)]
+enum Rejection {
+    /// The bucket has the tokens after the duration.
+    RetryAfter(Duration),
+    /// The request costs more than the requests per minute of the client.
+    OverCapacity { cost: u32, capacity: u32 },
+}
+
+impl Rejection {
+    /// Returns the response of the rejected request.
+    fn into_response(self) -> HttpResponse {
+        match self {
+            Rejection::RetryAfter(retry_after) => too_many_requests(retry_after),
+            Rejection::OverCapacity { cost, capacity } => HttpResponse::BadRequest().json(TNRAppErrorResponse {
+                code: TNRAppErrorType::BadRequestError.code().to_string(),
+                error: format!("The request costs {cost}, it can cost at most {capacity}. Split the batch into smaller ones."),
+            }),
+        }
+    }
+}
+
+/// The client a request is counted for.
+struct Client {
+    /// `"key:<key hash>"` for the keyed clients, `"ip:<address>"` for the anonymous ones.
+    id: String,
+    requests_per_minute: u32,
+}
+
+/// Returns the address of the client of a request, from the address of its connection and its `X-Forwarded-For` header.\
+/// The header is read only if the connection is from a trusted proxy. The addresses in it are read from the last one,
+/// and the first address that isn't a trusted proxy is the client, the ones before it can be set by the client itself.
+fn client_ip(peer: Option<IpAddr>, forwarded_for: Option<&str>, trusted_proxies: &[IpAddr]) -> Option<IpAddr> {
+    let peer = peer?;
+
+    if !trusted_proxies.contains(&peer) {
+        return Some(peer);
+    }
+
+    let mut client = peer;
+
+    for address in forwarded_for.unwrap_or_default().rsplit(',') {
+        match address.trim().parse::<IpAddr>() {
+            Ok(address) => {
+                client = address;
+                if !trusted_proxies.contains(&address) {
+                    break;
+                }
+            }
+            Err(_) => break,
+        }
+    }
+
+    Some(client)
+}
+
+/// Returns the response of a rate limited request.
+fn too_many_requests(retry_after: Duration) -> HttpResponse {
+    // The clients are asked to wait at least a second, the header has no fractions.
+    let retry_after = retry_after.as_secs().max(1);
+
+    HttpResponse::TooManyRequests()
+        .insert_header((RETRY_AFTER, retry_after.to_string()))
+        .json(TNRAppErrorResponse {
+            code: "rate_limited".to_string(),
+            error: format!("Too many requests, retry after {retry_after} seconds."),
+        })
+}
+
+/// The rate limiter shared by the workers of the server.
+pub struct RateLimiter {
+    /// The shared database, the API keys are read from.
+    database: DatabaseTR,
+    api_keys: DashMap<String, (Option<ApiKeyForDb>, Instant)>,
+    /// The proxies the `X-Forwarded-For` header is trusted from.
+    trusted_proxies: Vec<IpAddr>,
+    buckets: DashMap<String, Bucket>,
+    /// The requests per minute of the clients without an API key.
+    anonymous_rpm: u32,
+    /// The requests per minute of the clients with an API key, unless the key has its own limit.
+    keyed_rpm: u32,
+}
+
+impl RateLimiter {
+    /// Creates a new rate limiter with the tiers and the trusted proxies of the config.
+    pub fn new(database: DatabaseTR, config: &ServerConfig) -> Self {
+        Self {
+            database,
+            api_keys: DashMap::new(),
+            trusted_proxies: config.trusted_proxies.clone(),
+            buckets: DashMap::new(),
+            anonymous_rpm: config.rate_limit.anonymous_rpm,
+            keyed_rpm: config.rate_limit.keyed_rpm,
+        }
+    }
+
+    /// Returns the API key with given hash, from the memory if it is read recently.
+    async fn find_api_key(&self, key_hash: &str) -> Result<Option<ApiKeyForDb>, TNRAppError> {
+        if let Some(cached) = self.api_keys.get(key_hash) {
+            if cached.1.elapsed() < API_KEY_CACHE_TTL {
+                return Ok(cached.0.clone());
+            }
+        }
+
+        let api_key = match self.database.find_api_key(key_hash).await {
+            Ok(api_key) => Some(api_key),
+            Err(e) if e.error_type == TNRAppErrorType::NotFoundError => None,
+            Err(e) => return Err(e),
+        };
+
+        if self.api_keys.len() >= MAX_CACHED_API_KEYS {
+            self.api_keys.retain(|_, (_, read_at)| read_at.elapsed() < API_KEY_CACHE_TTL);
+        }
+
+        if self.api_keys.len() < MAX_CACHED_API_KEYS {
+            self.api_keys.insert(key_hash.to_string(), (api_key.clone(), Instant::now()));
+        }
+
+        Ok(api_key)
+    }
+
+    /// Returns the client of the request, charged by given cost.\
+    /// The error response is returned if the request has an unknown or a disabled API key, or the key cannot be read.
+    /// The failed key lookups are charged to the address of the client, so the keys cannot be guessed without a limit.
+    async fn identify(&self, req: &ServiceRequest, cost: u32) -> Result<Client, HttpResponse> {
+        if let Some(key) = req.headers().get(API_KEY_HEADER).and_then(|key| key.to_str().ok()) {
+            let key_hash = hash_api_key(key);
+
+            let api_key = self.find_api_key(&key_hash).await;
+
+            if let Ok(Some(api_key)) = &api_key {
+                if api_key.enabled {
+                    return Ok(Client {
+                        id: format!("key:{key_hash}"),
+                        requests_per_minute: api_key.requests_per_minute.unwrap_or(self.keyed_rpm),
+                    });
+                }
+            }
+
+            if let Err(rejection) = self.take(&self.anonymous_client(req), cost) {
+                return Err(rejection.into_response());
+            }
+
+            return match api_key {
+                Ok(_) => Err(HttpResponse::Unauthorized().json(TNRAppErrorResponse {
+                    code: TNRAppErrorType::UnauthorizedError.code().to_string(),
+                    error: "The API key is not valid.".to_string(),
+                })),
+                Err(e) => {
+                    tracing::error!("Cannot read the API key: {e}");
+                    Err(HttpResponse::InternalServerError().json(TNRAppErrorResponse {
+                        code: TNRAppErrorType::DbError.code().to_string(),
+                        error: "Cannot check the API key.".to_string(),
+                    }))
+                }
+            };
+        }
+
+        Ok(self.anonymous_client(req))
+    }
+
+    /// Returns the client of a request without an API key, identified by its address.
+    fn anonymous_client(&self, req: &ServiceRequest) -> Client {
+        let forwarded_for = req.headers().get("x-forwarded-for").and_then(|header| header.to_str().ok());
+        let ip = client_ip(req.peer_addr().map(|peer| peer.ip()), forwarded_for, &self.trusted_proxies);
+
+        Client {
+            id: format!("ip:{}", ip.map(|ip| ip.to_string()).unwrap_or("unknown".to_string())),
+            requests_per_minute: self.anonymous_rpm,
+        }
+    }
+
+    /// Takes given cost from the bucket of the client.\
+    /// Why the request is rejected is returned, if the bucket doesn't have the tokens now.
+    fn take(&self, client: &Client, cost: u32) -> Result<(), Rejection> {
+        let now = Instant::now();
+
+        if self.buckets.len() >= MAX_BUCKETS {
+            // A bucket idle for a minute is full again, so dropping it changes nothing.
+            self.buckets
+                .retain(|_, bucket| now.duration_since(bucket.updated_at) < Duration::from_secs(60));
+        }
+
+        let mut bucket = self.buckets.entry(client.id.clone()).or_insert(Bucket {
+            tokens: client.requests_per_minute as f64,
+            updated_at: now,
+        });
+
+        bucket.take(client.requests_per_minute, cost, now)
+    }
+}
+
+/// The middleware that authenticates the API keys and rate limits the clients.
+pub struct RateLimit {
+    limiter: Arc<RateLimiter>,
+}
+
+impl RateLimit {
+    pub fn new(limiter: Arc<RateLimiter>) -> Self {
+        Self { limiter }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RateLimit
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = RateLimitMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RateLimitMiddleware {
+            service: Rc::new(service),
+            limiter: self.limiter.clone(),
+        }))
+    }
+}
+
+pub struct RateLimitMiddleware<S> {
+    service: Rc<S>,
+    limiter: Arc<RateLimiter>,
+}
+
+impl<S, B> Service<ServiceRequest> for RateLimitMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let limiter = self.limiter.clone();
+
+        Box::pin(async move {
+            let cost = match req.method() == Method::POST && req.path() == BATCH_PATH {
+                true => {
+                    // The body is read to count the items, and given back to the route.
+                    let body = req.extract::<Bytes>().await?;
+                    let cost = batch_cost(&body);
+                    req.set_payload(Payload::from(body));
+                    cost
+                }
+                false => route_cost(req.path()),
+            };
+
+            // The CORS preflight requests are answered before this middleware, they are not counted anyway.
+            if cost == 0 || req.method() == Method::OPTIONS {
+                return service.call(req).await.map(ServiceResponse::map_into_left_body);
+            }
+
+            let client = match limiter.identify(&req, cost).await {
+                Ok(client) => client,
+                Err(response) => return Ok(req.into_response(response).map_into_right_body()),
+            };
+
+            if let Err(rejection) = limiter.take(&client, cost) {
+                return Ok(req.into_response(rejection.into_response()).map_into_right_body());
+            }
+
+            service.call(req).await.map(ServiceResponse::map_into_left_body)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn route_cost_of_chain_routes() {
+        assert_eq!(route_cost("/axelar/dashboard"), 1);
+        assert_eq!(route_cost("/axelar/block/100"), 1);
+        assert_eq!(route_cost("/axelar/account/axelar1abc"), 3);
+        assert_eq!(route_cost("/axelar/validator-delegations/axelarvaloper1abc"), 5);
+        assert_eq!(route_cost("/axelar/account/axelar1abc/export"), EXPORT_COST);
+    }
+
+    #[test]
+    fn route_cost_of_top_level_routes() {
+        assert_eq!(route_cost("/docs/"), 0);
+        assert_eq!(route_cost("/openapi.json"), 0);
+        assert_eq!(route_cost("/metrics"), 0);
+        assert_eq!(route_cost("/ready"), 0);
+        assert_eq!(route_cost("/health"), 5);
+        assert_eq!(route_cost("/all/dashboard"), 10);
+        assert_eq!(route_cost("/search"), 3);
+        assert_eq!(route_cost("/graphql"), 5);
+        assert_eq!(route_cost("/batch"), 1);
+        assert_eq!(route_cost("/"), 1);
+        assert_eq!(route_cost(""), 1);
+    }
+
+    #[test]
+    fn batch_cost_is_the_sum_of_its_items() {
+        let body = br#"[
+            { "chain": "axelar", "route": "dashboard" },
+            { "chain": "axelar", "route": "account", "params": { "account_address": "axelar1abc" } },
+            { "chain": "cosmos", "route": "validator-delegations" }
+        ]"#;
+
+        assert_eq!(batch_cost(body), 1 + 3 + 5);
+        assert_eq!(batch_cost(b"[]"), 1);
+        assert_eq!(batch_cost(b"not a batch"), 1);
+    }
+
+    #[test]
+    fn bucket_take_waits_for_the_missing_tokens() {
+        let now = Instant::now();
+        let mut bucket = Bucket {
+            tokens: 60.0,
+            updated_at: now,
+        };
+
+        assert_eq!(bucket.take(60, 50, now), Ok(()));
+        assert_eq!(bucket.take(60, 20, now), Err(Rejection::RetryAfter(Duration::from_secs(10))));
+        assert_eq!(bucket.take(60, 20, now + Duration::from_secs(10)), Ok(()));
+    }
+
+    #[test]
+    fn bucket_take_rejects_the_costs_over_capacity() {
+        let now = Instant::now();
+        let mut bucket = Bucket {
+            tokens: 60.0,
+            updated_at: now,
+        };
+
+        assert_eq!(bucket.take(60, 61, now), Err(Rejection::OverCapacity { cost: 61, capacity: 60 }));
+        assert_eq!(bucket.take(60, 60, now), Ok(()));
+        assert_eq!(bucket.take(0, 1, now), Err(Rejection::RetryAfter(Duration::from_secs(60))));
+    }
+
+    #[test]
+    fn client_ip_ignores_forwarded_for_of_untrusted_peers() {
+        let peer: IpAddr = "203.0.113.7".parse().unwrap();
+
+        assert_eq!(client_ip(Some(peer), Some("198.51.100.1"), &[]), Some(peer));
+        assert_eq!(client_ip(Some(peer), Some("198.51.100.1"), &["10.0.0.2".parse().unwrap()]), Some(peer));
+        assert_eq!(client_ip(None, Some("198.51.100.1"), &[]), None);
+    }
+
+    #[test]
+    fn client_ip_reads_forwarded_for_of_trusted_proxies() {
+        let proxies: Vec<IpAddr> = vec!["10.0.0.2".parse().unwrap(), "10.0.0.3".parse().unwrap()];
+        let peer = Some(proxies[0]);
+
+        assert_eq!(client_ip(peer, Some("198.51.100.1"), &proxies), "198.51.100.1".parse().ok());
+        // The addresses before the client are set by the client itself.
+        assert_eq!(
+            client_ip(peer, Some("192.0.2.9, 198.51.100.1, 10.0.0.3"), &proxies),
+            "198.51.100.1".parse().ok()
+        );
+        assert_eq!(client_ip(peer, Some("not-an-ip, 10.0.0.3"), &proxies), Some(proxies[1]));
+        assert_eq!(client_ip(peer, None, &proxies), peer);
+    }
+}
@@ -1,23 +1,160 @@
-use crate::{routes::QueryParams, state::State};
+use crate::chain::Chain;
+use crate::fetch::export::{ExportFilter, ExportKind, ExportRow};
+use crate::state::State;
 use actix_web::{
+    error::ErrorInternalServerError,
     get,
-    web::{Data, Path, Query},
-    Responder,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    web::{Bytes, Data, Path, Query},
+    HttpResponse, Responder,
 };
+use futures::{stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 use crate::routes::{extract_chain, PaginationData, TNRAppError, TNRAppSuccessResponse};
 
 // ======== Account Methods ========
 
 //account address => evmos198zkgedxs9f77ru80zd3g693dhpv6n5wej6d7p
+#[utoipa::path(
+    get,
+    path = "/{chain}/account/{account_address}",
+    tag = "account",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("account_address" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::AccountResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/account/{account_address}")]
 pub async fn account(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, account_address) = path.into_inner();
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.get_account_info(&account_address).await?;
+    account_response(&chain, &account_address).await
+}
+
+/// Returns the response of `{chain}/account`, it is shared with the batches.
+pub async fn account_response(chain: &Chain, address: &str) -> Result<TNRAppSuccessResponse<impl Serialize>, TNRAppError> {
+    let data = chain.get_account_info(address).await?;
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/account/{account_address}/txs",
+    tag = "account",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("account_address" = String, Path),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::TransactionListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[get("{chain}/account/{account_address}/txs")]
+pub async fn account_txs(path: Path<(String, String)>, chains: Data<State>, query: Query<PaginationData>) -> Result<impl Responder, TNRAppError> {
+    let (chain, account_address) = path.into_inner();
+    let chain = extract_chain(&chain, chains)?;
+    let data = chain.get_address_txs(&account_address, None, query.into_inner()).await?;
+    Ok(TNRAppSuccessResponse::from(data))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{chain}/account/{account_address}/export",
+    tag = "account",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("account_address" = String, Path),
+        ExportQueryParams,
+    ),
+    responses(
+        (status = 200, description = "CSV or newline delimited JSON file, streamed.", content_type = "text/csv"),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[get("{chain}/account/{account_address}/export")]
+pub async fn account_export(
+    path: Path<(String, String)>,
+    chains: Data<State>,
+    query: Query<ExportQueryParams>,
+) -> Result<impl Responder, TNRAppError> {
+    let (chain, account_address) = path.into_inner();
+    let chain = extract_chain(&chain, chains)?;
+    let query = query.into_inner();
+
+    let filter = ExportFilter {
+        kind: query.kind,
+        from: query.from,
+        to: query.to,
+    };
+
+    let file_name = format!(
+        "{}-{account_address}-{}.{}",
+        chain.config.name,
+        query.kind.as_str(),
+        query.format.extension()
+    );
+    let format = query.format;
+
+    let rows = chain.export_address_history(account_address, filter).map(move |page| {
+        let page = page.map_err(|e| {
+            tracing::error!("Export failed: {e}");
+            ErrorInternalServerError(e)
+        })?;
+
+        let mut chunk = String::new();
+        for row in page {
+            match format {
+                ExportFormat::Csv => chunk.push_str(&row.to_csv_line()),
+                ExportFormat::Ndjson => chunk.push_str(&row.to_ndjson_line().map_err(ErrorInternalServerError)?),
+            }
+        }
+
+        Ok::<Bytes, actix_web::Error>(Bytes::from(chunk))
+    });
+
+    let header = match format {
+        ExportFormat::Csv => ExportRow::CSV_HEADER,
+        ExportFormat::Ndjson => "",
+    };
+
+    Ok(HttpResponse::Ok()
+        .content_type(format.content_type())
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(file_name)],
+        })
+        .streaming(stream::once(async move { Ok::<Bytes, actix_web::Error>(Bytes::from(header)) }).chain(rows)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{chain}/vesting/{account_address}",
+    tag = "account",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("account_address" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::VestingAccountResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/vesting/{account_address}")]
 pub async fn account_vesting(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, account_address) = path.into_inner();
@@ -26,17 +163,80 @@ pub async fn account_vesting(path: Path<(String, String)>, chains: Data<State>)
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/balances/{account_address}",
+    tag = "account",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("account_address" = String, Path),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::AmountListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/balances/{account_address}")]
-pub async fn account_balances(path: Path<(String, String)>, chains: Data<State>, _query: Query<QueryParams>) -> Result<impl Responder, TNRAppError> {
+pub async fn account_balances(
+    path: Path<(String, String)>,
+    chains: Data<State>,
+    query: Query<PaginationData>,
+) -> Result<impl Responder, TNRAppError> {
     let (chain, account_address) = path.into_inner();
-    let config = PaginationData {
-        cursor: None,
-        offset: None,
-        limit: Some(1000),
-        direction: None,
-    };
+
     let chain = extract_chain(&chain, chains)?;
+    balances_response(&chain, &account_address, query.into_inner()).await
+}
 
-    let data = chain.get_account_balances(&account_address, config).await?;
-    Ok(TNRAppSuccessResponse::new(data, None))
+/// Returns the response of `{chain}/balances`, it is shared with the batches.
+pub async fn balances_response(chain: &Chain, address: &str, config: PaginationData) -> Result<TNRAppSuccessResponse<impl Serialize>, TNRAppError> {
+    let data = chain.get_account_balances(address, config).await?;
+    Ok(TNRAppSuccessResponse::from(data))
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    Csv,
+    Ndjson,
+}
+
+impl Default for ExportFormat {
+    fn default() -> Self {
+        Self::Csv
+    }
+}
+
+impl ExportFormat {
+    fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Ndjson => "application/x-ndjson",
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Ndjson => "ndjson",
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportQueryParams {
+    /// The file format. Defaults to `csv`.
+    #[serde(default)]
+    pub format: ExportFormat,
+    /// The rows to export. Defaults to `txs`.
+    #[serde(default)]
+    pub kind: ExportKind,
+    /// The time in milliseconds the export starts from, inclusive.
+    pub from: Option<i64>,
+    /// The time in milliseconds the export ends at, inclusive.
+    pub to: Option<i64>,
 }
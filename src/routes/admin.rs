@@ -0,0 +1,198 @@
+use actix_web::web::{Data, Json, Path};
+use actix_web::{delete, get, post, put, HttpRequest};
+
+use crate::chain::IntermediateChainConfig;
+use crate::database::ApiKeyForDb;
+use crate::rate_limit::{hash_api_key, API_KEY_HEADER};
+use crate::routes::{TNRAppError, TNRAppErrorType, TNRAppSuccessResponse};
+use crate::state::{ChainEntry, State};
+
+// ======== Admin Methods ========
+
+/// Returns the API key of the request, if it is an enabled admin key.
+async fn authorize_admin(req: &HttpRequest, state: &State) -> Result<ApiKeyForDb, TNRAppError> {
+    let key = req
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|key| key.to_str().ok())
+        .ok_or_else(|| TNRAppError::new(TNRAppErrorType::UnauthorizedError, "The admin routes require an API key."))?;
+
+    let api_key = match state.database().find_api_key(&hash_api_key(key)).await {
+        Ok(api_key) if api_key.enabled => api_key,
+        Ok(_) => return Err(TNRAppError::new(TNRAppErrorType::UnauthorizedError, "The API key is not valid.")),
+        Err(e) if e.error_type == TNRAppErrorType::NotFoundError => {
+            return Err(TNRAppError::new(TNRAppErrorType::UnauthorizedError, "The API key is not valid."))
+        }
+        Err(e) => return Err(e),
+    };
+
+    match api_key.admin {
+        true => Ok(api_key),
+        false => Err(TNRAppError::new(TNRAppErrorType::ForbiddenError, "The API key is not an admin key.")),
+    }
+}
+
+/// The registered chains with their configs and statuses, including the ones failed to initialize.
+#[utoipa::path(
+    get,
+    path = "/admin/chains",
+    tag = "admin",
+    responses(
+        (status = 200, body = crate::routes::ChainEntryListResponse),
+        (status = 401, body = crate::routes::TNRAppErrorResponse),
+        (status = 403, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[get("admin/chains")]
+pub async fn admin_chains(req: HttpRequest, state: Data<State>) -> Result<TNRAppSuccessResponse<Vec<ChainEntry>>, TNRAppError> {
+    authorize_admin(&req, &state).await?;
+
+    Ok(TNRAppSuccessResponse::new(state.get_chain_entries(), None))
+}
+
+/// Adds a chain, or replaces the config of a chain. The chain is started once it is initialized, unless it is stopped.\
+/// A new chain is retried in the background if it fails to initialize, an initialized chain keeps its current config.
+#[utoipa::path(
+    put,
+    path = "/admin/chains/{chain}",
+    tag = "admin",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    request_body = IntermediateChainConfig,
+    responses(
+        (status = 200, body = crate::routes::ChainEntryResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 401, body = crate::routes::TNRAppErrorResponse),
+        (status = 403, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[put("admin/chains/{chain}")]
+pub async fn admin_upsert_chain(
+    req: HttpRequest,
+    path: Path<String>,
+    state: Data<State>,
+    body: Json<IntermediateChainConfig>,
+) -> Result<TNRAppSuccessResponse<ChainEntry>, TNRAppError> {
+    authorize_admin(&req, &state).await?;
+
+    let chain = path.into_inner();
+    let config = body.into_inner();
+
+    if config.name != chain {
+        return Err(TNRAppError::new(
+            TNRAppErrorType::BadRequestError,
+            format!("The name in the config must be `{chain}`."),
+        ));
+    }
+
+    let entry = state.upsert_chain(&chain, config).await?;
+
+    Ok(TNRAppSuccessResponse::new(entry, None))
+}
+
+/// Stops a chain and removes its config. The data of the chain is kept in the database.
+#[utoipa::path(
+    delete,
+    path = "/admin/chains/{chain}",
+    tag = "admin",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    responses(
+        (status = 200, body = crate::routes::StringResponse),
+        (status = 401, body = crate::routes::TNRAppErrorResponse),
+        (status = 403, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[delete("admin/chains/{chain}")]
+pub async fn admin_remove_chain(req: HttpRequest, path: Path<String>, state: Data<State>) -> Result<TNRAppSuccessResponse<String>, TNRAppError> {
+    authorize_admin(&req, &state).await?;
+
+    let chain = path.into_inner();
+
+    state.remove_chain(&chain).await?;
+
+    Ok(TNRAppSuccessResponse::new(chain, None))
+}
+
+/// Starts the ingestion and the cron jobs of a chain.
+#[utoipa::path(
+    post,
+    path = "/admin/chains/{chain}/start",
+    tag = "admin",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ChainEntryResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 401, body = crate::routes::TNRAppErrorResponse),
+        (status = 403, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[post("admin/chains/{chain}/start")]
+pub async fn admin_start_chain(req: HttpRequest, path: Path<String>, state: Data<State>) -> Result<TNRAppSuccessResponse<ChainEntry>, TNRAppError> {
+    authorize_admin(&req, &state).await?;
+
+    let chain = path.into_inner();
+
+    state.start_chain(&chain).await?;
+
+    Ok(TNRAppSuccessResponse::new(state.get_chain_entry(&chain)?, None))
+}
+
+/// Stops the ingestion and the cron jobs of a chain. The chain is still served from the database and its nodes.
+#[utoipa::path(
+    post,
+    path = "/admin/chains/{chain}/stop",
+    tag = "admin",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ChainEntryResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 401, body = crate::routes::TNRAppErrorResponse),
+        (status = 403, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[post("admin/chains/{chain}/stop")]
+pub async fn admin_stop_chain(req: HttpRequest, path: Path<String>, state: Data<State>) -> Result<TNRAppSuccessResponse<ChainEntry>, TNRAppError> {
+    authorize_admin(&req, &state).await?;
+
+    let chain = path.into_inner();
+
+    state.stop_chain(&chain).await?;
+
+    Ok(TNRAppSuccessResponse::new(state.get_chain_entry(&chain)?, None))
+}
+
+/// Initializes a chain again with its config, like after its node is upgraded. It is restarted if it was running.\
+/// The chain keeps running if it fails to initialize again.
+#[utoipa::path(
+    post,
+    path = "/admin/chains/{chain}/reload",
+    tag = "admin",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ChainEntryResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 401, body = crate::routes::TNRAppErrorResponse),
+        (status = 403, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[post("admin/chains/{chain}/reload")]
+pub async fn admin_reload_chain(req: HttpRequest, path: Path<String>, state: Data<State>) -> Result<TNRAppSuccessResponse<ChainEntry>, TNRAppError> {
+    authorize_admin(&req, &state).await?;
+
+    let entry = state.reload_chain(&path.into_inner()).await?;
+
+    Ok(TNRAppSuccessResponse::new(entry, None))
+}
@@ -0,0 +1,64 @@
+use actix_web::{
+    get,
+    web::{Data, Path, Query},
+    Responder,
+};
+
+use crate::routes::{LastCountListsQueryParams, TNRAppError, TNRAppSuccessResponse};
+use crate::state::State;
+
+// ======== Cross-chain Methods ========
+
+/// The dashboard info of every chain, with the totals of the chains that responded.
+#[utoipa::path(
+    get,
+    path = "/all/dashboard",
+    tag = "all_chains",
+    responses(
+        (status = 200, body = crate::routes::AllChainsDashboardResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[get("all/dashboard")]
+pub async fn all_dashboard(chains: Data<State>) -> Result<impl Responder, TNRAppError> {
+    let data = chains.get_all_dashboard_info().await;
+    Ok(TNRAppSuccessResponse::new(data, None))
+}
+
+/// The last saved blocks of every chain. Maximum count is 20.
+#[utoipa::path(
+    get,
+    path = "/all/latest-blocks",
+    tag = "all_chains",
+    params(LastCountListsQueryParams),
+    responses(
+        (status = 200, body = crate::routes::AllChainsBlocksResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[get("all/latest-blocks")]
+pub async fn all_latest_blocks(chains: Data<State>, query: Query<LastCountListsQueryParams>) -> Result<impl Responder, TNRAppError> {
+    let default_count = 5;
+    let count = query.count.unwrap_or(default_count).min(20);
+    let data = chains.get_all_latest_blocks(count).await;
+    Ok(TNRAppSuccessResponse::new(data, None))
+}
+
+/// The validators with the given moniker or Keybase identity on every chain.
+#[utoipa::path(
+    get,
+    path = "/all/validators/{moniker_or_identity}",
+    tag = "all_chains",
+    params(("moniker_or_identity" = String, Path, description = "Validator moniker or Keybase identity. Eg: `367960C067E253A4`")),
+    responses(
+        (status = 200, body = crate::routes::AllChainsValidatorsResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[get("all/validators/{moniker_or_identity}")]
+pub async fn all_validators(path: Path<String>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
+    let moniker_or_identity = path.into_inner();
+
+    let data = chains.get_validators_across_chains(moniker_or_identity.trim()).await;
+    Ok(TNRAppSuccessResponse::new(data, None))
+}
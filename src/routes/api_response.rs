@@ -1,20 +1,77 @@
 use actix_web::body::BoxBody;
-use actix_web::http::header::ContentType;
+use actix_web::http::header::{CacheControl, CacheDirective, ContentType, ETag, EntityTag, Header, IfNoneMatch};
 use actix_web::http::StatusCode;
 use actix_web::{HttpRequest, HttpResponse, Responder, ResponseError};
+use base64::{engine::general_purpose::STANDARD, Engine};
 use mongodb_cursor_pagination::CursorDirections;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::fmt;
 use std::string::ParseError;
+use std::time::Duration;
+use utoipa::{IntoParams, ToSchema};
 
-#[derive(Debug)]
+use crate::database::{BlockEventForDb, BlockForDb, EvmPollParticipantForDb, HeartbeatForDb, SupplyPage, TransactionForDb, ValidatorPage};
+use crate::fetch::account::{AccountInfo, InternalVestingAccount};
+use crate::fetch::all_chains::{AllChainsDashboard, ChainBlocksOutcome, ChainValidatorsOutcome};
+use crate::fetch::batch::BatchItemResult;
+use crate::fetch::blocks::{InternalBlockchainResp, ValidatorSignatureListElement};
+use crate::fetch::chain::{ChainDashboardInfoResponse, ChainStatsInfoResponse};
+use crate::fetch::delegations::{InternalDelegation, InternalRedelegation, InternalUnbonding};
+use crate::fetch::delegators::InternalDelegatorRewards;
+use crate::fetch::evm::EvmPollRespElement;
+use crate::fetch::health::HealthReport;
+use crate::fetch::params::ChainParams;
+use crate::fetch::proposals::{InternalProposal, InternalProposalDeposit, InternalProposalFinalTallyResult, InternalProposalVote, ProposalItem};
+use crate::fetch::search::SearchResponse;
+use crate::fetch::transactions::TransactionItem;
+use crate::fetch::validator_signing::{ValidatorMissedBlocks, ValidatorSigningBucket, ValidatorUptimePeriods};
+use crate::fetch::validators::{
+    InternalDelegation as ValidatorInternalDelegation, InternalRedelegation as ValidatorInternalRedelegation, InternalSlashingSigningInfoItem,
+    InternalUnbonding as ValidatorInternalUnbonding, InternalValidator, ValidatorCommisionResp, ValidatorListResp, ValidatorListValidator,
+    ValidatorResp, ValidatorRewardsResp,
+};
+use crate::routes::{
+    ChainAmountItem, ChainResponseItem, OutRestBlock, OutRestCommunityPool, OutRestStakingPool, OutRestTransaction, OutRestValidatorSet,
+};
+use crate::state::ChainEntry;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TNRAppErrorType {
-    // DbError,
+    /// An unexpected error of the server.
     MessageError,
-    // ForbiddenError,
+    /// The request has no API key, or the key is not valid.
+    UnauthorizedError,
+    /// The API key is not allowed to make the request, like a non-admin key on the admin routes.
+    ForbiddenError,
     // ParseError,
+    /// The request is not valid, like an invalid address.
+    BadRequestError,
     NotFoundError,
-    // ValidationError,
+    /// The node of the chain cannot be reached, or it returned an invalid response.
+    UpstreamUnavailableError,
+    /// The node of the chain didn't respond in time.
+    UpstreamTimeoutError,
+    DbError,
+    /// The chain doesn't support the request, like the EVM polls of a chain without them.
+    UnsupportedError,
+}
+
+impl TNRAppErrorType {
+    /// Returns the machine-readable code of the error type. The codes don't change, the clients can match them.
+    pub fn code(&self) -> &'static str {
+        match self {
+            TNRAppErrorType::MessageError => "internal_error",
+            TNRAppErrorType::UnauthorizedError => "unauthorized",
+            TNRAppErrorType::ForbiddenError => "forbidden",
+            TNRAppErrorType::BadRequestError => "bad_request",
+            TNRAppErrorType::NotFoundError => "not_found",
+            TNRAppErrorType::UpstreamUnavailableError => "upstream_unavailable",
+            TNRAppErrorType::UpstreamTimeoutError => "upstream_timeout",
+            TNRAppErrorType::DbError => "db_error",
+            TNRAppErrorType::UnsupportedError => "unsupported",
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -23,13 +80,22 @@ pub struct TNRAppError {
     pub error_type: TNRAppErrorType,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct TNRAppErrorResponse {
+    /// Machine-readable error code. Eg: `"not_found"`
+    pub code: String,
     pub error: String,
 }
 
 impl TNRAppError {
-    fn message(&self) -> String {
+    pub fn new(error_type: TNRAppErrorType, message: impl Into<String>) -> Self {
+        Self {
+            message: Some(message.into()),
+            error_type,
+        }
+    }
+
+    pub fn message(&self) -> String {
         match self {
             TNRAppError {
                 message: Some(message),
@@ -42,11 +108,32 @@ impl TNRAppError {
             _ => "An unexpected error has occured".to_string(),
         }
     }
+
+    /// Returns the error of a failed request to a node.
+    pub fn from_request_error(url: &str, error: reqwest::Error) -> Self {
+        match error.is_timeout() {
+            true => Self::new(TNRAppErrorType::UpstreamTimeoutError, format!("`{url}` didn't respond in time.")),
+            false => Self::new(TNRAppErrorType::UpstreamUnavailableError, format!("Cannot make a request to `{url}`.")),
+        }
+    }
+
+    /// Returns the error of a node response with given HTTP status.
+    pub fn from_upstream_status(status: reqwest::StatusCode, message: String) -> Self {
+        let error_type = match status.as_u16() {
+            400 => TNRAppErrorType::BadRequestError,
+            404 => TNRAppErrorType::NotFoundError,
+            501 => TNRAppErrorType::UnsupportedError,
+            504 => TNRAppErrorType::UpstreamTimeoutError,
+            _ => TNRAppErrorType::UpstreamUnavailableError,
+        };
+
+        Self::new(error_type, message)
+    }
 }
 
 impl fmt::Display for TNRAppError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        write!(f, "{}", self.message())
     }
 }
 
@@ -71,24 +158,136 @@ impl From<String> for TNRAppError {
     }
 }
 
+impl From<&str> for TNRAppError {
+    fn from(error: &str) -> TNRAppError {
+        TNRAppError::from(error.to_string())
+    }
+}
+
+impl From<tonic::Status> for TNRAppError {
+    fn from(status: tonic::Status) -> TNRAppError {
+        use tonic::Code;
+
+        let error_type = match status.code() {
+            Code::InvalidArgument | Code::OutOfRange => TNRAppErrorType::BadRequestError,
+            Code::NotFound => TNRAppErrorType::NotFoundError,
+            Code::DeadlineExceeded => TNRAppErrorType::UpstreamTimeoutError,
+            Code::Unimplemented => TNRAppErrorType::UnsupportedError,
+            _ => TNRAppErrorType::UpstreamUnavailableError,
+        };
+
+        TNRAppError::new(error_type, status.message())
+    }
+}
+
+/// The errors of the gRPC server have the status code of their type, like the HTTP responses.
+impl From<TNRAppError> for tonic::Status {
+    fn from(error: TNRAppError) -> tonic::Status {
+        use tonic::Code;
+
+        let code = match error.error_type {
+            TNRAppErrorType::MessageError | TNRAppErrorType::DbError => Code::Internal,
+            TNRAppErrorType::UnauthorizedError => Code::Unauthenticated,
+            TNRAppErrorType::ForbiddenError => Code::PermissionDenied,
+            TNRAppErrorType::BadRequestError => Code::InvalidArgument,
+            TNRAppErrorType::NotFoundError => Code::NotFound,
+            TNRAppErrorType::UpstreamUnavailableError => Code::Unavailable,
+            TNRAppErrorType::UpstreamTimeoutError => Code::DeadlineExceeded,
+            TNRAppErrorType::UnsupportedError => Code::Unimplemented,
+        };
+
+        tonic::Status::new(code, error.message())
+    }
+}
+
 impl ResponseError for TNRAppError {
     fn status_code(&self) -> StatusCode {
         match self.error_type {
-            // TNRAppErrorType::DbError => StatusCode::INTERNAL_SERVER_ERROR,
-            TNRAppErrorType::NotFoundError => StatusCode::NOT_FOUND,
-            // TNRAppErrorType::ForbiddenError => StatusCode::FORBIDDEN,
-            // TNRAppErrorType::ParseError => StatusCode::INTERNAL_SERVER_ERROR,
             TNRAppErrorType::MessageError => StatusCode::INTERNAL_SERVER_ERROR,
-            // TNRAppErrorType::ValidationError => StatusCode::INTERNAL_SERVER_ERROR,
+            TNRAppErrorType::UnauthorizedError => StatusCode::UNAUTHORIZED,
+            TNRAppErrorType::ForbiddenError => StatusCode::FORBIDDEN,
+            // TNRAppErrorType::ParseError => StatusCode::INTERNAL_SERVER_ERROR,
+            TNRAppErrorType::BadRequestError => StatusCode::BAD_REQUEST,
+            TNRAppErrorType::NotFoundError => StatusCode::NOT_FOUND,
+            TNRAppErrorType::UpstreamUnavailableError => StatusCode::BAD_GATEWAY,
+            TNRAppErrorType::UpstreamTimeoutError => StatusCode::GATEWAY_TIMEOUT,
+            TNRAppErrorType::DbError => StatusCode::INTERNAL_SERVER_ERROR,
+            TNRAppErrorType::UnsupportedError => StatusCode::NOT_IMPLEMENTED,
         }
     }
     fn error_response(&self) -> HttpResponse {
-        HttpResponse::build(self.status_code()).json(TNRAppErrorResponse { error: self.message() })
+        HttpResponse::build(self.status_code()).json(TNRAppErrorResponse {
+            code: self.error_type.code().to_string(),
+            error: self.message(),
+        })
     }
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
+#[aliases(
+    AccountResponse = TNRAppSuccessResponse<AccountInfo>,
+    VestingAccountResponse = TNRAppSuccessResponse<InternalVestingAccount>,
+    AmountListResponse = TNRAppSuccessResponse<Vec<ChainAmountItem>>,
+    ChainEntryListResponse = TNRAppSuccessResponse<Vec<ChainEntry>>,
+    ChainEntryResponse = TNRAppSuccessResponse<ChainEntry>,
+    StringResponse = TNRAppSuccessResponse<String>,
+    StringListResponse = TNRAppSuccessResponse<Vec<String>>,
+    NumberResponse = TNRAppSuccessResponse<f64>,
+    AllChainsDashboardResponse = TNRAppSuccessResponse<AllChainsDashboard>,
+    AllChainsBlocksResponse = TNRAppSuccessResponse<Vec<ChainBlocksOutcome>>,
+    AllChainsValidatorsResponse = TNRAppSuccessResponse<Vec<ChainValidatorsOutcome>>,
+    BatchResponse = TNRAppSuccessResponse<Vec<BatchItemResult>>,
+    BlockListResponse = TNRAppSuccessResponse<Vec<BlockForDb>>,
+    BlockResponse = TNRAppSuccessResponse<OutRestBlock>,
+    BlockEventListResponse = TNRAppSuccessResponse<Vec<BlockEventForDb>>,
+    BlockHeadersResponse = TNRAppSuccessResponse<InternalBlockchainResp>,
+    SignedBlockListResponse = TNRAppSuccessResponse<Vec<ValidatorSignatureListElement>>,
+    ChainListResponse = TNRAppSuccessResponse<Vec<ChainResponseItem>>,
+    DashboardResponse = TNRAppSuccessResponse<ChainDashboardInfoResponse>,
+    StatsResponse = TNRAppSuccessResponse<ChainStatsInfoResponse>,
+    CommunityPoolResponse = TNRAppSuccessResponse<OutRestCommunityPool>,
+    StakingPoolResponse = TNRAppSuccessResponse<OutRestStakingPool>,
+    ParamsResponse = TNRAppSuccessResponse<ChainParams>,
+    SupplyResponse = TNRAppSuccessResponse<ChainAmountItem>,
+    SupplyPageResponse = TNRAppSuccessResponse<SupplyPage>,
+    SigningInfoResponse = TNRAppSuccessResponse<InternalSlashingSigningInfoItem>,
+    SearchResultsResponse = TNRAppSuccessResponse<SearchResponse>,
+    DelegationListResponse = TNRAppSuccessResponse<Vec<InternalDelegation>>,
+    UnbondingListResponse = TNRAppSuccessResponse<Vec<InternalUnbonding>>,
+    RedelegationListResponse = TNRAppSuccessResponse<Vec<InternalRedelegation>>,
+    DelegatorRewardsResponse = TNRAppSuccessResponse<InternalDelegatorRewards>,
+    EvmPollListResponse = TNRAppSuccessResponse<Vec<EvmPollRespElement>>,
+    EvmPollResponse = TNRAppSuccessResponse<EvmPollRespElement>,
+    EvmVoteListResponse = TNRAppSuccessResponse<Vec<EvmPollParticipantForDb>>,
+    HeartbeatListResponse = TNRAppSuccessResponse<Vec<HeartbeatForDb>>,
+    HealthResponse = TNRAppSuccessResponse<HealthReport>,
+    ProposalListResponse = TNRAppSuccessResponse<Vec<ProposalItem>>,
+    ProposalResponse = TNRAppSuccessResponse<InternalProposal>,
+    ProposalTallyResponse = TNRAppSuccessResponse<InternalProposalFinalTallyResult>,
+    ProposalDepositListResponse = TNRAppSuccessResponse<Vec<InternalProposalDeposit>>,
+    ProposalDepositResponse = TNRAppSuccessResponse<InternalProposalDeposit>,
+    ProposalVoteListResponse = TNRAppSuccessResponse<Vec<InternalProposalVote>>,
+    ProposalVoteResponse = TNRAppSuccessResponse<InternalProposalVote>,
+    TransactionListResponse = TNRAppSuccessResponse<Vec<TransactionItem>>,
+    StoredTransactionListResponse = TNRAppSuccessResponse<Vec<TransactionForDb>>,
+    TransactionResponse = TNRAppSuccessResponse<OutRestTransaction>,
+    ValidatorResponse = TNRAppSuccessResponse<InternalValidator>,
+    ValidatorListResponse = TNRAppSuccessResponse<ValidatorListResp>,
+    ValidatorPageResponse = TNRAppSuccessResponse<ValidatorPage>,
+    ValidatorDelegationListResponse = TNRAppSuccessResponse<Vec<ValidatorInternalDelegation>>,
+    ValidatorUnbondingListResponse = TNRAppSuccessResponse<Vec<ValidatorInternalUnbonding>>,
+    ValidatorRedelegationListResponse = TNRAppSuccessResponse<Vec<ValidatorInternalRedelegation>>,
+    ValidatorCommissionResponse = TNRAppSuccessResponse<ValidatorCommisionResp>,
+    ValidatorRewardsResponse = TNRAppSuccessResponse<ValidatorRewardsResp>,
+    ValidatorSetResponse = TNRAppSuccessResponse<OutRestValidatorSet>,
+    DelegatorValidatorListResponse = TNRAppSuccessResponse<Vec<ValidatorListValidator>>,
+    DelegatorValidatorResponse = TNRAppSuccessResponse<ValidatorResp>,
+    MissedBlocksResponse = TNRAppSuccessResponse<ValidatorMissedBlocks>,
+    UptimeResponse = TNRAppSuccessResponse<ValidatorUptimePeriods>,
+    SigningHeatmapResponse = TNRAppSuccessResponse<Vec<ValidatorSigningBucket>>
+)]
 pub struct TNRAppSuccessResponse<T> {
+    /// The requested data, its shape depends on the route.
     pub data: T,
     pub pagination: Option<PaginationData>,
 }
@@ -99,7 +298,8 @@ impl<T> TNRAppSuccessResponse<T> {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct PaginationDataQueryParams {
     pub cursor: Option<String>,
     pub offset: Option<String>,
@@ -114,16 +314,26 @@ impl From<PaginationDataQueryParams> for PaginationData {
             offset: value.offset.map(|o| o.parse::<u64>().ok()).unwrap_or(None),
             limit: value.limit.map(|o| o.parse::<u64>().ok()).unwrap_or(None),
             direction: value.direction,
+            ..Default::default()
         }
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct PaginationData {
+    /// The cursor of the page to read. The responses have the cursor of the next page here.
     pub cursor: Option<String>,
     pub offset: Option<u64>,
     pub limit: Option<u64>,
+    /// `Prev` reads the page before the cursor, using the `prev_cursor` of a response.
     pub direction: Option<PaginationDirection>,
+    /// The cursor of the previous page, if there is one. It is only returned in the responses.
+    #[serde(skip_deserializing)]
+    pub prev_cursor: Option<String>,
+    /// The count of all the items, if the source can count them. It is only returned in the responses.
+    #[serde(skip_deserializing)]
+    pub total: Option<u64>,
 }
 
 impl From<PaginationDirection> for CursorDirections {
@@ -135,7 +345,7 @@ impl From<PaginationDirection> for CursorDirections {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub enum PaginationDirection {
     Next,
     Prev,
@@ -154,15 +364,197 @@ impl Default for PaginationData {
             offset: None,
             limit: Some(50),
             direction: Some(PaginationDirection::Next),
+            prev_cursor: None,
+            total: None,
+        }
+    }
+}
+
+impl PaginationData {
+    /// Returns the offset and the limit of the requested page, for the sources paginated by offsets.\
+    /// The cursors of these sources are the offsets of the page boundaries, so a previous page ends at its cursor.
+    pub fn to_offset_page(&self, default_limit: u64) -> Result<(u64, u64), TNRAppError> {
+        let limit = self.limit.unwrap_or(default_limit);
+
+        let boundary = match &self.cursor {
+            Some(cursor) => decode_offset_cursor(cursor)?,
+            None => self.offset.unwrap_or(0),
+        };
+
+        match self.direction {
+            Some(PaginationDirection::Prev) if boundary > 0 => Ok((boundary.saturating_sub(limit), limit.min(boundary))),
+            _ => Ok((boundary, limit)),
+        }
+    }
+
+    /// Returns the pagination of a page read from an offset, with the cursors of the pages around it.\
+    /// The next page is assumed to exist if the page is full and the total is unknown.
+    pub fn from_offset_page(offset: u64, limit: u64, count: u64, total: Option<u64>) -> Self {
+        let end = offset + count;
+
+        let has_next_page = match total {
+            Some(total) => end < total,
+            None => count >= limit,
+        };
+
+        Self {
+            cursor: has_next_page.then(|| encode_offset_cursor(end)),
+            offset: Some(offset),
+            limit: Some(limit),
+            direction: Some(PaginationDirection::Next),
+            prev_cursor: (offset > 0).then(|| encode_offset_cursor(offset)),
+            total,
         }
     }
 }
 
+/// Returns the cursor of given offset.
+fn encode_offset_cursor(offset: u64) -> String {
+    STANDARD.encode(offset.to_string())
+}
+
+/// Returns the offset of given cursor.
+fn decode_offset_cursor(cursor: &str) -> Result<u64, TNRAppError> {
+    STANDARD
+        .decode(cursor)
+        .ok()
+        .and_then(|offset| String::from_utf8(offset).ok())
+        .and_then(|offset| offset.parse().ok())
+        .ok_or_else(|| TNRAppError::new(TNRAppErrorType::BadRequestError, format!("Invalid cursor, '{cursor}'.")))
+}
+
 impl<T: Serialize> Responder for TNRAppSuccessResponse<T> {
     type Body = BoxBody;
 
-    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
         let body = serde_json::to_string(&self).unwrap();
-        HttpResponse::Ok().insert_header(ContentType::json()).body(body)
+        json_response(req, body, None)
+    }
+}
+
+/// Returns a JSON response with an `ETag` of its body.\
+/// `304 Not Modified` is returned without the body if the client has the same body already.
+/// The clients must revalidate the response on each request, unless a `max_age` is given.
+pub fn json_response(req: &HttpRequest, body: String, max_age: Option<Duration>) -> HttpResponse {
+    let etag = EntityTag::new_strong(hex::encode(&Sha256::digest(body.as_bytes())[..16]));
+
+    let cache_control = match max_age {
+        Some(max_age) => CacheControl(vec![CacheDirective::Public, CacheDirective::MaxAge(max_age.as_secs() as u32)]),
+        None => CacheControl(vec![CacheDirective::NoCache]),
+    };
+
+    let not_modified = match IfNoneMatch::parse(req) {
+        Ok(IfNoneMatch::Any) => true,
+        Ok(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(&etag)),
+        Err(_) => false,
+    };
+
+    match not_modified {
+        true => HttpResponse::NotModified()
+            .insert_header(ETag(etag))
+            .insert_header(cache_control)
+            .finish(),
+        false => HttpResponse::Ok()
+            .insert_header(ContentType::json())
+            .insert_header(ETag(etag))
+            .insert_header(cache_control)
+            .body(body),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page_request(cursor: Option<u64>, offset: Option<u64>, limit: Option<u64>, direction: Option<PaginationDirection>) -> PaginationData {
+        PaginationData {
+            cursor: cursor.map(encode_offset_cursor),
+            offset,
+            limit,
+            direction,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn to_offset_page_reads_next_pages() {
+        assert_eq!(page_request(None, None, None, None).to_offset_page(20).unwrap(), (0, 20));
+        assert_eq!(page_request(None, Some(40), Some(10), None).to_offset_page(20).unwrap(), (40, 10));
+        assert_eq!(
+            page_request(Some(30), Some(40), Some(10), Some(PaginationDirection::Next))
+                .to_offset_page(20)
+                .unwrap(),
+            (30, 10)
+        );
+    }
+
+    #[test]
+    fn to_offset_page_reads_prev_pages() {
+        let prev = || Some(PaginationDirection::Prev);
+
+        assert_eq!(page_request(Some(30), None, Some(10), prev()).to_offset_page(20).unwrap(), (20, 10));
+        // The previous page of a boundary nearer than the limit is shorter.
+        assert_eq!(page_request(Some(5), None, Some(10), prev()).to_offset_page(20).unwrap(), (0, 5));
+        // There is no page before the first one, so the first page is read.
+        assert_eq!(page_request(Some(0), None, Some(10), prev()).to_offset_page(20).unwrap(), (0, 10));
+        assert_eq!(page_request(None, None, Some(10), prev()).to_offset_page(20).unwrap(), (0, 10));
+    }
+
+    #[test]
+    fn to_offset_page_rejects_invalid_cursors() {
+        let config = PaginationData {
+            cursor: Some("not a cursor".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(config.to_offset_page(20).unwrap_err().error_type, TNRAppErrorType::BadRequestError);
+    }
+
+    #[test]
+    fn from_offset_page_links_the_pages_around() {
+        let first = PaginationData::from_offset_page(0, 10, 10, Some(25));
+        assert_eq!(first.cursor, Some(encode_offset_cursor(10)));
+        assert_eq!(first.prev_cursor, None);
+        assert_eq!(first.total, Some(25));
+
+        let last = PaginationData::from_offset_page(20, 10, 5, Some(25));
+        assert_eq!(last.cursor, None);
+        assert_eq!(last.prev_cursor, Some(encode_offset_cursor(20)));
+    }
+
+    #[test]
+    fn from_offset_page_without_total() {
+        assert_eq!(PaginationData::from_offset_page(10, 10, 10, None).cursor, Some(encode_offset_cursor(20)));
+        assert_eq!(PaginationData::from_offset_page(10, 10, 3, None).cursor, None);
+    }
+
+    #[test]
+    fn offset_pages_round_trip() {
+        let page = PaginationData::from_offset_page(20, 10, 10, Some(50));
+
+        let next = page_request(None, None, Some(10), Some(PaginationDirection::Next));
+        let next = PaginationData { cursor: page.cursor, ..next };
+        assert_eq!(next.to_offset_page(10).unwrap(), (30, 10));
+
+        let prev = page_request(None, None, Some(10), Some(PaginationDirection::Prev));
+        let prev = PaginationData {
+            cursor: page.prev_cursor,
+            ..prev
+        };
+        assert_eq!(prev.to_offset_page(10).unwrap(), (10, 10));
+    }
+
+    #[test]
+    fn grpc_status_keeps_the_error_type() {
+        let status = tonic::Status::from(TNRAppError::new(TNRAppErrorType::BadRequestError, "Invalid address."));
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(status.message(), "Invalid address.");
+
+        let status = tonic::Status::from(TNRAppError::new(TNRAppErrorType::NotFoundError, "No transaction is found."));
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        // The upstream errors keep their type through the typed errors.
+        let error = TNRAppError::from(tonic::Status::invalid_argument("decoding bech32 failed"));
+        assert_eq!(error.status_code(), StatusCode::BAD_REQUEST);
     }
 }
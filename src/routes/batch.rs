@@ -0,0 +1,30 @@
+use actix_web::{
+    post,
+    web::{Data, Json},
+};
+
+use crate::fetch::batch::{BatchItem, BatchItemResult};
+use crate::routes::{TNRAppError, TNRAppSuccessResponse};
+use crate::state::State;
+
+// ======== Batch Methods ========
+
+/// Runs the chain routes of the items concurrently, and returns their results in the order of the items.\
+/// The route of an item is the path after the chain name without its path parameters, and the params are the path and the query parameters of it.\
+/// The failing items have the error of the route in their place, so the response is `200` unless the batch itself is invalid.
+#[utoipa::path(
+    post,
+    path = "/batch",
+    tag = "batch",
+    request_body = [BatchItem],
+    responses(
+        (status = 200, body = crate::routes::BatchResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[post("batch")]
+pub async fn batch(state: Data<State>, body: Json<Vec<BatchItem>>) -> Result<TNRAppSuccessResponse<Vec<BatchItemResult>>, TNRAppError> {
+    let results = state.run_batch(body.into_inner()).await?;
+
+    Ok(TNRAppSuccessResponse::new(results, None))
+}
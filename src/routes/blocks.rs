@@ -4,53 +4,225 @@ use actix_web::{
     web::{Data, Path},
     Responder,
 };
+use mongodb::bson::Document;
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
 
-use crate::routes::{extract_chain, LastCountListsQueryParams, PaginationData, TNRAppError, TNRAppSuccessResponse};
-use crate::{fetch::others::Response, state::State};
+use crate::chain::Chain;
+use crate::routes::{extract_chain, range_query, LastCountListsQueryParams, PaginationData, TNRAppError, TNRAppErrorType, TNRAppSuccessResponse};
+use crate::state::State;
+
+/// The count of the blocks and the transactions returned by `last-blocks` and `last-txs`, if it is not given.
+pub const DEFAULT_LAST_COUNT: u16 = 10;
 
 // ====== Block Methods ======
 
+#[derive(Deserialize, Debug, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BlocksFilterQueryParams {
+    /// The operator address of the proposer. Eg: `"axelarvaloper1qy9uq03rkpqkzwsa4fz7xxetkxttdcj6tf09pg"`
+    pub proposer: Option<String>,
+    /// The height the blocks start from, inclusive.
+    pub from_height: Option<u64>,
+    /// The height the blocks end at, inclusive.
+    pub to_height: Option<u64>,
+    /// The time in milliseconds the blocks start from, inclusive.
+    pub from: Option<i64>,
+    /// The time in milliseconds the blocks end at, inclusive.
+    pub to: Option<i64>,
+    /// The minimum count of the transactions in the blocks.
+    pub min_tx_count: Option<u64>,
+}
+
+impl BlocksFilterQueryParams {
+    /// Returns the blocks query of the filters, `None` if no filter is given.
+    pub fn to_query(&self) -> Result<Option<Document>, TNRAppError> {
+        let mut query = Document::new();
+
+        if let Some(proposer) = &self.proposer {
+            query.insert("proposer_address", proposer);
+        }
+
+        if let Some(time) = range_query("timestamp", self.from, self.to)? {
+            query.extend(time);
+        }
+        if let Some(height) = range_query(
+            "height",
+            self.from_height.map(|height| height as i64),
+            self.to_height.map(|height| height as i64),
+        )? {
+            query.extend(height);
+        }
+        if let Some(tx_count) = range_query("tx_count", self.min_tx_count.map(|count| count as i64), None)? {
+            query.extend(tx_count);
+        }
+
+        Ok(match query.is_empty() {
+            true => None,
+            false => Some(query),
+        })
+    }
+}
+
+/// The blocks, from the newest to the oldest. They can be filtered by proposer, height, time and transaction count.
+#[utoipa::path(
+    get,
+    path = "/{chain}/blocks",
+    tag = "blocks",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        PaginationData,
+        BlocksFilterQueryParams,
+    ),
+    responses(
+        (status = 200, body = crate::routes::BlockListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/blocks")]
-pub async fn blocks(path: Path<String>, chains: Data<State>, query: Query<PaginationData>) -> Result<impl Responder, TNRAppError> {
+pub async fn blocks(
+    path: Path<String>,
+    chains: Data<State>,
+    query: Query<PaginationData>,
+    filter: Query<BlocksFilterQueryParams>,
+) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
 
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.database.find_paginated_blocks(None, query.into_inner()).await?;
+    let data = chain.database.find_paginated_blocks(filter.to_query()?, query.into_inner()).await?;
     Ok(TNRAppSuccessResponse::new(data.data, Some(data.pagination)))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/block-by-height/{height}",
+    tag = "blocks",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("height" = u64, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::BlockResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/block-by-height/{height}")]
 pub async fn block_by_height(path: Path<(String, u64)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, height) = path.into_inner();
 
     let chain = extract_chain(&chain, chains)?;
+    block_by_height_response(&chain, height).await
+}
+
+/// Returns the response of `{chain}/block-by-height`, it is shared with the batches.
+pub async fn block_by_height_response(chain: &Chain, height: u64) -> Result<TNRAppSuccessResponse<impl Serialize>, TNRAppError> {
     let data = chain.get_block_by_height(Some(height)).await?;
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BlockEventsQueryParams {
+    /// Comma separated event types. Eg: `slash,liveness`
+    #[serde(rename = "type")]
+    pub event_type: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{chain}/block/{height}/events",
+    tag = "blocks",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("height" = u64, Path),
+        BlockEventsQueryParams,
+    ),
+    responses(
+        (status = 200, body = crate::routes::BlockEventListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[get("{chain}/block/{height}/events")]
+pub async fn block_events(
+    path: Path<(String, u64)>,
+    chains: Data<State>,
+    query: Query<BlockEventsQueryParams>,
+) -> Result<impl Responder, TNRAppError> {
+    let (chain, height) = path.into_inner();
+
+    let event_types = query
+        .into_inner()
+        .event_type
+        .map(|types| types.split(',').map(|t| t.trim().to_string()).filter(|t| !t.is_empty()).collect());
+
+    let chain = extract_chain(&chain, chains)?;
+    let data = chain.get_block_events(height, event_types).await?;
+    Ok(TNRAppSuccessResponse::new(data, None))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{chain}/block-by-hash/{hash}",
+    tag = "blocks",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("hash" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::BlockResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/block-by-hash/{hash}")]
 pub async fn block_by_hash(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, hash) = path.into_inner();
 
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.get_block_by_hash(&hash).await?;
+    block_by_hash_response(&chain, &hash).await
+}
+
+/// Returns the response of `{chain}/block-by-hash`, it is shared with the batches.
+pub async fn block_by_hash_response(chain: &Chain, hash: &str) -> Result<TNRAppSuccessResponse<impl Serialize>, TNRAppError> {
+    let data = chain.get_block_by_hash(hash).await?;
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
 /// Example: http://localhost:8080/axelar/block-headers/2500-2520
 /// Maximum block headers length is 20.
+#[utoipa::path(
+    get,
+    path = "/{chain}/block-headers/{min_and_max_height}",
+    tag = "blocks",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("min_and_max_height" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::BlockHeadersResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/block-headers/{min_and_max_height}")]
 pub async fn headers_by_heights(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, min_and_max_height) = path.into_inner();
 
     let chain = extract_chain(&chain, chains)?;
-    let data = match min_and_max_height.split_once('-') {
-        Some((min_height, max_height)) => match (min_height.parse(), max_height.parse()) {
-            (Ok(min_height), Ok(max_height)) => Response::Success(chain.get_block_headers(min_height, max_height).await),
-            _ => Response::Error(format!("{} is mistaken!", min_and_max_height)),
-        },
-        None => Response::Error(format!("{} is mistaken!", min_and_max_height)),
-    };
+    let (min_height, max_height) = min_and_max_height
+        .split_once('-')
+        .and_then(|(min_height, max_height)| Some((min_height.parse().ok()?, max_height.parse().ok()?)))
+        .ok_or_else(|| TNRAppError::new(TNRAppErrorType::BadRequestError, format!("{min_and_max_height} is mistaken!")))?;
+
+    let data = chain.get_block_headers(min_height, max_height).await?;
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
@@ -63,17 +235,51 @@ pub async fn latest_headers(path: Path<String>, chains: Data<State>) -> Result<i
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/last-blocks",
+    tag = "blocks",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        LastCountListsQueryParams,
+    ),
+    responses(
+        (status = 200, body = crate::routes::BlockListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/last-blocks")]
 pub async fn last_blocks(path: Path<String>, chains: Data<State>, query: Query<LastCountListsQueryParams>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
 
-    let default_count = 10;
-    let count = query.count.unwrap_or(default_count);
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.get_last_blocks_from_db(count).await?;
+    last_blocks_response(&chain, query.into_inner()).await
+}
+
+/// Returns the response of `{chain}/last-blocks`, it is shared with the batches.
+pub async fn last_blocks_response(chain: &Chain, query: LastCountListsQueryParams) -> Result<TNRAppSuccessResponse<impl Serialize>, TNRAppError> {
+    let data = chain.get_last_blocks_from_db(query.count.unwrap_or(DEFAULT_LAST_COUNT)).await?;
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validator/last_signed_blocks/{operator_address}",
+    tag = "blocks",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("operator_address" = String, Path),
+        LastCountListsQueryParams,
+    ),
+    responses(
+        (status = 200, body = crate::routes::SignedBlockListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validator/last_signed_blocks/{operator_address}")]
 pub async fn validator_last_signed_blocks(
     path: Path<(String, String)>,
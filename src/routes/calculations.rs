@@ -8,6 +8,19 @@ use actix_web::{
 
 // ======== Calculations Methods ========
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/calculations/apr",
+    tag = "calculations",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    responses(
+        (status = 200, body = crate::routes::NumberResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/calculations/apr")]
 pub async fn calculations(path: Path<String>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
@@ -4,14 +4,17 @@ use actix_web::{
     Responder,
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
+use crate::cache::{CacheRoute, CachedResponse};
+use crate::chain::Chain;
 use crate::routes::{extract_chain, TNRAppError, TNRAppSuccessResponse};
 use crate::state::State;
 
 // ======== Chains Methods ========
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-struct ChainResponseItem {
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct ChainResponseItem {
     name: String,
     logo: String,
     main: bool,
@@ -20,11 +23,19 @@ struct ChainResponseItem {
     acc_address_prefix: String,
 }
 
+#[utoipa::path(
+    get,
+    path = "/chains",
+    tag = "chain",
+    responses(
+        (status = 200, body = crate::routes::ChainListResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("chains")]
 pub async fn chains(state: Data<State>) -> Result<impl Responder, TNRAppError> {
     let chains = state
         .get_chains()
-        .clone()
         .into_iter()
         .map(|(name, chain)| ChainResponseItem {
             name,
@@ -39,20 +50,66 @@ pub async fn chains(state: Data<State>) -> Result<impl Responder, TNRAppError> {
     Ok(TNRAppSuccessResponse::new(chains, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/dashboard",
+    tag = "chain",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    responses(
+        (status = 200, body = crate::routes::DashboardResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/dashboard")]
 pub async fn dashboard(path: Path<String>, chains_data: Data<State>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
     let chain = extract_chain(&chain, chains_data)?;
 
-    let data = chain.get_dashboard_info().await?;
-    Ok(TNRAppSuccessResponse::new(data, None))
+    dashboard_response(&chain).await
+}
+
+/// Returns the cached response of `{chain}/dashboard`, it is shared with the batches.
+pub async fn dashboard_response(chain: &Chain) -> Result<CachedResponse, TNRAppError> {
+    chain
+        .cache
+        .get_or_insert(CacheRoute::Dashboard, "", || async {
+            let data = chain.get_dashboard_info().await?;
+            Ok(TNRAppSuccessResponse::new(data, None))
+        })
+        .await
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/stats",
+    tag = "chain",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    responses(
+        (status = 200, body = crate::routes::StatsResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/stats")]
 pub async fn stats(path: Path<String>, chains_data: Data<State>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
     let chain = extract_chain(&chain, chains_data)?;
 
-    let data = chain.get_stats().await?;
-    Ok(TNRAppSuccessResponse::new(data, None))
+    stats_response(&chain).await
+}
+
+/// Returns the cached response of `{chain}/stats`, it is shared with the batches.
+pub async fn stats_response(chain: &Chain) -> Result<CachedResponse, TNRAppError> {
+    chain
+        .cache
+        .get_or_insert(CacheRoute::Stats, "", || async {
+            let data = chain.get_stats().await?;
+            Ok(TNRAppSuccessResponse::new(data, None))
+        })
+        .await
 }
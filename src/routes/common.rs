@@ -1,28 +1,30 @@
 use crate::chain::Chain;
 use crate::fetch::amount_util::{ChainAmountItemBuilder, TnrDecimal};
-use crate::fetch::others::{Pagination, PaginationConfig};
+use crate::fetch::blocks::InternalBlock;
+use crate::fetch::others::{InternalStakingPool, Pagination, PaginationConfig};
+use crate::fetch::transactions::InternalTransaction;
+use crate::fetch::validators::ValidatorSetValidator;
 use crate::routes::{TNRAppError, TNRAppErrorType};
 use crate::state::State;
 use actix_web::web::Data;
-use mongodb::bson::doc;
+use mongodb::bson::{doc, Document};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
-/// The query params that has `page` param.
-#[derive(Deserialize)]
-pub struct QueryParams {
-    /// Page. Indexing starts at 1.\
-    /// The value is `1/ONE`, if it is not specified. \
-    /// Example values are: 1, 2, 3, 4, etc
-    pub page: Option<u8>,
-    pub limit: Option<u16>,
-}
-
-#[derive(Deserialize)]
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct LastCountListsQueryParams {
     pub count: Option<u16>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
+#[aliases(
+    OutRestBlock = OutRestResponse<InternalBlock>,
+    OutRestTransaction = OutRestResponse<InternalTransaction>,
+    OutRestCommunityPool = OutRestResponse<u64>,
+    OutRestStakingPool = OutRestResponse<InternalStakingPool>,
+    OutRestValidatorSet = OutRestResponse<Vec<ValidatorSetValidator>>
+)]
 pub struct OutRestResponse<T> {
     pub value: T,
     /// The count of the pages.
@@ -62,12 +64,39 @@ pub fn calc_pages(pagination: Pagination, config: PaginationConfig) -> Result<u8
 pub fn extract_chain(chain: &str, chains: Data<State>) -> Result<Chain, TNRAppError> {
     chains.get(chain).map_err(|_| TNRAppError {
         message: Some(format!("Chain could not found {}", chain)),
-        error_type: TNRAppErrorType::MessageError,
+        error_type: TNRAppErrorType::NotFoundError,
+    })
+}
+
+/// Returns the range query of a field for the list filters, `None` if neither end is given. Both ends are inclusive.
+pub fn range_query(field: &str, from: Option<i64>, to: Option<i64>) -> Result<Option<Document>, TNRAppError> {
+    if let (Some(from), Some(to)) = (from, to) {
+        if from > to {
+            return Err(TNRAppError::new(
+                TNRAppErrorType::BadRequestError,
+                format!("The range of `{field}` is not valid, {from} is after {to}."),
+            ));
+        }
+    }
+
+    let mut range = Document::new();
+    if let Some(from) = from {
+        range.insert("$gte", from);
+    }
+    if let Some(to) = to {
+        range.insert("$lte", to);
+    }
+
+    Ok(match range.is_empty() {
+        true => None,
+        false => Some(doc! { field: range }),
     })
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, ToSchema)]
 pub struct ChainAmountItem {
+    /// The amount as a decimal string. Eg: `"1250.5"`
+    #[schema(value_type = String)]
     pub amount: TnrDecimal,
     pub ticker: String,
 }
@@ -102,3 +131,38 @@ impl ChainAmountItem {
         Self { amount, ticker }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_query_of_both_ends() {
+        assert_eq!(
+            range_query("height", Some(10), Some(20)).unwrap(),
+            Some(doc! {"height": {"$gte": 10_i64, "$lte": 20_i64}})
+        );
+        assert_eq!(
+            range_query("height", Some(10), Some(10)).unwrap(),
+            Some(doc! {"height": {"$gte": 10_i64, "$lte": 10_i64}})
+        );
+    }
+
+    #[test]
+    fn range_query_of_one_end() {
+        assert_eq!(range_query("time", Some(10), None).unwrap(), Some(doc! {"time": {"$gte": 10_i64}}));
+        assert_eq!(range_query("time", None, Some(20)).unwrap(), Some(doc! {"time": {"$lte": 20_i64}}));
+    }
+
+    #[test]
+    fn range_query_without_ends() {
+        assert_eq!(range_query("time", None, None).unwrap(), None);
+    }
+
+    #[test]
+    fn range_query_rejects_reversed_ends() {
+        let error = range_query("time", Some(20), Some(10)).unwrap_err();
+
+        assert_eq!(error.error_type, TNRAppErrorType::BadRequestError);
+    }
+}
@@ -3,17 +3,37 @@ use actix_web::{
     web::{Data, Path},
     Responder,
 };
+use serde::Serialize;
 
+use crate::chain::Chain;
 use crate::routes::{extract_chain, TNRAppError, TNRAppSuccessResponse};
 use crate::state::State;
 
 // ======== Community Pool Methods ========
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/community-pool",
+    tag = "community_pool",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    responses(
+        (status = 200, body = crate::routes::CommunityPoolResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/community-pool")]
 pub async fn community_pool(path: Path<String>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
 
     let chain = extract_chain(&chain, chains)?;
+    community_pool_response(&chain).await
+}
+
+/// Returns the response of `{chain}/community-pool`, it is shared with the batches.
+pub async fn community_pool_response(chain: &Chain) -> Result<TNRAppSuccessResponse<impl Serialize>, TNRAppError> {
     let data = chain.get_community_pool().await?;
     Ok(TNRAppSuccessResponse::new(data, None))
 }
@@ -7,19 +7,32 @@ use actix_web::{
 use crate::routes::{extract_chain, PaginationData, TNRAppError, TNRAppSuccessResponse};
 use crate::state::State;
 
-use super::QueryParams;
-
 // ======== Delegation Methods ========
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/delegations/{delegator_address}",
+    tag = "delegations",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("delegator_address" = String, Path),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::DelegationListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/delegations/{delegator_address}")]
-pub async fn delegations(path: Path<(String, String)>, chains: Data<State>, _query: Query<QueryParams>) -> Result<impl Responder, TNRAppError> {
+pub async fn delegations(path: Path<(String, String)>, chains: Data<State>, query: Query<PaginationData>) -> Result<impl Responder, TNRAppError> {
     let (chain, delegator_addr) = path.into_inner();
 
+    let query = query.into_inner();
     let config = PaginationData {
-        cursor: None,
-        offset: None,
-        limit: Some(5),
-        direction: None,
+        limit: query.limit.or(Some(5)),
+        ..query
     };
 
     let chain = extract_chain(&chain, chains)?;
@@ -27,19 +40,34 @@ pub async fn delegations(path: Path<(String, String)>, chains: Data<State>, _que
     Ok(TNRAppSuccessResponse::from(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/unbonding-delegations/{delegator_address}",
+    tag = "delegations",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("delegator_address" = String, Path),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::UnbondingListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/unbonding-delegations/{delegator_address}")]
 pub async fn unbonding_delegations(
     path: Path<(String, String)>,
     chains: Data<State>,
-    _query: Query<QueryParams>,
+    query: Query<PaginationData>,
 ) -> Result<impl Responder, TNRAppError> {
     let (chain, delegator_addr) = path.into_inner();
 
+    let query = query.into_inner();
     let config = PaginationData {
-        cursor: None,
-        offset: None,
-        limit: Some(5),
-        direction: None,
+        limit: query.limit.or(Some(5)),
+        ..query
     };
 
     let chain = extract_chain(&chain, chains)?;
@@ -47,15 +75,30 @@ pub async fn unbonding_delegations(
     Ok(TNRAppSuccessResponse::from(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/redelegations/{delegator_address}",
+    tag = "delegations",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("delegator_address" = String, Path),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::RedelegationListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/redelegations/{delegator_address}")]
-pub async fn redelegations(path: Path<(String, String)>, chains: Data<State>, _query: Query<QueryParams>) -> Result<impl Responder, TNRAppError> {
+pub async fn redelegations(path: Path<(String, String)>, chains: Data<State>, query: Query<PaginationData>) -> Result<impl Responder, TNRAppError> {
     let (chain, delegator_addr) = path.into_inner();
 
+    let query = query.into_inner();
     let config = PaginationData {
-        cursor: None,
-        offset: None,
-        limit: Some(5),
-        direction: None,
+        limit: query.limit.or(Some(5)),
+        ..query
     };
 
     let chain = extract_chain(&chain, chains)?;
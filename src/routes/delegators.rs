@@ -9,6 +9,21 @@ use crate::state::State;
 
 // ======== 'axelar' Delegator Methods ========
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/delegator-rewards/{address}",
+    tag = "delegators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("address" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::DelegatorRewardsResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/delegator-rewards/{address}")]
 pub async fn delegator_rewards(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, delegator_addr) = path.into_inner();
@@ -18,6 +33,21 @@ pub async fn delegator_rewards(path: Path<(String, String)>, chains: Data<State>
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/delegator-withdraw-address/{address}",
+    tag = "delegators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("address" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::StringResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/delegator-withdraw-address/{address}")]
 pub async fn delegator_withdraw_address(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, delegator_addr) = path.into_inner();
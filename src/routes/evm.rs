@@ -6,11 +6,26 @@ use actix_web::{
 };
 use mongodb::bson::doc;
 
-use crate::routes::{extract_chain, PaginationData, TNRAppError, TNRAppSuccessResponse};
+use crate::routes::{extract_chain, PaginationData, TNRAppError, TNRAppErrorType, TNRAppSuccessResponse};
 use crate::state::State;
 
 // ====== Evm Methods ======
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/evm/polls",
+    tag = "evm",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::EvmPollListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/evm/polls")]
 pub async fn evm_polls(path: Path<String>, chains: Data<State>, query: Query<PaginationData>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
@@ -18,13 +33,31 @@ pub async fn evm_polls(path: Path<String>, chains: Data<State>, query: Query<Pag
     let chain = extract_chain(&chain, chains)?;
 
     if &chain.config.name != "axelar" {
-        return Err(TNRAppError::from(format!("Evm polls not supported for {}", &chain.config.name)));
+        return Err(TNRAppError::new(
+            TNRAppErrorType::UnsupportedError,
+            format!("Evm polls not supported for {}", &chain.config.name),
+        ));
     };
 
     let data = chain.get_evm_polls(None, query.into_inner()).await?;
     Ok(TNRAppSuccessResponse::from(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/evm/poll/{poll_id}",
+    tag = "evm",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("poll_id" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::EvmPollResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/evm/poll/{poll_id}")]
 pub async fn evm_poll(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, poll_id) = path.into_inner();
@@ -32,13 +65,32 @@ pub async fn evm_poll(path: Path<(String, String)>, chains: Data<State>) -> Resu
     let chain = extract_chain(&chain, chains)?;
 
     if &chain.config.name != "axelar" {
-        return Err(TNRAppError::from(format!("Evm polls not supported for {}", &chain.config.name)));
+        return Err(TNRAppError::new(
+            TNRAppErrorType::UnsupportedError,
+            format!("Evm polls not supported for {}", &chain.config.name),
+        ));
     };
 
     let data = chain.get_evm_poll(&poll_id).await?;
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/evm/votes/{operator_address}",
+    tag = "evm",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("operator_address" = String, Path),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::EvmVoteListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/evm/votes/{operator_address}")]
 pub async fn evm_validator_votes(
     path: Path<(String, String)>,
@@ -50,7 +102,10 @@ pub async fn evm_validator_votes(
     let chain = extract_chain(&chain, chains)?;
 
     if &chain.config.name != "axelar" {
-        return Err(TNRAppError::from(format!("Evm votes not supported for {}", &chain.config.name)));
+        return Err(TNRAppError::new(
+            TNRAppErrorType::UnsupportedError,
+            format!("Evm votes not supported for {}", &chain.config.name),
+        ));
     };
 
     let data = chain
@@ -61,6 +116,21 @@ pub async fn evm_validator_votes(
     Ok(TNRAppSuccessResponse::from(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/evm/validator/supported_chains/{operator_address}",
+    tag = "evm",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("operator_address" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::StringListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/evm/validator/supported_chains/{operator_address}")]
 pub async fn evm_val_supported_chains(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, operator_address) = path.into_inner();
@@ -68,7 +138,10 @@ pub async fn evm_val_supported_chains(path: Path<(String, String)>, chains: Data
     let chain = extract_chain(&chain, chains)?;
 
     if &chain.config.name != "axelar" {
-        return Err(TNRAppError::from(format!("Evm polls not supported for {}", &chain.config.name)));
+        return Err(TNRAppError::new(
+            TNRAppErrorType::UnsupportedError,
+            format!("Evm polls not supported for {}", &chain.config.name),
+        ));
     };
 
     let data: Vec<String> = chain.get_supported_chains(&operator_address).await?;
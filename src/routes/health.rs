@@ -0,0 +1,50 @@
+use actix_web::http::StatusCode;
+use actix_web::{get, web::Data, HttpResponse};
+
+use crate::fetch::health::{HealthReport, HealthStatus};
+use crate::routes::TNRAppSuccessResponse;
+use crate::state::State;
+
+// ======== Health Methods ========
+
+/// The health of the server and every chain: the reachability of the RPC, REST and gRPC endpoints, the ingestion lag, the cron jobs, the websocket clients and MongoDB.\
+/// It responds with `503` if MongoDB is not reachable or a chain is degraded. The report is reused for `health.cache_secs`.
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    responses(
+        (status = 200, body = crate::routes::HealthResponse),
+        (status = 503, body = crate::routes::HealthResponse),
+    ),
+)]
+#[get("health")]
+pub async fn health(chains: Data<State>) -> HttpResponse {
+    health_response(chains.get_health(true).await)
+}
+
+/// The readiness of the server and every chain: the ingestion lag, the websocket of the nodes and MongoDB.\
+/// It responds with `503` if MongoDB is not reachable or the ingestion of a chain is behind. The report is reused for `health.cache_secs`.
+#[utoipa::path(
+    get,
+    path = "/ready",
+    tag = "health",
+    responses(
+        (status = 200, body = crate::routes::HealthResponse),
+        (status = 503, body = crate::routes::HealthResponse),
+    ),
+)]
+#[get("ready")]
+pub async fn ready(chains: Data<State>) -> HttpResponse {
+    health_response(chains.get_health(false).await)
+}
+
+/// Returns the report with `503` status if it is degraded.
+fn health_response(report: HealthReport) -> HttpResponse {
+    let status = match report.status {
+        HealthStatus::Ok => StatusCode::OK,
+        HealthStatus::Degraded => StatusCode::SERVICE_UNAVAILABLE,
+    };
+
+    HttpResponse::build(status).json(TNRAppSuccessResponse::new(report, None))
+}
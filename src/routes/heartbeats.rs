@@ -5,14 +5,32 @@ use actix_web::{
 };
 use mongodb::bson::doc;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::database::HeartbeatForDb;
 use crate::fetch::heartbeats::{HeartbeatsListElement, HeartbeatsQuery};
-use crate::routes::{extract_chain, PaginationData, TNRAppError, TNRAppSuccessResponse};
+use crate::routes::{extract_chain, PaginationData, TNRAppError, TNRAppErrorType, TNRAppSuccessResponse};
 use crate::state::State;
 
 // ====== Heart Beats Methods ======
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validator/heartbeats/{operator_address}",
+    tag = "heartbeats",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("operator_address" = String, Path),
+        PaginationData,
+    ),
+    request_body = ValidatorHeartbeatsQBody,
+    responses(
+        (status = 200, body = crate::routes::HeartbeatListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validator/heartbeats/{operator_address}")]
 pub async fn validator_hearbeats(
     path: Path<(String, String)>,
@@ -25,7 +43,10 @@ pub async fn validator_hearbeats(
     let chain = extract_chain(&chain, chains)?;
 
     if &chain.config.name != "axelar" {
-        return Err(TNRAppError::from(format!("Heartbeats not supported for {}", &chain.config.name)));
+        return Err(TNRAppError::new(
+            TNRAppErrorType::UnsupportedError,
+            format!("Heartbeats not supported for {}", &chain.config.name),
+        ));
     };
 
     let (from, to) = match body {
@@ -41,6 +62,21 @@ pub async fn validator_hearbeats(
     Ok(TNRAppSuccessResponse::new(data.data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/heartbeats",
+    tag = "heartbeats",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::HeartbeatListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/heartbeats")]
 pub async fn hearbeats(
     path: Path<String>,
@@ -52,7 +88,10 @@ pub async fn hearbeats(
     let chain = extract_chain(&chain, chains)?;
 
     if &chain.config.name != "axelar" {
-        return Err(TNRAppError::from(format!("Hearbeats not supported for {}", &chain.config.name)));
+        return Err(TNRAppError::new(
+            TNRAppErrorType::UnsupportedError,
+            format!("Hearbeats not supported for {}", &chain.config.name),
+        ));
     };
 
     let data = chain
@@ -62,7 +101,7 @@ pub async fn hearbeats(
     Ok(TNRAppSuccessResponse::new(data.data, Some(data.pagination)))
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct ValidatorHeartbeatsQBody {
     pub from_block: Option<i64>,
     pub to_block: Option<i64>,
@@ -0,0 +1,24 @@
+use actix_web::{get, HttpResponse};
+use prometheus::TEXT_FORMAT;
+
+use crate::metrics::METRICS;
+use crate::routes::TNRAppError;
+
+// ======== Metrics Methods ========
+
+/// The metrics of the server in the Prometheus text format: the latencies of the routes, the requests to the nodes by chain and outcome, the ingested blocks and transactions, the events the websocket clients lagged behind for, the durations of the cron jobs and the MongoDB commands.
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "metrics",
+    responses(
+        (status = 200, body = String, content_type = "text/plain"),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[get("metrics")]
+pub async fn metrics() -> Result<HttpResponse, TNRAppError> {
+    let body = METRICS.encode()?;
+
+    Ok(HttpResponse::Ok().content_type(TEXT_FORMAT).body(body))
+}
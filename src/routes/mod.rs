@@ -1,5 +1,8 @@
 mod account;
+mod admin;
+mod all_chains;
 mod api_response;
+mod batch;
 mod blocks;
 mod calculations;
 mod chain;
@@ -8,9 +11,13 @@ mod community_pool;
 mod delegations;
 mod delegators;
 mod evm;
+mod health;
 mod heartbeats;
+mod metrics;
+mod openapi;
 mod params;
 mod proposals;
+mod search;
 mod signing_info;
 mod staking_pool;
 mod tokenomics;
@@ -18,7 +25,10 @@ mod transactions;
 mod validators;
 
 pub use account::*;
+pub use admin::*;
+pub use all_chains::*;
 pub use api_response::*;
+pub use batch::*;
 pub use blocks::*;
 pub use calculations::*;
 pub use chain::*;
@@ -27,9 +37,13 @@ pub use community_pool::*;
 pub use delegations::*;
 pub use delegators::*;
 pub use evm::*;
+pub use health::*;
 pub use heartbeats::*;
+pub use metrics::*;
+pub use openapi::*;
 pub use params::*;
 pub use proposals::*;
+pub use search::*;
 pub use signing_info::*;
 pub use staking_pool::*;
 pub use tokenomics::*;
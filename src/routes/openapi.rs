@@ -0,0 +1,322 @@
+use utoipa::OpenApi;
+
+use crate::chain::IntermediateChainConfig;
+use crate::database::{
+    BlockEventAttributeForDb, BlockEventForDb, BlockEventPhaseForDb, BlockForDb, EvmPollParticipantForDb, HeartbeatForDb, HeartbeatRawForDb,
+    SupplyPage, TransactionForDb, ValidatorForDb, ValidatorPage,
+};
+use crate::fetch::account::{AccountInfo, InternalPeriod, InternalVestingAccount, NativeTokenBalanceInfo};
+use crate::fetch::all_chains::{AllChainsDashboard, ChainBlocksOutcome, ChainDashboardOutcome, ChainDashboardSummary, ChainValidatorsOutcome};
+use crate::fetch::batch::{BatchItem, BatchItemResult};
+use crate::fetch::blocks::{
+    BlockHeaderVersion, BlockId, BlockIdParts, BlockLastCommitSignatures, CosmosEvent, CosmosEventAttribute, InternalBlock, InternalBlockHeader,
+    InternalBlockMeta, InternalBlockSignature, InternalBlockchainResp, ValidatorSignatureListElement,
+};
+use crate::fetch::chain::{ChainDashboardInfoResponse, ChainStatsInfoResponse, InternalMarketChart, TokenMarketHistory};
+use crate::fetch::chain_socket::EvmPollVote;
+use crate::fetch::delegations::{InternalDelegation, InternalRedelegation, InternalUnbonding};
+use crate::fetch::delegators::{InternalDelegatorReward, InternalDelegatorRewards};
+use crate::fetch::evm::{EvmPollOperatorInfo, EvmPollParticipantRespElement, EvmPollRespElement, EvmPollVoteCountInfoElement};
+use crate::fetch::export::ExportKind;
+use crate::fetch::health::{ChainHealth, CronJobHealth, HealthReport, HealthStatus, ProbeResult};
+use crate::fetch::heartbeats::HeartbeatStatus;
+use crate::fetch::others::InternalStakingPool;
+use crate::fetch::params::{ChainParams, ChainParamsDistribution, ChainParamsGov, ChainParamsSlashing, ChainParamsStaking};
+use crate::fetch::proposals::{
+    InternalProposal, InternalProposalDeposit, InternalProposalFinalTallyResult, InternalProposalVote, ProposalInfo, ProposalItem, ProposalOption,
+};
+use crate::fetch::search::{SearchKind, SearchResponse};
+use crate::fetch::transactions::{InternalTransaction, TransactionItem, TxResponseEvent, TxResponseEventAttribute, TxResponseLog};
+use crate::fetch::validator_signing::{ValidatorMissedBlocks, ValidatorSigningBucket, ValidatorUptimePeriod, ValidatorUptimePeriods};
+use crate::fetch::validators::{
+    ConsensusPubkey, InternalDelegation as ValidatorInternalDelegation, InternalRedelegation as ValidatorInternalRedelegation,
+    InternalSlashingSigningInfoItem, InternalUnbonding as ValidatorInternalUnbonding, InternalValidator, ValidatorCommision, ValidatorCommisionResp,
+    ValidatorListElement, ValidatorListElementValidatorCommission, ValidatorListElementValidatorCommissionRates, ValidatorListResp,
+    ValidatorListValidator, ValidatorListValidatorCommission, ValidatorListValidatorCommissionRates, ValidatorListValidatorDescription,
+    ValidatorResp, ValidatorRewardsResp, ValidatorSetPubKey, ValidatorSetValidator,
+};
+use crate::health::CronJobRun;
+use crate::routes::{
+    self, AccountResponse, AllChainsBlocksResponse, AllChainsDashboardResponse, AllChainsValidatorsResponse, AmountListResponse, BatchResponse,
+    BlockEventListResponse, BlockHeadersResponse, BlockListResponse, BlockResponse, ChainAmountItem, ChainEntryListResponse, ChainEntryResponse,
+    ChainListResponse, ChainResponseItem, CommunityPoolResponse, DashboardResponse, DelegationListResponse, DelegatorRewardsResponse,
+    DelegatorValidatorListResponse, DelegatorValidatorResponse, EvmPollListResponse, EvmPollResponse, EvmVoteListResponse, ExportFormat,
+    HealthResponse, HeartbeatListResponse, MissedBlocksResponse, NumberResponse, OutRestBlock, OutRestCommunityPool, OutRestStakingPool,
+    OutRestTransaction, OutRestValidatorSet, PaginationData, PaginationDirection, ParamsResponse, ProposalDepositListResponse,
+    ProposalDepositResponse, ProposalListResponse, ProposalResponse, ProposalStatus, ProposalTallyResponse, ProposalVoteListResponse,
+    ProposalVoteResponse, RedelegationListResponse, SearchResultsResponse, SignedBlockListResponse, SigningHeatmapResponse, SigningInfoResponse,
+    StakingPoolResponse, StatsResponse, StoredTransactionListResponse, StringListResponse, StringResponse, SupplyPageResponse, SupplyResponse,
+    TNRAppErrorResponse, TransactionListResponse, TransactionResponse, TxStatus, UnbondingListResponse, UptimeResponse, ValidatorCommissionResponse,
+    ValidatorDelegationListResponse, ValidatorHeartbeatsQBody, ValidatorListResponse, ValidatorPageResponse, ValidatorRedelegationListResponse,
+    ValidatorResponse, ValidatorRewardsResponse, ValidatorSetResponse, ValidatorUnbondingListResponse, VestingAccountResponse,
+};
+use crate::state::ChainEntry;
+
+/// The OpenAPI specification of the REST routes.\
+/// It is served at `/openapi.json`, and the docs page of it is served at `/docs/`.\
+/// A route must be listed here after it is registered in `server.rs`.
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Explorer Backend", description = "REST API of the explorer backend."),
+    paths(
+        routes::health,
+        routes::ready,
+        routes::metrics,
+        routes::admin_chains,
+        routes::admin_upsert_chain,
+        routes::admin_remove_chain,
+        routes::admin_start_chain,
+        routes::admin_stop_chain,
+        routes::admin_reload_chain,
+        routes::all_dashboard,
+        routes::all_latest_blocks,
+        routes::all_validators,
+        routes::batch,
+        routes::dashboard,
+        routes::stats,
+        routes::search,
+        routes::chains,
+        routes::search_all_chains,
+        routes::block_by_hash,
+        routes::block_by_height,
+        routes::block_events,
+        routes::headers_by_heights,
+        routes::community_pool,
+        routes::delegations,
+        routes::delegator_rewards,
+        routes::delegator_withdraw_address,
+        routes::inflation,
+        routes::blocks,
+        routes::last_blocks,
+        routes::last_txs,
+        routes::params,
+        routes::proposal_deposit,
+        routes::proposal_deposits,
+        routes::proposal_details,
+        routes::proposal_tally,
+        routes::proposal_vote,
+        routes::proposal_votes,
+        routes::proposals,
+        routes::redelegations,
+        routes::signing,
+        routes::calculations,
+        routes::staking_pool,
+        routes::supplies,
+        routes::supply,
+        routes::account,
+        routes::account_balances,
+        routes::account_txs,
+        routes::account_export,
+        routes::account_vesting,
+        routes::txs,
+        routes::tx_by_hash,
+        routes::txs_by_height,
+        routes::txs_of_recipient,
+        routes::txs_of_sender,
+        routes::txs_on_latest_block,
+        routes::unbonding_delegations,
+        routes::validator,
+        routes::validator_commission,
+        routes::validator_delegator_pair,
+        routes::validator_rewards,
+        routes::validator_delegations,
+        routes::validator_last_signed_blocks,
+        routes::validator_redelegations,
+        routes::validator_unbondings,
+        routes::validators_bonded,
+        routes::validator_set,
+        routes::validator_missed_blocks,
+        routes::validator_uptime,
+        routes::validator_signing_heatmap,
+        routes::validator_set_by_height,
+        routes::validators_of_delegator,
+        routes::validators_unbonded,
+        routes::validators_unbonding,
+        routes::validators_unspecified,
+        routes::evm_poll,
+        routes::evm_polls,
+        routes::evm_validator_votes,
+        routes::evm_val_supported_chains,
+        routes::validator_hearbeats,
+        routes::hearbeats,
+    ),
+    components(schemas(
+        AccountResponse,
+        VestingAccountResponse,
+        AmountListResponse,
+        ChainEntryListResponse,
+        ChainEntryResponse,
+        StringResponse,
+        StringListResponse,
+        NumberResponse,
+        AllChainsDashboardResponse,
+        AllChainsBlocksResponse,
+        AllChainsValidatorsResponse,
+        BatchResponse,
+        BlockListResponse,
+        BlockResponse,
+        BlockEventListResponse,
+        BlockHeadersResponse,
+        SignedBlockListResponse,
+        ChainListResponse,
+        DashboardResponse,
+        StatsResponse,
+        CommunityPoolResponse,
+        StakingPoolResponse,
+        ParamsResponse,
+        SupplyResponse,
+        SupplyPageResponse,
+        SigningInfoResponse,
+        SearchResultsResponse,
+        DelegationListResponse,
+        UnbondingListResponse,
+        RedelegationListResponse,
+        DelegatorRewardsResponse,
+        EvmPollListResponse,
+        EvmPollResponse,
+        EvmVoteListResponse,
+        HeartbeatListResponse,
+        HealthResponse,
+        ProposalListResponse,
+        ProposalResponse,
+        ProposalTallyResponse,
+        ProposalDepositListResponse,
+        ProposalDepositResponse,
+        ProposalVoteListResponse,
+        ProposalVoteResponse,
+        TransactionListResponse,
+        StoredTransactionListResponse,
+        TransactionResponse,
+        ValidatorResponse,
+        ValidatorListResponse,
+        ValidatorPageResponse,
+        ValidatorDelegationListResponse,
+        ValidatorUnbondingListResponse,
+        ValidatorRedelegationListResponse,
+        ValidatorCommissionResponse,
+        ValidatorRewardsResponse,
+        ValidatorSetResponse,
+        DelegatorValidatorListResponse,
+        DelegatorValidatorResponse,
+        MissedBlocksResponse,
+        UptimeResponse,
+        SigningHeatmapResponse,
+        TNRAppErrorResponse,
+        PaginationData,
+        PaginationDirection,
+        ProposalStatus,
+        TxStatus,
+        ValidatorHeartbeatsQBody,
+        ExportFormat,
+        ExportKind,
+        IntermediateChainConfig,
+        BatchItem,
+        BatchItemResult,
+        OutRestBlock,
+        OutRestCommunityPool,
+        OutRestStakingPool,
+        OutRestTransaction,
+        OutRestValidatorSet,
+        SupplyPage,
+        ValidatorPage,
+        ChainBlocksOutcome,
+        ChainDashboardOutcome,
+        ChainValidatorsOutcome,
+        AccountInfo,
+        NativeTokenBalanceInfo,
+        InternalVestingAccount,
+        InternalPeriod,
+        TransactionItem,
+        InternalTransaction,
+        TxResponseLog,
+        TxResponseEvent,
+        TxResponseEventAttribute,
+        ChainAmountItem,
+        ChainEntry,
+        AllChainsDashboard,
+        ChainDashboardSummary,
+        BlockForDb,
+        ValidatorForDb,
+        TransactionForDb,
+        BlockEventForDb,
+        BlockEventPhaseForDb,
+        BlockEventAttributeForDb,
+        EvmPollParticipantForDb,
+        HeartbeatForDb,
+        HeartbeatRawForDb,
+        InternalBlock,
+        InternalBlockSignature,
+        InternalBlockchainResp,
+        InternalBlockMeta,
+        InternalBlockHeader,
+        BlockId,
+        BlockIdParts,
+        BlockHeaderVersion,
+        ValidatorSignatureListElement,
+        BlockLastCommitSignatures,
+        CosmosEvent,
+        CosmosEventAttribute,
+        ChainDashboardInfoResponse,
+        ChainStatsInfoResponse,
+        TokenMarketHistory,
+        InternalMarketChart,
+        InternalStakingPool,
+        ChainParams,
+        ChainParamsStaking,
+        ChainParamsSlashing,
+        ChainParamsGov,
+        ChainParamsDistribution,
+        InternalSlashingSigningInfoItem,
+        ValidatorInternalDelegation,
+        ValidatorInternalUnbonding,
+        ValidatorInternalRedelegation,
+        InternalValidator,
+        ValidatorCommisionResp,
+        ValidatorCommision,
+        ValidatorRewardsResp,
+        ValidatorListResp,
+        ValidatorListElement,
+        ValidatorListElementValidatorCommission,
+        ValidatorListElementValidatorCommissionRates,
+        ValidatorListValidator,
+        ValidatorListValidatorDescription,
+        ValidatorListValidatorCommission,
+        ValidatorListValidatorCommissionRates,
+        ConsensusPubkey,
+        ValidatorResp,
+        ValidatorSetValidator,
+        ValidatorSetPubKey,
+        SearchResponse,
+        SearchKind,
+        InternalDelegation,
+        InternalUnbonding,
+        InternalRedelegation,
+        InternalDelegatorRewards,
+        InternalDelegatorReward,
+        EvmPollRespElement,
+        EvmPollOperatorInfo,
+        EvmPollVoteCountInfoElement,
+        EvmPollParticipantRespElement,
+        EvmPollVote,
+        HeartbeatStatus,
+        ProposalItem,
+        ProposalInfo,
+        InternalProposalDeposit,
+        InternalProposal,
+        InternalProposalFinalTallyResult,
+        InternalProposalVote,
+        ProposalOption,
+        ValidatorMissedBlocks,
+        ValidatorUptimePeriods,
+        ValidatorUptimePeriod,
+        ValidatorSigningBucket,
+        HealthReport,
+        HealthStatus,
+        ProbeResult,
+        ChainHealth,
+        CronJobHealth,
+        CronJobRun,
+        ChainResponseItem,
+    ))
+)]
+pub struct ApiDoc;
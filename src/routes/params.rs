@@ -4,17 +4,42 @@ use actix_web::{
     Responder,
 };
 
+use crate::cache::{CacheRoute, CachedResponse};
+use crate::chain::Chain;
 use crate::routes::{extract_chain, TNRAppError, TNRAppSuccessResponse};
 use crate::state::State;
 
 // ======== Parameter Methods ========
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/params",
+    tag = "params",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ParamsResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/params")]
 pub async fn params(path: Path<String>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
 
     let chain = extract_chain(&chain, chains)?;
     // Database can be used.
-    let data = chain.get_params_all().await?;
-    Ok(TNRAppSuccessResponse::new(data, None))
+    params_response(&chain).await
+}
+
+/// Returns the cached response of `{chain}/params`, it is shared with the batches.
+pub async fn params_response(chain: &Chain) -> Result<CachedResponse, TNRAppError> {
+    chain
+        .cache
+        .get_or_insert(CacheRoute::Params, "", || async {
+            let data = chain.get_params_all().await?;
+            Ok(TNRAppSuccessResponse::new(data, None))
+        })
+        .await
 }
@@ -4,14 +4,16 @@ use actix_web::{
     Responder,
 };
 
+use crate::chain::Chain;
 use crate::routes::PaginationDataQueryParams;
 use crate::routes::{extract_chain, TNRAppError, TNRAppSuccessResponse};
 use crate::state::State;
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 // ======== 'axelar' Propsals Methods ========
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ProposalStatus {
     Unspecified,
@@ -49,24 +51,61 @@ impl ProposalStatus {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ProposalsQueryParams {
-    status: Option<ProposalStatus>,
+    pub status: Option<ProposalStatus>,
     #[serde(flatten)]
-    pagination: PaginationDataQueryParams,
+    pub pagination: PaginationDataQueryParams,
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/proposals",
+    tag = "proposals",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ProposalsQueryParams,
+    ),
+    responses(
+        (status = 200, body = crate::routes::ProposalListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/proposals")]
 pub async fn proposals(path: Path<String>, chains: Data<State>, query: Query<ProposalsQueryParams>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
 
     let chain = extract_chain(&chain, chains)?;
+    proposals_response(&chain, query.into_inner()).await
+}
+
+/// Returns the response of `{chain}/proposals`, it is shared with the batches.
+pub async fn proposals_response(chain: &Chain, query: ProposalsQueryParams) -> Result<TNRAppSuccessResponse<impl Serialize>, TNRAppError> {
     let data = chain
-        .get_proposals_by_status(query.0.status.unwrap_or(ProposalStatus::Unspecified), query.0.pagination.into())
+        .get_proposals_by_status(query.status.unwrap_or(ProposalStatus::Unspecified), query.pagination.into())
         .await?;
     Ok(TNRAppSuccessResponse::from(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/proposal-deposits/{id}",
+    tag = "proposals",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("id" = u64, Path),
+        ProposalsQueryParams,
+    ),
+    responses(
+        (status = 200, body = crate::routes::ProposalDepositListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/proposal-deposits/{id}")]
 pub async fn proposal_deposits(
     path: Path<(String, u64)>,
@@ -80,15 +119,50 @@ pub async fn proposal_deposits(
     Ok(TNRAppSuccessResponse::from(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/proposal-details/{id}",
+    tag = "proposals",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("id" = u64, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ProposalResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/proposal-details/{id}")]
 pub async fn proposal_details(path: Path<(String, u64)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, proposal_id) = path.into_inner();
 
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.get_proposal_details(proposal_id).await?;
+    proposal_details_response(&chain, proposal_id).await
+}
+
+/// Returns the response of `{chain}/proposal-details`, it is shared with the batches.
+pub async fn proposal_details_response(chain: &Chain, id: u64) -> Result<TNRAppSuccessResponse<impl Serialize>, TNRAppError> {
+    let data = chain.get_proposal_details(id).await?;
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/proposal-tally/{id}",
+    tag = "proposals",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("id" = u64, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ProposalTallyResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/proposal-tally/{id}")]
 pub async fn proposal_tally(path: Path<(String, u64)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, proposal_id) = path.into_inner();
@@ -98,6 +172,22 @@ pub async fn proposal_tally(path: Path<(String, u64)>, chains: Data<State>) -> R
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/proposal-votes/{id}",
+    tag = "proposals",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("id" = u64, Path),
+        ProposalsQueryParams,
+    ),
+    responses(
+        (status = 200, body = crate::routes::ProposalVoteListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/proposal-votes/{id}")]
 pub async fn proposal_votes(
     path: Path<(String, u64)>,
@@ -111,6 +201,22 @@ pub async fn proposal_votes(
     Ok(TNRAppSuccessResponse::from(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/proposal-vote/{id}/{voter_address}",
+    tag = "proposals",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("id" = u64, Path),
+        ("voter_address" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ProposalVoteResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/proposal-vote/{id}/{voter_address}")]
 pub async fn proposal_vote(path: Path<(String, u64, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, proposal_id, voter_addr) = path.into_inner();
@@ -120,6 +226,22 @@ pub async fn proposal_vote(path: Path<(String, u64, String)>, chains: Data<State
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/proposal-deposit/{id}/{depositor_address}",
+    tag = "proposals",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("id" = u64, Path),
+        ("depositor_address" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ProposalDepositResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/proposal-deposit/{id}/{depositor_address}")]
 pub async fn proposal_deposit(path: Path<(String, u64, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, proposal_id, depositor_addr) = path.into_inner();
@@ -0,0 +1,68 @@
+use actix_web::{
+    get,
+    web::{Data, Path, Query},
+    Responder,
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use crate::routes::{extract_chain, TNRAppError, TNRAppErrorType, TNRAppSuccessResponse};
+use crate::state::State;
+
+// ======== Search Methods ========
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchQueryParams {
+    /// A height, block hash, tx hash, address or proposal ID.
+    pub q: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{chain}/search",
+    tag = "search",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        SearchQueryParams,
+    ),
+    responses(
+        (status = 200, body = crate::routes::SearchResultsResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[get("{chain}/search")]
+pub async fn search(path: Path<String>, chains: Data<State>, query: Query<SearchQueryParams>) -> Result<impl Responder, TNRAppError> {
+    let chain = path.into_inner();
+
+    let chain = extract_chain(&chain, chains)?;
+    let data = chain.search(&query.q).await?;
+    Ok(TNRAppSuccessResponse::new(data, None))
+}
+
+/// Only bech32 addresses can be searched without a chain, they are routed to the chain with the matching prefix.
+#[utoipa::path(
+    get,
+    path = "/search",
+    tag = "search",
+    params(
+        SearchQueryParams,
+    ),
+    responses(
+        (status = 200, body = crate::routes::SearchResultsResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[get("search")]
+pub async fn search_all_chains(chains: Data<State>, query: Query<SearchQueryParams>) -> Result<impl Responder, TNRAppError> {
+    let chain = chains.get_by_bech32_address(query.q.trim()).map_err(|e| TNRAppError {
+        message: Some(e),
+        error_type: TNRAppErrorType::BadRequestError,
+    })?;
+
+    let data = chain.search(&query.q).await?;
+    Ok(TNRAppSuccessResponse::new(data, None))
+}
@@ -9,6 +9,21 @@ use crate::state::State;
 
 // ======== Signing Information Methods ========
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/signing/{cons_address}",
+    tag = "signing_info",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("cons_address" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::SigningInfoResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/signing/{cons_address}")]
 pub async fn signing(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, cons_addr) = path.into_inner();
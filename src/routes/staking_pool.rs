@@ -5,17 +5,36 @@ use actix_web::{
 };
 use serde::Serialize;
 
+use crate::chain::Chain;
 use crate::routes::{extract_chain, TNRAppError, TNRAppSuccessResponse};
 use crate::state::State;
 
 // ======== Staking Pool Methods ========
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/staking-pool",
+    tag = "staking_pool",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    responses(
+        (status = 200, body = crate::routes::StakingPoolResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/staking-pool")]
 pub async fn staking_pool(path: Path<String>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
 
     let chain = extract_chain(&chain, chains)?;
     // Database can be used.
+    staking_pool_response(&chain).await
+}
+
+/// Returns the response of `{chain}/staking-pool`, it is shared with the batches.
+pub async fn staking_pool_response(chain: &Chain) -> Result<TNRAppSuccessResponse<impl Serialize>, TNRAppError> {
     let data = chain.get_staking_pool().await?;
     Ok(TNRAppSuccessResponse::new(data, None))
 }
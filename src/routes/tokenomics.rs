@@ -4,11 +4,30 @@ use actix_web::{
     Responder,
 };
 
+use serde::Serialize;
+
+use crate::cache::{CacheRoute, CachedResponse};
+use crate::chain::Chain;
 use crate::routes::{extract_chain, PaginationData, TNRAppError, TNRAppSuccessResponse};
 use crate::state::State;
 
 // ======== Tokenomic Methods ========
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/supply/{denom}",
+    tag = "tokenomics",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("denom" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::SupplyResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/supply/{denom}")]
 pub async fn supply(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, denom) = path.into_inner();
@@ -17,19 +36,62 @@ pub async fn supply(path: Path<(String, String)>, chains: Data<State>) -> Result
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/supplies",
+    tag = "tokenomics",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::SupplyPageResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/supplies")]
 pub async fn supplies(path: Path<String>, chains: Data<State>, query: Query<PaginationData>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
 
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.get_supply_of_all_tokens(query.into_inner()).await?;
+    supplies_response(&chain, query.into_inner()).await
+}
+
+/// Returns the response of `{chain}/supplies`, it is shared with the batches.
+pub async fn supplies_response(chain: &Chain, config: PaginationData) -> Result<TNRAppSuccessResponse<impl Serialize>, TNRAppError> {
+    let data = chain.get_supply_of_all_tokens(config).await?;
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/inflation",
+    tag = "tokenomics",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    responses(
+        (status = 200, body = crate::routes::NumberResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/inflation")]
 pub async fn inflation(path: Path<String>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.get_inflation_rate().await?;
-    Ok(TNRAppSuccessResponse::new(data, None))
+    inflation_response(&chain).await
+}
+
+/// Returns the cached response of `{chain}/inflation`, it is shared with the batches.
+pub async fn inflation_response(chain: &Chain) -> Result<CachedResponse, TNRAppError> {
+    chain
+        .cache
+        .get_or_insert(CacheRoute::Inflation, "", || async {
+            let data = chain.get_inflation_rate().await?;
+            Ok(TNRAppSuccessResponse::new(data, None))
+        })
+        .await
 }
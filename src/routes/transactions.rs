@@ -4,82 +4,283 @@ use actix_web::{
     web::{Data, Path},
     Responder,
 };
+use mongodb::bson::{doc, Document};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
-use crate::routes::{extract_chain, LastCountListsQueryParams, PaginationData, QueryParams, TNRAppError, TNRAppSuccessResponse};
-use crate::{fetch::others::PaginationConfig, state::State};
+use crate::chain::Chain;
+use crate::routes::{extract_chain, range_query, LastCountListsQueryParams, PaginationData, TNRAppError, TNRAppSuccessResponse, DEFAULT_LAST_COUNT};
+use crate::{database::AddressTxRoleForDb, state::State};
 
 // ======== Transaction Methods ========
 
+#[derive(Deserialize, Debug, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TxsFilterQueryParams {
+    /// The height the transactions start from, inclusive.
+    pub from_height: Option<u64>,
+    /// The height the transactions end at, inclusive.
+    pub to_height: Option<u64>,
+    /// The time in milliseconds the transactions start from, inclusive.
+    pub from: Option<i64>,
+    /// The time in milliseconds the transactions end at, inclusive.
+    pub to: Option<i64>,
+    /// Comma separated message types. Eg: `Send,Delegate`
+    #[serde(rename = "type")]
+    pub message_type: Option<String>,
+    pub status: Option<TxStatus>,
+    /// The minimum amount of the transactions in the main token of the chain, in its display unit. The transactions in the other tokens are not matched. Eg: `100.5`
+    pub min_amount: Option<f64>,
+}
+
+impl TxsFilterQueryParams {
+    /// Returns the transactions query of the filters, `None` if no filter is given.\
+    /// The filters are covered by the indexes of the transactions collection, see `DatabaseTR::create_indexes`.
+    pub fn to_query(&self) -> Result<Option<Document>, TNRAppError> {
+        let mut query = Document::new();
+
+        if let Some(time) = range_query("time", self.from, self.to)? {
+            query.extend(time);
+        }
+        if let Some(height) = range_query(
+            "height",
+            self.from_height.map(|height| height as i64),
+            self.to_height.map(|height| height as i64),
+        )? {
+            query.extend(height);
+        }
+
+        if let Some(message_type) = &self.message_type {
+            let message_types: Vec<&str> = message_type.split(',').map(str::trim).filter(|t| !t.is_empty()).collect();
+            if !message_types.is_empty() {
+                query.insert("message_types", doc! {"$in": message_types});
+            }
+        }
+
+        if let Some(status) = self.status {
+            query.insert("result", status.as_result());
+        }
+
+        if let Some(min_amount) = self.min_amount {
+            query.insert("main_amount", doc! {"$gte": min_amount});
+        }
+
+        Ok(match query.is_empty() {
+            true => None,
+            false => Some(query),
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TxStatus {
+    Success,
+    Failed,
+}
+
+impl TxStatus {
+    /// Returns the result of the transactions with the status, as it is saved to the database.
+    pub fn as_result(&self) -> &'static str {
+        match self {
+            Self::Success => "Success",
+            Self::Failed => "Failed",
+        }
+    }
+}
+
+/// The transactions, from the newest to the oldest. They can be filtered by height, time, message type, status and amount.
+#[utoipa::path(
+    get,
+    path = "/{chain}/txs",
+    tag = "transactions",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        PaginationData,
+        TxsFilterQueryParams,
+    ),
+    responses(
+        (status = 200, body = crate::routes::StoredTransactionListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/txs")]
-pub async fn txs(path: Path<String>, chains: Data<State>, query: Query<PaginationData>) -> Result<impl Responder, TNRAppError> {
+pub async fn txs(
+    path: Path<String>,
+    chains: Data<State>,
+    query: Query<PaginationData>,
+    filter: Query<TxsFilterQueryParams>,
+) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
 
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.database.find_paginated_txs(None, query.into_inner()).await?;
+    let data = chain.database.find_paginated_txs(filter.to_query()?, query.into_inner()).await?;
     Ok(TNRAppSuccessResponse::new(data.data, Some(data.pagination)))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/tx/{hash}",
+    tag = "transactions",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("hash" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::TransactionResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/tx/{hash}")]
 pub async fn tx_by_hash(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, hash) = path.into_inner();
 
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.get_tx_by_hash(&hash).await?;
+    tx_response(&chain, &hash).await
+}
+
+/// Returns the response of `{chain}/tx`, it is shared with the batches.
+pub async fn tx_response(chain: &Chain, hash: &str) -> Result<TNRAppSuccessResponse<impl Serialize>, TNRAppError> {
+    let data = chain.get_stored_tx_by_hash(hash).await?;
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/txs-on-latest-block",
+    tag = "transactions",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::TransactionListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/txs-on-latest-block")]
-pub async fn txs_on_latest_block(path: Path<String>, chains: Data<State>, query: Query<QueryParams>) -> Result<impl Responder, TNRAppError> {
+pub async fn txs_on_latest_block(path: Path<String>, chains: Data<State>, query: Query<PaginationData>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
 
-    let config = PaginationConfig::new().limit(query.limit.unwrap_or(20)).page(query.page.unwrap_or(1));
-
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.get_txs_by_height(None, config).await?;
-    Ok(TNRAppSuccessResponse::new(data, None))
+    let data = chain.get_txs_by_height(None, query.into_inner()).await?;
+    Ok(TNRAppSuccessResponse::from(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/txs-by-height/{heigth}",
+    tag = "transactions",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("heigth" = u64, Path),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::TransactionListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/txs-by-height/{heigth}")]
-pub async fn txs_by_height(path: Path<(String, u64)>, chains: Data<State>, query: Query<QueryParams>) -> Result<impl Responder, TNRAppError> {
+pub async fn txs_by_height(path: Path<(String, u64)>, chains: Data<State>, query: Query<PaginationData>) -> Result<impl Responder, TNRAppError> {
     let (chain, height) = path.into_inner();
 
-    let config = PaginationConfig::new().limit(query.limit.unwrap_or(20)).page(query.page.unwrap_or(1));
-
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.get_txs_by_height(Some(height), config).await?;
-    Ok(TNRAppSuccessResponse::new(data, None))
+    let data = chain.get_txs_by_height(Some(height), query.into_inner()).await?;
+    Ok(TNRAppSuccessResponse::from(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/txs-of-sender/{address}",
+    tag = "transactions",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("address" = String, Path),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::TransactionListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/txs-of-sender/{address}")]
-pub async fn txs_of_sender(path: Path<(String, String)>, chains: Data<State>, query: Query<QueryParams>) -> Result<impl Responder, TNRAppError> {
+pub async fn txs_of_sender(path: Path<(String, String)>, chains: Data<State>, query: Query<PaginationData>) -> Result<impl Responder, TNRAppError> {
     let (chain, sender_addr) = path.into_inner();
 
-    let config = PaginationConfig::new().limit(query.limit.unwrap_or(20)).page(query.page.unwrap_or(1));
-
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.get_txs_by_sender(&sender_addr, config).await?;
-    Ok(TNRAppSuccessResponse::new(data, None))
+    let data = chain
+        .get_address_txs(&sender_addr, Some(AddressTxRoleForDb::Sender), query.into_inner())
+        .await?;
+    Ok(TNRAppSuccessResponse::from(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/txs-of-recipient/{address}",
+    tag = "transactions",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("address" = String, Path),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::TransactionListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/txs-of-recipient/{address}")]
-pub async fn txs_of_recipient(path: Path<(String, String)>, chains: Data<State>, query: Query<QueryParams>) -> Result<impl Responder, TNRAppError> {
+pub async fn txs_of_recipient(
+    path: Path<(String, String)>,
+    chains: Data<State>,
+    query: Query<PaginationData>,
+) -> Result<impl Responder, TNRAppError> {
     let (chain, recipient_addr) = path.into_inner();
 
-    let config = PaginationConfig::new().limit(query.limit.unwrap_or(20)).page(query.page.unwrap_or(1));
-
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.get_txs_by_recipient(&recipient_addr, config).await?;
-    Ok(TNRAppSuccessResponse::new(data, None))
+    let data = chain
+        .get_address_txs(&recipient_addr, Some(AddressTxRoleForDb::Recipient), query.into_inner())
+        .await?;
+    Ok(TNRAppSuccessResponse::from(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/last-txs",
+    tag = "transactions",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        LastCountListsQueryParams,
+    ),
+    responses(
+        (status = 200, body = crate::routes::StoredTransactionListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/last-txs")]
 pub async fn last_txs(path: Path<String>, chains: Data<State>, query: Query<LastCountListsQueryParams>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
 
-    let default_count = 10;
-    let count = query.count.unwrap_or(default_count);
-
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.get_last_txs_from_db(count).await?;
+    last_txs_response(&chain, query.into_inner()).await
+}
+
+/// Returns the response of `{chain}/last-txs`, it is shared with the batches.
+pub async fn last_txs_response(chain: &Chain, query: LastCountListsQueryParams) -> Result<TNRAppSuccessResponse<impl Serialize>, TNRAppError> {
+    let data = chain.get_last_txs_from_db(query.count.unwrap_or(DEFAULT_LAST_COUNT)).await?;
     Ok(TNRAppSuccessResponse::new(data, None))
 }
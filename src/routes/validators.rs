@@ -1,4 +1,6 @@
 use crate::{
+    cache::{CacheRoute, CachedResponse},
+    chain::Chain,
     fetch::validators::InternalRedelegation,
     routes::{extract_chain, PaginationData, TNRAppError, TNRAppSuccessResponse},
 };
@@ -12,19 +14,56 @@ use actix_web::{
     Responder,
 };
 use mongodb::bson::doc;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
 
 // ======== Validator Methods ========
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validator/{address}",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("address" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ValidatorResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validator/{address}")]
 pub async fn validator(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, validator_addr) = path.into_inner();
 
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.get_validator_info(&validator_addr).await?;
+    validator_response(&chain, &validator_addr).await
+}
+
+/// Returns the response of `{chain}/validator`, it is shared with the batches.
+pub async fn validator_response(chain: &Chain, address: &str) -> Result<TNRAppSuccessResponse<impl Serialize>, TNRAppError> {
+    let data = chain.get_validator_info(address).await?;
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validator-delegations/{address}",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("address" = String, Path),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::ValidatorDelegationListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validator-delegations/{address}")]
 pub async fn validator_delegations(
     path: Path<(String, String)>,
@@ -38,6 +77,22 @@ pub async fn validator_delegations(
     Ok(TNRAppSuccessResponse::from(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validator-unbondings/{address}",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("address" = String, Path),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::ValidatorUnbondingListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validator-unbondings/{address}")]
 pub async fn validator_unbondings(
     path: Path<(String, String)>,
@@ -51,6 +106,22 @@ pub async fn validator_unbondings(
     Ok(TNRAppSuccessResponse::from(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validator-redelegations/{address}",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("address" = String, Path),
+        ValidatorRedelegationQueryParams,
+    ),
+    responses(
+        (status = 200, body = crate::routes::ValidatorRedelegationListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validator-redelegations/{address}")]
 pub async fn validator_redelegations(
     path: Path<(String, String)>,
@@ -70,6 +141,21 @@ pub async fn validator_redelegations(
     Ok(data.into())
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validator-commission/{address}",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("address" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ValidatorCommissionResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validator-commission/{address}")]
 pub async fn validator_commission(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, validator_addr) = path.into_inner();
@@ -79,6 +165,21 @@ pub async fn validator_commission(path: Path<(String, String)>, chains: Data<Sta
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validator-rewards/{address}",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("address" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ValidatorRewardsResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validator-rewards/{address}")]
 pub async fn validator_rewards(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, validator_addr) = path.into_inner();
@@ -88,21 +189,61 @@ pub async fn validator_rewards(path: Path<(String, String)>, chains: Data<State>
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validators-bonded",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::ValidatorListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validators-bonded")]
 pub async fn validators_bonded(path: Path<String>, chains: Data<State>, query: Query<PaginationData>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
     let chain = extract_chain(&chain, chains)?;
-    let validator_db_resp = chain
-        .database
-        .find_paginated_validators(Some(doc! { "is_active": true }), query.into_inner())
-        .await?;
+    validators_bonded_response(&chain, query.into_inner()).await
+}
 
-    let pagination = validator_db_resp.pagination.clone();
-    let data = ValidatorListResp::from_db_list(validator_db_resp, &chain).await?;
+/// Returns the cached response of `{chain}/validators-bonded`, it is shared with the batches.\
+/// The responses are cached by the parsed pagination, so the unknown parameters and their order don't make new keys.
+pub async fn validators_bonded_response(chain: &Chain, config: PaginationData) -> Result<CachedResponse, TNRAppError> {
+    let query = serde_json::to_string(&config).map_err(|e| format!("Cannot serialize the pagination: {e}"))?;
 
-    Ok(TNRAppSuccessResponse::new(data, Some(pagination)))
+    chain
+        .cache
+        .get_or_insert(CacheRoute::ValidatorsBonded, &query, || async {
+            let validator_db_resp = chain.database.find_paginated_validators(Some(doc! { "is_active": true }), config).await?;
+
+            let pagination = validator_db_resp.pagination.clone();
+            let data = ValidatorListResp::from_db_list(validator_db_resp, chain).await?;
+
+            Ok(TNRAppSuccessResponse::new(data, Some(pagination)))
+        })
+        .await
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validators-unbonded",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::ValidatorListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validators-unbonded")]
 pub async fn validators_unbonded(path: Path<String>, chains: Data<State>, query: Query<PaginationData>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
@@ -118,6 +259,19 @@ pub async fn validators_unbonded(path: Path<String>, chains: Data<State>, query:
     Ok(TNRAppSuccessResponse::new(data, Some(pagination)))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validators-unbonding",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ValidatorPageResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validators-unbonding")]
 pub async fn validators_unbonding(path: Path<String>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
@@ -127,6 +281,19 @@ pub async fn validators_unbonding(path: Path<String>, chains: Data<State>) -> Re
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validators-unspecified",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ValidatorPageResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validators-unspecified")]
 pub async fn validators_unspecified(path: Path<String>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
@@ -136,15 +303,51 @@ pub async fn validators_unspecified(path: Path<String>, chains: Data<State>) ->
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validators-of/{address}",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("address" = String, Path),
+        PaginationData,
+    ),
+    responses(
+        (status = 200, body = crate::routes::DelegatorValidatorListResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validators-of/{address}")]
-pub async fn validators_of_delegator(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
+pub async fn validators_of_delegator(
+    path: Path<(String, String)>,
+    chains: Data<State>,
+    query: Query<PaginationData>,
+) -> Result<impl Responder, TNRAppError> {
     let (chain, delegator_addr) = path.into_inner();
 
     let chain = extract_chain(&chain, chains)?;
-    let data = chain.get_validators_by_delegator(&delegator_addr, PaginationData::default()).await?;
-    Ok(TNRAppSuccessResponse::new(data, None))
+    let data = chain.get_validators_by_delegator(&delegator_addr, query.into_inner()).await?;
+    Ok(TNRAppSuccessResponse::from(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validator-delegator-pair/{validator_address}/{delegator_address}",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("validator_address" = String, Path),
+        ("delegator_address" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::DelegatorValidatorResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validator-delegator-pair/{validator_address}/{delegator_address}")]
 pub async fn validator_delegator_pair(path: Path<(String, String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, validator_addr, delegator_addr) = path.into_inner();
@@ -154,6 +357,21 @@ pub async fn validator_delegator_pair(path: Path<(String, String, String)>, chai
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validator-set/{height}",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("height" = i64, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ValidatorSetResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validator-set/{height}")]
 pub async fn validator_set_by_height(path: Path<(String, i64)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let (chain, height) = path.into_inner();
@@ -163,6 +381,19 @@ pub async fn validator_set_by_height(path: Path<(String, i64)>, chains: Data<Sta
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{chain}/validator-set",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+    ),
+    responses(
+        (status = 200, body = crate::routes::ValidatorSetResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
 #[get("{chain}/validator-set")]
 pub async fn validator_set(path: Path<String>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
     let chain = path.into_inner();
@@ -172,7 +403,106 @@ pub async fn validator_set(path: Path<String>, chains: Data<State>) -> Result<im
     Ok(TNRAppSuccessResponse::new(data, None))
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ValidatorSigningQueryParams {
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+    /// Only used by the heatmap.
+    pub buckets: Option<u64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{chain}/validator-missed-blocks/{address}",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("address" = String, Path),
+        ValidatorSigningQueryParams,
+    ),
+    responses(
+        (status = 200, body = crate::routes::MissedBlocksResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[get("{chain}/validator-missed-blocks/{address}")]
+pub async fn validator_missed_blocks(
+    path: Path<(String, String)>,
+    chains: Data<State>,
+    query: Query<ValidatorSigningQueryParams>,
+) -> Result<impl Responder, TNRAppError> {
+    let (chain, validator_addr) = path.into_inner();
+
+    let chain = extract_chain(&chain, chains)?;
+    let data = chain.get_validator_missed_blocks(&validator_addr, query.from, query.to).await?;
+    Ok(TNRAppSuccessResponse::new(data, None))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{chain}/validator-uptime/{address}",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("address" = String, Path),
+    ),
+    responses(
+        (status = 200, body = crate::routes::UptimeResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[get("{chain}/validator-uptime/{address}")]
+pub async fn validator_uptime(path: Path<(String, String)>, chains: Data<State>) -> Result<impl Responder, TNRAppError> {
+    let (chain, validator_addr) = path.into_inner();
+
+    let chain = extract_chain(&chain, chains)?;
+    validator_uptime_response(&chain, &validator_addr).await
+}
+
+/// Returns the response of `{chain}/validator-uptime`, it is shared with the batches.
+pub async fn validator_uptime_response(chain: &Chain, address: &str) -> Result<TNRAppSuccessResponse<impl Serialize>, TNRAppError> {
+    let data = chain.get_validator_uptime_periods(address).await?;
+    Ok(TNRAppSuccessResponse::new(data, None))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{chain}/validator-signing-heatmap/{address}",
+    tag = "validators",
+    params(
+        ("chain" = String, Path, description = "Chain name. Eg: `axelar`"),
+        ("address" = String, Path),
+        ValidatorSigningQueryParams,
+    ),
+    responses(
+        (status = 200, body = crate::routes::SigningHeatmapResponse),
+        (status = 400, body = crate::routes::TNRAppErrorResponse),
+        (status = 404, body = crate::routes::TNRAppErrorResponse),
+        (status = 500, body = crate::routes::TNRAppErrorResponse),
+    ),
+)]
+#[get("{chain}/validator-signing-heatmap/{address}")]
+pub async fn validator_signing_heatmap(
+    path: Path<(String, String)>,
+    chains: Data<State>,
+    query: Query<ValidatorSigningQueryParams>,
+) -> Result<impl Responder, TNRAppError> {
+    let (chain, validator_addr) = path.into_inner();
+
+    let chain = extract_chain(&chain, chains)?;
+    let data = chain
+        .get_validator_signing_heatmap(&validator_addr, query.from, query.to, query.buckets)
+        .await?;
+    Ok(TNRAppSuccessResponse::new(data, None))
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ValidatorRedelegationQueryParams {
     #[serde(flatten)]
     pub pagination: PaginationData,
@@ -1,13 +1,21 @@
-use std::collections::HashSet;
+use std::sync::Arc;
 
 use actix_cors::Cors;
+use actix_web::http::header;
 use actix_web::web::Json;
 use actix_web::{get, web, App, HttpServer, Responder};
 use tokio::sync::broadcast::channel;
 use tracing_actix_web::TracingLogger;
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
 use web::Data;
 
-use crate::events::{run_ws, WsEvent};
+use crate::config::ServerConfig;
+use crate::events::{run_ws, SequencedWsEvent};
+use crate::graphql;
+use crate::grpc::run_grpc;
+use crate::metrics::RequestMetrics;
+use crate::rate_limit::{RateLimit, RateLimiter, API_KEY_HEADER};
 use crate::routes;
 use crate::state::State;
 
@@ -17,58 +25,112 @@ async fn initial() -> impl Responder {
 }
 
 /// Starts the web server.
-pub async fn start_web_server() -> std::io::Result<()> {
+pub async fn start_web_server(config: ServerConfig) -> std::io::Result<()> {
     // Create the state of the app.
-    let state = Data::new(State::new().await);
+    let state = Data::new(State::new(config.clone()).await);
 
-    // Start running cron jobs to update MongoDB database.
-    state.run_cron_jobs();
+    // The socket events are passed to the ingestion sinks of each chain, see `src/ingest`.
+    // The broadcast sink sends them to the websocket clients through this channel, after writing them to the event log.
 
-    // Spawn a thread to subscribe to events.
-    let state_clone = state.clone();
-
-    // After connecting to MongoDB, there are so many thread safety & ownership errors.
-    // You have to rewrite `src/fetch/socket.rs` to fix them.
+    let (tx, _rx) = channel::<(String, SequencedWsEvent)>(config.broadcast_capacity);
 
-    let (tx, _rx) = channel::<(String, WsEvent)>(100);
+    // Start running cron jobs to update MongoDB database, and subscribe to the events of the chains.
+    // The chains added by the admin routes are started the same way.
+    state.start_chains(tx.clone(), config.cron.clone());
 
-    let tx_clone = tx.clone();
+    // Retry the chains failed to initialize in the background.
+    let state_clone = state.clone();
     tokio::spawn(async move {
-        let tx_clone = tx_clone.clone();
-        state_clone.subscribe_to_events(tx_clone).await;
+        state_clone.retry_failed_chains().await;
     });
 
-    let chains = HashSet::from_iter(state.get_chains().keys().cloned());
+    let chains = state.chains();
 
     let tx_clone = tx.clone();
+    let ws_address = config.ws_address;
+    let ws_max_connections = config.ws_max_connections;
+    let ws_clients = state.ws_clients();
     tokio::spawn(async move {
-        if let Err(e) = run_ws(tx_clone, chains).await {
+        if let Err(e) = run_ws(tx_clone, chains, ws_address, ws_max_connections, ws_clients).await {
             tracing::error!("Error spawning the websocket task {e}");
         };
     });
 
-    HttpServer::new(move || {
+    let grpc_state = state.clone();
+    let tx_clone = tx.clone();
+    let grpc_address = config.grpc_address;
+    tokio::spawn(async move {
+        if let Err(e) = run_grpc(grpc_state, tx_clone, grpc_address).await {
+            tracing::error!("Error spawning the gRPC task {e}");
+        };
+    });
+
+    // The GraphQL schema is built once and shared by the workers.
+    let schema = Data::new(graphql::build_schema(state.clone()));
+
+    // The rate limiter is shared by the workers, so the limits are not multiplied by the worker count.
+    let limiter = Arc::new(RateLimiter::new(state.database().clone(), &config));
+
+    let cors_config = config.clone();
+    let mut server = HttpServer::new(move || {
         // Build a CORS middleware.
-        let cors = Cors::default()
-            .allow_any_origin()
-            .allowed_methods(vec!["GET", "POST"])
+        let cors = match cors_config.allows_any_origin() {
+            true => Cors::default().allow_any_origin(),
+            false => cors_config
+                .allowed_origins
+                .iter()
+                .fold(Cors::default(), |cors, origin| cors.allowed_origin(origin)),
+        };
+
+        let cors = cors
+            .allowed_methods(vec!["GET", "POST", "PUT", "DELETE"])
             //.allowed_headers(vec![header::AUTHORIZATION, header::ACCEPT])
             //.allowed_header(header::CONTENT_TYPE)
+            .allowed_header(API_KEY_HEADER)
+            .expose_headers(vec![header::RETRY_AFTER])
             .max_age(3600);
 
         // Build the app.
         App::new()
+            .wrap(RateLimit::new(limiter.clone()))
+            .wrap(RequestMetrics)
             .wrap(TracingLogger::default())
             .wrap(cors)
             // State data.
             .app_data(state.clone())
+            .app_data(schema.clone())
             // Services.
             .service(initial)
+            // Health routes of the load balancers.
+            .service(routes::health)
+            .service(routes::ready)
+            // Prometheus metrics.
+            .service(routes::metrics)
+            // GraphQL endpoint and its GraphiQL page.
+            .service(graphql::graphql)
+            .service(graphql::graphiql)
+            // OpenAPI specification and its docs page.
+            .service(SwaggerUi::new("/docs/{_:.*}").url("/openapi.json", routes::ApiDoc::openapi()))
+            // The admin routes must be registered before the chain routes too, not to be matched as a chain named `admin`.
+            .service(routes::admin_chains)
+            .service(routes::admin_upsert_chain)
+            .service(routes::admin_remove_chain)
+            .service(routes::admin_start_chain)
+            .service(routes::admin_stop_chain)
+            .service(routes::admin_reload_chain)
+            // The cross-chain routes must be registered before the chain routes, not to be matched as a chain named `all`.
+            .service(routes::all_dashboard)
+            .service(routes::all_latest_blocks)
+            .service(routes::all_validators)
+            .service(routes::batch)
             .service(routes::dashboard)
             .service(routes::stats)
+            .service(routes::search)
             .service(routes::chains)
+            .service(routes::search_all_chains)
             .service(routes::block_by_hash)
             .service(routes::block_by_height)
+            .service(routes::block_events)
             .service(routes::headers_by_heights)
             .service(routes::community_pool)
             .service(routes::delegations)
@@ -94,6 +156,8 @@ pub async fn start_web_server() -> std::io::Result<()> {
             .service(routes::supply)
             .service(routes::account)
             .service(routes::account_balances)
+            .service(routes::account_txs)
+            .service(routes::account_export)
             .service(routes::account_vesting)
             .service(routes::txs)
             .service(routes::tx_by_hash)
@@ -112,6 +176,9 @@ pub async fn start_web_server() -> std::io::Result<()> {
             .service(routes::validator_unbondings)
             .service(routes::validators_bonded)
             .service(routes::validator_set)
+            .service(routes::validator_missed_blocks)
+            .service(routes::validator_uptime)
+            .service(routes::validator_signing_heatmap)
             .service(routes::validator_set_by_height)
             .service(routes::validators_of_delegator)
             .service(routes::validators_unbonded)
@@ -123,9 +190,20 @@ pub async fn start_web_server() -> std::io::Result<()> {
             .service(routes::evm_val_supported_chains)
             .service(routes::validator_hearbeats)
             .service(routes::hearbeats)
-    })
-    .bind(("127.0.0.1", 8080))
-    .unwrap()
-    .run()
-    .await
+    });
+
+    if let Some(workers) = config.workers {
+        server = server.workers(workers);
+    }
+
+    // The certificate is checked while the config is loaded, so it can be read here.
+    let server = match &config.tls {
+        Some(tls) => server.bind_openssl(
+            config.http_address,
+            tls.acceptor().map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?,
+        )?,
+        None => server.bind(config.http_address)?,
+    };
+
+    server.run().await
 }
@@ -1,18 +1,84 @@
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::Read;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
 
-use futures::{stream, StreamExt};
+use chrono::Utc;
+use dashmap::mapref::entry::Entry;
+use dashmap::DashMap;
+use once_cell::sync::OnceCell;
 use serde::{Deserialize, Serialize};
 use tokio::sync::broadcast::Sender;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+use tokio::task::JoinHandle;
+use utoipa::ToSchema;
 
+use crate::cache::ResponseCache;
 use crate::chain::{Chain, IntermediateChainConfig};
-use crate::database::DatabaseTR;
-use crate::events::WsEvent;
+use crate::config::{CronConfig, ServerConfig};
+use crate::database::{DatabaseTR, SHARED_DB_NAME};
+use crate::events::SequencedWsEvent;
+use crate::fetch::health::HealthCache;
+use crate::ingest::IngestPipeline;
+use crate::routes::{TNRAppError, TNRAppErrorType};
+
+/// The initialized chains by their names.\
+/// It is shared with the websocket server, so the chains added at runtime are served there too.
+pub type Chains = Arc<DashMap<String, Chain>>;
 
 /// The state of the server.
 pub struct State {
-    chains: HashMap<String, Chain>,
+    chains: Chains,
+    /// The configs of all the registered chains with their statuses, including the ones failed to initialize.
+    registry: DashMap<String, ChainEntry>,
+    /// The tasks of the running chains, the event subscription and the cron jobs.
+    tasks: DashMap<String, Vec<JoinHandle<()>>>,
+    /// The locks held while a chain is added, started, stopped, removed or retried, so these are not interleaved.
+    lifecycle_locks: DashMap<String, Arc<Mutex<()>>>,
+    /// The broadcast channel and the cron config the chains are started with. It is set once by `State::start_chains`.
+    runtime: OnceCell<ChainRuntime>,
+    /// The database that keeps the data not belonging to a chain.
+    database: DatabaseTR,
+    /// The count of the open websocket connections.
+    ws_clients: Arc<AtomicUsize>,
+    /// The last reports of `/health` and `/ready`.
+    health_cache: HealthCache,
+    config: ServerConfig,
+}
+
+/// The things the tasks of a chain are started with.
+struct ChainRuntime {
+    tx: Sender<(String, SequencedWsEvent)>,
+    cron: CronConfig,
+}
+
+/// A registered chain config, with the status of its chain.
+#[derive(Serialize, Clone, ToSchema)]
+pub struct ChainEntry {
+    pub config: IntermediateChainConfig,
+    /// `"stopped"`, `"running"` or `"failed"` in the `status` field, the failed chains have their `error`, `attempts` and `last_attempt_at`.
+    #[serde(flatten)]
+    #[schema(value_type = Object)]
+    pub status: ChainStatus,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ChainStatus {
+    /// The chain is initialized, but its ingestion and cron jobs are not running.
+    Stopped,
+    /// The ingestion and the cron jobs of the chain are running.
+    Running,
+    /// The chain failed to initialize, it is retried in the background.
+    Failed {
+        error: String,
+        /// The count of the failed initializations.
+        attempts: u32,
+        /// Milliseconds since the Unix epoch.
+        last_attempt_at: i64,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -20,101 +86,429 @@ pub struct AssetsManifest {
     files: Vec<String>,
 }
 
-impl State {
-    /// Creates a new `State`.
-    pub async fn new() -> State {
-        let chain_configs = match std::env::var("OFFLINE") {
-            Ok(var) if var == "true" => {
-                let mut yml = String::new();
-                File::open("Chains.yml")
-                    .expect("Missing Chains.yml file")
-                    .read_to_string(&mut yml)
-                    .unwrap();
-                let chain_configs: HashMap<String, IntermediateChainConfig> = serde_yaml::from_str(yml.as_str()).expect("Invalid Chains.yml format");
-                chain_configs
-            }
-            _ => {
-                let manifest: AssetsManifest = reqwest::get(format!("{}/chains.json", std::env::var("TNR_EXPLORER_ASSETS_URI").unwrap()))
+/// Returns the chain configs from `Chains.yml` if `OFFLINE` is `true`, or from the assets manifest otherwise.
+async fn load_chain_configs() -> HashMap<String, IntermediateChainConfig> {
+    match std::env::var("OFFLINE") {
+        Ok(var) if var == "true" => {
+            let mut yml = String::new();
+            File::open("Chains.yml")
+                .expect("Missing Chains.yml file")
+                .read_to_string(&mut yml)
+                .unwrap();
+            let chain_configs: HashMap<String, IntermediateChainConfig> = serde_yaml::from_str(yml.as_str()).expect("Invalid Chains.yml format");
+            chain_configs
+        }
+        _ => {
+            let manifest: AssetsManifest = reqwest::get(format!("{}/chains.json", std::env::var("TNR_EXPLORER_ASSETS_URI").unwrap()))
+                .await
+                .unwrap()
+                .json()
+                .await
+                .unwrap();
+            let mut chains = HashMap::new();
+            for file in manifest.files {
+                let content = reqwest::get(format!("{}/{file}", std::env::var("TNR_EXPLORER_ASSETS_URI").unwrap()))
                     .await
                     .unwrap()
-                    .json()
+                    .text()
                     .await
                     .unwrap();
-                let mut chains = HashMap::new();
-                for file in manifest.files {
-                    let content = reqwest::get(format!("{}/{file}", std::env::var("TNR_EXPLORER_ASSETS_URI").unwrap()))
-                        .await
-                        .unwrap()
-                        .text()
-                        .await
-                        .unwrap();
-                    let current_config: HashMap<String, IntermediateChainConfig> =
-                        serde_yaml::from_str(content.as_str()).expect("Invalid Chains.yml format");
-                    current_config.into_iter().for_each(|(name, config)| {
-                        chains.entry(name).or_insert(config);
-                    });
-                }
-
-                chains
+                let current_config: HashMap<String, IntermediateChainConfig> =
+                    serde_yaml::from_str(content.as_str()).expect("Invalid Chains.yml format");
+                current_config.into_iter().for_each(|(name, config)| {
+                    chains.entry(name).or_insert(config);
+                });
             }
+
+            chains
+        }
+    }
+}
+
+impl State {
+    /// Creates a new `State`.\
+    /// The chains failed to initialize are registered too, they are retried by `State::retry_failed_chains`.
+    pub async fn new(config: ServerConfig) -> State {
+        let state = State {
+            chains: Default::default(),
+            registry: DashMap::new(),
+            tasks: DashMap::new(),
+            lifecycle_locks: DashMap::new(),
+            runtime: OnceCell::new(),
+            database: DatabaseTR::new().await.change_name(SHARED_DB_NAME),
+            ws_clients: Default::default(),
+            health_cache: Default::default(),
+            config,
         };
 
-        let stream = stream::iter(chain_configs.into_iter());
+        for (name, config) in load_chain_configs().await {
+            let _ = state.initialize_chain(&name, config).await;
+        }
 
-        let chains = stream
-            .then(|(name, config)| async move {
-                let chain = match Chain::initialize(config, reqwest::Client::new(), DatabaseTR::new().await).await {
-                    Ok(mut chain) => {
-                        tracing::info!("Successfully initialized {name}");
-                        chain.database = chain.database.change_name(&name);
-                        Some(chain)
-                    }
-                    Err(e) => {
-                        tracing::error!("Error initializing chain {name}: {e}");
-                        None
-                    }
-                };
-                (name, chain)
-            })
-            .filter(|(_name, chain)| futures::future::ready(chain.is_some()))
-            .map(|(name, chain)| (name, chain.unwrap()))
-            .collect::<HashMap<String, Chain>>()
-            .await;
+        state
+    }
+
+    /// Initializes a chain and registers it with its config, or registers it as failed.
+    async fn initialize_chain(&self, name: &str, config: IntermediateChainConfig) -> Result<(), String> {
+        match self.build_chain(name, config.clone()).await {
+            Ok(chain) => {
+                self.register_chain(name, config, chain);
+                Ok(())
+            }
+            Err(e) => {
+                self.register_failure(name, config, e.clone());
+                Err(e)
+            }
+        }
+    }
+
+    /// Initializes a chain with its config, without registering it.
+    async fn build_chain(&self, name: &str, config: IntermediateChainConfig) -> Result<Chain, String> {
+        let mut chain = Chain::initialize(config, reqwest::Client::new(), DatabaseTR::new().await)
+            .await
+            .map_err(|e| {
+                tracing::error!("Error initializing chain {name}: {e}");
+                e
+            })?;
+
+        tracing::info!("Successfully initialized {name}");
+        chain.database = chain.database.change_name(name);
+        chain.cache = ResponseCache::new(&self.config.cache_ttl);
+
+        if let Err(e) = chain.database.create_indexes().await {
+            tracing::error!("Failed creating the indexes of {name}: {e}");
+        }
+
+        Ok(chain)
+    }
+
+    /// Registers an initialized chain with its config as stopped. It replaces the chain with the same name, if it is not running.
+    fn register_chain(&self, name: &str, config: IntermediateChainConfig, chain: Chain) {
+        self.chains.insert(name.to_string(), chain);
+        self.registry.insert(
+            name.to_string(),
+            ChainEntry {
+                config,
+                status: ChainStatus::Stopped,
+            },
+        );
+    }
+
+    /// Registers a chain failed to initialize with its config, so it is retried by `State::retry_failed_chains`.
+    fn register_failure(&self, name: &str, config: IntermediateChainConfig, error: String) {
+        let attempts = match self.registry.get(name).map(|entry| entry.status.clone()) {
+            Some(ChainStatus::Failed { attempts, .. }) => attempts + 1,
+            _ => 1,
+        };
 
-        State { chains }
+        self.chains.remove(name);
+        self.registry.insert(
+            name.to_string(),
+            ChainEntry {
+                config,
+                status: ChainStatus::Failed {
+                    error,
+                    attempts,
+                    last_attempt_at: Utc::now().timestamp_millis(),
+                },
+            },
+        );
     }
 
     /// Returns the matched chain.
     pub fn get(&self, name: &str) -> Result<Chain, String> {
-        self.chains.get(name).cloned().ok_or_else(|| format!("{name} is not a supported chain"))
+        self.chains
+            .get(name)
+            .map(|chain| chain.clone())
+            .ok_or_else(|| format!("{name} is not a supported chain"))
+    }
+
+    /// Starts the ingestion and the cron jobs of all the initialized chains.\
+    /// The chains added or retried later are started with the same channel and config.
+    pub fn start_chains(&self, tx: Sender<(String, SequencedWsEvent)>, cron: CronConfig) {
+        if self.runtime.set(ChainRuntime { tx, cron }).is_err() {
+            tracing::warn!("The chains are started already");
+            return;
+        }
+
+        let names: Vec<String> = self.chains.iter().map(|chain| chain.key().clone()).collect();
+        for name in names {
+            if let Err(e) = self.start_tasks(&name) {
+                tracing::error!("Failed starting {name}: {e}");
+            }
+        }
+    }
+
+    /// Starts the ingestion and the cron jobs of a chain. Nothing is done if the chain is running already.
+    pub async fn start_chain(&self, name: &str) -> Result<(), TNRAppError> {
+        let _lock = self.lock_chain(name).await;
+
+        self.start_tasks(name)
+    }
+
+    /// Starts the tasks of a chain, unless it has them already.
+    fn start_tasks(&self, name: &str) -> Result<(), TNRAppError> {
+        let runtime = self
+            .runtime
+            .get()
+            .ok_or_else(|| TNRAppError::new(TNRAppErrorType::UnsupportedError, "The chains are not started yet."))?;
+
+        let chain = self.initialized_chain(name)?;
+
+        match self.tasks.entry(name.to_string()) {
+            Entry::Occupied(_) => return Ok(()),
+            Entry::Vacant(entry) => {
+                let mut tasks = chain.cron_jobs_all(&runtime.cron);
+                tasks.push(subscribe_to_events(chain, runtime.tx.clone()));
+
+                entry.insert(tasks);
+            }
+        }
+
+        self.set_status(name, ChainStatus::Running);
+
+        tracing::info!("Started {name}");
+
+        Ok(())
+    }
+
+    /// Stops the ingestion and the cron jobs of a chain. The chain is still served by the routes.
+    pub async fn stop_chain(&self, name: &str) -> Result<(), TNRAppError> {
+        let _lock = self.lock_chain(name).await;
+
+        self.stop_tasks(name)
+    }
+
+    /// Aborts the tasks of a chain, and marks it as stopped.
+    fn stop_tasks(&self, name: &str) -> Result<(), TNRAppError> {
+        self.initialized_chain(name)?;
+
+        if let Some((_, tasks)) = self.tasks.remove(name) {
+            tasks.iter().for_each(JoinHandle::abort);
+            tracing::info!("Stopped {name}");
+        }
+
+        self.set_status(name, ChainStatus::Stopped);
+
+        Ok(())
+    }
+
+    /// Adds a chain, or replaces the chain with the same name.\
+    /// The chain is started after it is initialized, unless it was stopped by the admin routes.
+    /// The initialized chain with the same name is only replaced once the new config is initialized, it keeps running otherwise.
+    pub async fn upsert_chain(&self, name: &str, config: IntermediateChainConfig) -> Result<ChainEntry, TNRAppError> {
+        let _lock = self.lock_chain(name).await;
+
+        let chain = match self.build_chain(name, config.clone()).await {
+            Ok(chain) => chain,
+            Err(e) if self.chains.contains_key(name) => {
+                return Err(TNRAppError::new(
+                    TNRAppErrorType::BadRequestError,
+                    format!("{name} is kept with its current config, the new one cannot be initialized: {e}"),
+                ));
+            }
+            Err(e) => {
+                self.register_failure(name, config, e);
+                return self.get_chain_entry(name);
+            }
+        };
+
+        let was_stopped = self.is_stopped(name);
+
+        if self.tasks.contains_key(name) {
+            self.stop_tasks(name)?;
+        }
+
+        self.register_chain(name, config, chain);
+
+        if !was_stopped && self.runtime.get().is_some() {
+            self.start_tasks(name)?;
+        }
+
+        self.get_chain_entry(name)
     }
 
-    /// Updates all the validator databases of chain.
-    pub fn run_cron_jobs(&self) {
-        for chain in self.chains.values() {
-            chain.cron_jobs_all();
+    /// Stops a chain and removes it with its config. The data of the chain is kept in the database.
+    pub async fn remove_chain(&self, name: &str) -> Result<(), TNRAppError> {
+        let _lock = self.lock_chain(name).await;
+
+        if self.registry.remove(name).is_none() {
+            return Err(TNRAppError::new(
+                TNRAppErrorType::NotFoundError,
+                format!("{name} is not a registered chain."),
+            ));
         }
+
+        if let Some((_, tasks)) = self.tasks.remove(name) {
+            tasks.iter().for_each(JoinHandle::abort);
+        }
+
+        self.chains.remove(name);
+
+        tracing::info!("Removed {name}");
+
+        Ok(())
     }
 
-    /// Subscribes to all the events for all the chains.
-    pub async fn subscribe_to_events(&self, tx: Sender<(String, WsEvent)>) {
-        self.chains.clone().into_iter().for_each(|(name, chain)| {
-            let tx = tx.clone();
+    /// Initializes the chain again with its config, and restarts it if it was running.\
+    /// The chain keeps running with its current tasks if it cannot be initialized again.
+    pub async fn reload_chain(&self, name: &str) -> Result<ChainEntry, TNRAppError> {
+        let entry = self.get_chain_entry(name)?;
+
+        self.upsert_chain(name, entry.config).await
+    }
+
+    /// Retries the chains failed to initialize periodically, and starts the ones initialized. It never returns.\
+    /// The interval is `chain_init_retry_secs` of the server config.
+    pub async fn retry_failed_chains(&self) {
+        let duration = Duration::from_secs(self.config.chain_init_retry_secs);
+
+        loop {
+            tokio::time::sleep(duration).await;
+
+            let failed: Vec<String> = self
+                .registry
+                .iter()
+                .filter(|entry| matches!(entry.status, ChainStatus::Failed { .. }))
+                .map(|entry| entry.key().clone())
+                .collect();
+
+            for name in failed {
+                let _lock = self.lock_chain(&name).await;
+
+                // The chain may be removed, replaced or started by the admin routes since it is listed.
+                let config = match self.registry.get(&name) {
+                    Some(entry) if matches!(entry.status, ChainStatus::Failed { .. }) => entry.config.clone(),
+                    _ => continue,
+                };
 
-            tokio::spawn(async move {
-                loop {
-                    let tx_clone = tx.clone();
-                    match chain.subscribe_events(tx_clone).await {
-                        Ok(_) => tracing::info!("Stopped listening events for {name}"),
-                        Err(e) => tracing::error!("Failed listening events for {name}: {e}"),
+                if self.initialize_chain(&name, config).await.is_ok() && self.runtime.get().is_some() {
+                    if let Err(e) = self.start_tasks(&name) {
+                        tracing::error!("Failed starting {name}: {e}");
                     }
-                    tokio::time::sleep(tokio::time::Duration::from_secs(10)).await;
                 }
-            });
-        });
+            }
+        }
+    }
+
+    /// Returns the registered chain with its config and status.
+    pub fn get_chain_entry(&self, name: &str) -> Result<ChainEntry, TNRAppError> {
+        self.registry
+            .get(name)
+            .map(|entry| entry.clone())
+            .ok_or_else(|| TNRAppError::new(TNRAppErrorType::NotFoundError, format!("{name} is not a registered chain.")))
+    }
+
+    /// Returns all the registered chains with their configs and statuses, sorted by name.
+    pub fn get_chain_entries(&self) -> Vec<ChainEntry> {
+        let mut entries: Vec<ChainEntry> = self.registry.iter().map(|entry| entry.clone()).collect();
+        entries.sort_by(|a, b| a.config.name.cmp(&b.config.name));
+        entries
+    }
+
+    /// Returns `true` if the chain is stopped by the admin routes.
+    pub fn is_stopped(&self, name: &str) -> bool {
+        self.registry.get(name).map_or(false, |entry| entry.status == ChainStatus::Stopped)
     }
 
-    pub fn get_chains(&self) -> &HashMap<String, Chain> {
-        &self.chains
+    /// Returns the initialized chain, or the reason it is not initialized.
+    fn initialized_chain(&self, name: &str) -> Result<Chain, TNRAppError> {
+        if let Some(chain) = self.chains.get(name) {
+            return Ok(chain.clone());
+        }
+
+        match self.registry.get(name).map(|entry| entry.status.clone()) {
+            Some(ChainStatus::Failed { error, .. }) => Err(TNRAppError::new(
+                TNRAppErrorType::BadRequestError,
+                format!("{name} is not initialized: {error}"),
+            )),
+            _ => Err(TNRAppError::new(
+                TNRAppErrorType::NotFoundError,
+                format!("{name} is not a registered chain."),
+            )),
+        }
+    }
+
+    /// Waits for the other changes of a chain to finish, and returns the lock kept until the change is done.
+    async fn lock_chain(&self, name: &str) -> OwnedMutexGuard<()> {
+        let lock = self.lifecycle_locks.entry(name.to_string()).or_default().clone();
+
+        lock.lock_owned().await
+    }
+
+    /// Sets the status of a registered chain.
+    fn set_status(&self, name: &str, status: ChainStatus) {
+        if let Some(mut entry) = self.registry.get_mut(name) {
+            entry.status = status;
+        }
+    }
+
+    /// Returns the chain that given bech32 address belongs to, using the account, valoper and valcons prefixes of the chains.
+    pub fn get_by_bech32_address(&self, address: &str) -> Result<Chain, String> {
+        let (hrp, _, _) = bech32::decode(address).map_err(|e| format!("{address} is not a bech32 address: {e}"))?;
+
+        let prefix = hrp.strip_suffix("valoper").or_else(|| hrp.strip_suffix("valcons")).unwrap_or(&hrp);
+
+        self.chains
+            .iter()
+            .find(|chain| chain.config.base_prefix == prefix)
+            .map(|chain| chain.clone())
+            .ok_or_else(|| format!("No supported chain has the {hrp} prefix"))
+    }
+
+    /// Returns the initialized chains at the moment.
+    pub fn get_chains(&self) -> HashMap<String, Chain> {
+        self.chains.iter().map(|chain| (chain.key().clone(), chain.value().clone())).collect()
+    }
+
+    /// Returns the initialized chains, shared with the websocket server.
+    pub fn chains(&self) -> Chains {
+        self.chains.clone()
     }
+
+    /// Returns the config of the server.
+    pub fn config(&self) -> &ServerConfig {
+        &self.config
+    }
+
+    /// Returns the database that keeps the data not belonging to a chain.
+    pub fn database(&self) -> &DatabaseTR {
+        &self.database
+    }
+
+    /// Returns the counter of the open websocket connections, updated by the websocket server.
+    pub fn ws_clients(&self) -> Arc<AtomicUsize> {
+        self.ws_clients.clone()
+    }
+
+    /// Returns the count of the open websocket connections.
+    pub fn ws_client_count(&self) -> usize {
+        self.ws_clients.load(Ordering::Relaxed)
+    }
+
+    /// Returns the last reports of `/health` and `/ready`.
+    pub fn health_cache(&self) -> &HealthCache {
+        &self.health_cache
+    }
+}
+
+/// Subscribes to the events of the chain, and passes them to the ingestion pipeline of the chain.\
+/// The subscription is made again after it stops, until the returned task is aborted.
+fn subscribe_to_events(chain: Chain, tx: Sender<(String, SequencedWsEvent)>) -> JoinHandle<()> {
+    let name = chain.config.name.clone();
+    let pipeline = IngestPipeline::for_chain(&chain, tx);
+
+    tokio::spawn(async move {
+        if let Err(e) = chain.database.create_event_log().await {
+            tracing::error!("Failed creating the event log for {name}: {e}");
+        }
+
+        loop {
+            match chain.subscribe_events(pipeline.clone()).await {
+                Ok(_) => tracing::info!("Stopped listening events for {name}"),
+                Err(e) => tracing::error!("Failed listening events for {name}: {e}"),
+            }
+            tokio::time::sleep(tokio::time::Duration::from_secs(10)).await;
+        }
+    })
 }
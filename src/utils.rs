@@ -115,15 +115,34 @@ pub fn convert_tx_to_hex(tx_base64: &str) -> Option<String> {
     base64_to_hex(tx_base64)
 }
 
-fn base64_to_hex(base64: &str) -> Option<String> {
+/// Returns the transaction hash of given raw transaction bytes.
+pub fn convert_tx_bytes_to_hex(tx_bytes: &[u8]) -> String {
     let mut hasher = Sha256::new();
 
-    hasher.update(STANDARD.decode(base64.as_bytes()).ok()?);
+    hasher.update(tx_bytes);
 
     let hash = hasher.finalize();
     let hex = to_hex(hash);
 
-    Some(hex.to_uppercase())
+    hex.to_uppercase()
+}
+
+fn base64_to_hex(base64: &str) -> Option<String> {
+    Some(convert_tx_bytes_to_hex(&STANDARD.decode(base64.as_bytes()).ok()?))
+}
+
+/// Returns `true` if given value only has hexadecimal characters.
+pub fn is_hex(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Returns the prefix of given bech32 encoded address of any chain, or `None` if it is not an address.
+pub fn bech32_address_prefix(value: &str) -> Option<String> {
+    // Addresses are at least 20 bytes, shorter bech32 strings are not taken into account.
+    match bech32::decode(value) {
+        Ok((hrp, data, _)) if value.len() <= 90 && data.len() >= 32 => Some(hrp),
+        _ => None,
+    }
 }
 
 /// From "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward" to "Withdraw Delegator Reward".
@@ -176,6 +195,14 @@ pub fn ts_to_ms(ts: &String) -> Result<i64, String> {
     Ok(date_time.timestamp_millis())
 }
 
+/// Milliseconds in a day.
+pub const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// Returns the start of the UTC day of given milliseconds since the Unix epoch.
+pub fn day_start_ms(ms: i64) -> i64 {
+    ms - ms.rem_euclid(DAY_MS)
+}
+
 pub fn to_rfc3339(timestamp: prost_wkt_types::Timestamp) -> String {
     let datetime: DateTime<Utc> = timestamp.into();
     datetime.to_rfc3339()